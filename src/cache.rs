@@ -0,0 +1,284 @@
+//! Pluggable page cache for [`VlrClient`](crate::VlrClient).
+//!
+//! Pages that can no longer change, like the detail page of a finished
+//! match, are worth fetching only once. A client built with
+//! [`VlrClient::with_cache`](crate::VlrClient::with_cache) looks up every
+//! page in its [`Cache`] before going to the network, and stores pages once
+//! they are final.
+//!
+//! ```no_run
+//! # async fn example() -> vlr_scraper::Result<()> {
+//! use vlr_scraper::{MemoryCache, VlrClient};
+//!
+//! let client = VlrClient::new().with_cache(MemoryCache::new());
+//! let first = client.get_match(378829).await?;
+//! // A completed match is served from the cache the second time
+//! let again = client.get_match(378829).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `lru-cache` feature, `LruCache` bounds the cache to a number of
+//! pages and optionally expires them, which makes it safe to keep pages that
+//! still change (profiles, listings) for a while too.
+
+#[cfg(feature = "lru-cache")]
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+#[cfg(feature = "lru-cache")]
+use std::time::{Duration, Instant};
+
+/// Storage for raw page HTML, keyed by URL.
+///
+/// Implementations must be shareable across tasks, since a client can run
+/// several requests concurrently.
+pub trait Cache: Send + Sync {
+    /// Return the cached HTML for `key`, if any.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Store the HTML fetched for `key`.
+    fn put(&self, key: &str, html: String);
+
+    /// Whether pages that can still change should be stored as well, not
+    /// only final ones. Only caches that expire entries should opt in, or
+    /// they would serve stale pages forever.
+    fn stores_changing_pages(&self) -> bool {
+        false
+    }
+}
+
+/// An unbounded in-memory [`Cache`].
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    pages: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cached pages.
+    pub fn len(&self) -> usize {
+        self.pages.lock().map(|p| p.len()).unwrap_or_default()
+    }
+
+    /// Whether no page is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<String> {
+        self.pages.lock().ok()?.get(key).cloned()
+    }
+
+    fn put(&self, key: &str, html: String) {
+        if let Ok(mut pages) = self.pages.lock() {
+            pages.insert(key.to_owned(), html);
+        }
+    }
+}
+
+impl<C: Cache + ?Sized> Cache for std::sync::Arc<C> {
+    fn get(&self, key: &str) -> Option<String> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, html: String) {
+        (**self).put(key, html)
+    }
+
+    fn stores_changing_pages(&self) -> bool {
+        (**self).stores_changing_pages()
+    }
+}
+
+/// An in-memory [`Cache`] holding at most `capacity` pages, evicting the
+/// least recently used one when full.
+///
+/// With a time to live, pages expire that long after they were fetched. Such
+/// a cache stores every page, not only final ones, so repeated requests for
+/// a profile or listing within the TTL are served without a request.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use vlr_scraper::{LruCache, VlrClient};
+///
+/// let cache = LruCache::new(500).with_ttl(Duration::from_secs(300));
+/// let client = VlrClient::new().with_cache(cache);
+/// ```
+#[cfg(feature = "lru-cache")]
+#[derive(Debug)]
+pub struct LruCache {
+    capacity: usize,
+    ttl: Option<Duration>,
+    state: Mutex<LruState>,
+}
+
+#[cfg(feature = "lru-cache")]
+#[derive(Debug, Default)]
+struct LruState {
+    pages: HashMap<String, LruEntry>,
+    /// Keys by the tick they were last used at, oldest first.
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+#[cfg(feature = "lru-cache")]
+#[derive(Debug)]
+struct LruEntry {
+    html: String,
+    stored_at: Instant,
+    last_used: u64,
+}
+
+#[cfg(feature = "lru-cache")]
+impl LruCache {
+    /// Create an empty cache for up to `capacity` pages that never expire.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ttl: None,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Expire pages `ttl` after they were stored.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Number of cached pages, including expired ones not yet evicted.
+    pub fn len(&self) -> usize {
+        self.state.lock().map(|s| s.pages.len()).unwrap_or_default()
+    }
+
+    /// Whether no page is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(feature = "lru-cache")]
+impl LruState {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &str) -> Option<LruEntry> {
+        let entry = self.pages.remove(key)?;
+        self.recency.remove(&entry.last_used);
+        Some(entry)
+    }
+}
+
+#[cfg(feature = "lru-cache")]
+impl Cache for LruCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut state = self.state.lock().ok()?;
+        let stored_at = state.pages.get(key)?.stored_at;
+        let expired = self.ttl.is_some_and(|ttl| stored_at.elapsed() >= ttl);
+        if expired {
+            state.remove(key);
+            return None;
+        }
+        let tick = state.next_tick();
+        let entry = state.pages.get_mut(key)?;
+        let previous = std::mem::replace(&mut entry.last_used, tick);
+        let html = entry.html.clone();
+        state.recency.remove(&previous);
+        state.recency.insert(tick, key.to_owned());
+        Some(html)
+    }
+
+    fn put(&self, key: &str, html: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.remove(key);
+        while state.pages.len() >= self.capacity {
+            let Some((_, oldest)) = state.recency.pop_first() else {
+                break;
+            };
+            state.pages.remove(&oldest);
+        }
+        let tick = state.next_tick();
+        state.recency.insert(tick, key.to_owned());
+        state.pages.insert(
+            key.to_owned(),
+            LruEntry {
+                html,
+                stored_at: Instant::now(),
+                last_used: tick,
+            },
+        );
+    }
+
+    fn stores_changing_pages(&self) -> bool {
+        self.ttl.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_cache() {
+        let cache = MemoryCache::new();
+        assert!(cache.is_empty());
+        assert_eq!(cache.get("https://www.vlr.gg/1"), None);
+
+        cache.put("https://www.vlr.gg/1", "<html></html>".to_string());
+        assert_eq!(
+            cache.get("https://www.vlr.gg/1").as_deref(),
+            Some("<html></html>")
+        );
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[cfg(feature = "lru-cache")]
+    #[test]
+    fn test_lru_cache_evicts_least_recently_used() {
+        let cache = LruCache::new(2);
+        cache.put("a", "A".to_string());
+        cache.put("b", "B".to_string());
+        // Touch "a" so "b" becomes the least recently used page
+        assert_eq!(cache.get("a").as_deref(), Some("A"));
+        cache.put("c", "C".to_string());
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a").as_deref(), Some("A"));
+        assert_eq!(cache.get("c").as_deref(), Some("C"));
+
+        cache.put("a", "A2".to_string());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("a").as_deref(), Some("A2"));
+        assert!(!cache.stores_changing_pages());
+    }
+
+    #[cfg(feature = "lru-cache")]
+    #[test]
+    fn test_lru_cache_expires_pages() {
+        let cache = LruCache::new(4).with_ttl(Duration::ZERO);
+        cache.put("a", "A".to_string());
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.is_empty());
+        assert!(cache.stores_changing_pages());
+
+        let cache = LruCache::new(4).with_ttl(Duration::from_secs(60));
+        cache.put("a", "A".to_string());
+        assert_eq!(cache.get("a").as_deref(), Some("A"));
+    }
+}
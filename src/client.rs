@@ -1,6 +1,10 @@
+use futures::StreamExt;
 use tracing::instrument;
 
-use crate::error::Result;
+use crate::cache::Cache;
+use crate::error::{ErrorPolicy, Result, VlrError};
+use crate::fetch::Fetch;
+use crate::metrics::Metrics;
 use crate::model::*;
 use crate::vlr_scraper;
 
@@ -28,24 +32,47 @@ use crate::vlr_scraper;
 /// # }
 /// ```
 pub struct VlrClient {
-    http: reqwest::Client,
+    http: vlr_scraper::HttpClient,
 }
 
 impl VlrClient {
     /// Create a new client with default settings.
     ///
-    /// Uses a default [`reqwest::Client`] with no custom configuration.
-    /// For custom timeouts, proxies, or headers, use [`VlrClient::with_client`].
+    /// Uses a [`reqwest::Client`] that sends `Accept-Language: en`, so vlr.gg
+    /// serves English pages with `.` decimals. For a timeout, user agent, or
+    /// retries, use [`VlrClient::builder`]; for proxies or other HTTP-level
+    /// settings, use [`VlrClient::with_client`].
     pub fn new() -> Self {
-        Self {
-            http: reqwest::Client::new(),
-        }
+        VlrClientBuilder::new()
+            .build()
+            .expect("default HTTP client should build")
+    }
+
+    /// Start configuring a client with a [`VlrClientBuilder`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent("my-stats-bot/1.0 (me@example.com)")
+    ///     .max_retries(3)
+    ///     .build()?;
+    /// # Ok::<(), vlr_scraper::VlrError>(())
+    /// ```
+    pub fn builder() -> VlrClientBuilder {
+        VlrClientBuilder::new()
     }
 
     /// Create a new client using the provided [`reqwest::Client`].
     ///
     /// Use this when you need to configure timeouts, proxies, headers, or
-    /// other HTTP-level settings.
+    /// other HTTP-level settings. Consider sending `Accept-Language: en` as
+    /// [`VlrClient::new`] does; localized pages may use decimal commas.
     ///
     /// # Examples
     ///
@@ -59,7 +86,158 @@ impl VlrClient {
     /// let client = VlrClient::with_client(http);
     /// ```
     pub fn with_client(client: reqwest::Client) -> Self {
-        Self { http: client }
+        Self {
+            http: client.into(),
+        }
+    }
+
+    /// Request pages from `base_url` instead of `https://www.vlr.gg`.
+    ///
+    /// Useful for pointing the client at a mirror or a local server with
+    /// fixture pages in tests. Pages are requested at the same paths as on
+    /// vlr.gg; links in the parsed data (match, team, image URLs) still point
+    /// at vlr.gg.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new().with_base_url("http://localhost:8080");
+    /// ```
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        self.http.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Serve pages from `cache` and store the ones that can no longer change.
+    ///
+    /// Every page is looked up in the cache before it is requested. Only
+    /// pages of completed matches (the match page and its performance and
+    /// economy tabs) are written to it, since profiles, event listings, and
+    /// live matches keep changing, unless the cache expires entries and
+    /// opts in through [`Cache::stores_changing_pages`] (like an `LruCache`
+    /// with a TTL). Pass an [`Arc`](std::sync::Arc) to keep a handle to the
+    /// cache.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use std::sync::Arc;
+    ///
+    /// use vlr_scraper::{MemoryCache, VlrClient};
+    ///
+    /// let cache = Arc::new(MemoryCache::new());
+    /// let client = VlrClient::new().with_cache(Arc::clone(&cache));
+    /// client.get_match(378829).await?;
+    /// println!("{} pages cached", cache.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cache(mut self, cache: impl Cache + 'static) -> Self {
+        self.http.cache = Some(std::sync::Arc::new(cache));
+        self
+    }
+
+    /// Choose whether items that fail to parse abort a request or are skipped.
+    ///
+    /// Applies to the items of list pages (events, match lists, match
+    /// histories, a player's teams) and to the games of a match. The default,
+    /// [`ErrorPolicy::Lenient`], skips such items and logs a warning;
+    /// [`ErrorPolicy::Strict`] returns the first item's error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlr_scraper::{ErrorPolicy, VlrClient};
+    ///
+    /// let client = VlrClient::new().with_error_policy(ErrorPolicy::Strict);
+    /// ```
+    pub fn with_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.http.error_policy = policy;
+        self
+    }
+
+    /// Report requests and parse failures to `metrics`.
+    ///
+    /// Every page fetched from the network is reported through
+    /// [`Metrics::on_request_start`] and [`Metrics::on_request_end`]; pages
+    /// served from the cache are not. A method that fails because its page
+    /// couldn't be parsed calls [`Metrics::on_parse_error`] with the method
+    /// name. Pass an `Arc` to keep a handle on the metrics for reading them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlr_scraper::{NoopMetrics, VlrClient};
+    ///
+    /// let client = VlrClient::new().with_metrics(NoopMetrics);
+    /// ```
+    pub fn with_metrics(mut self, metrics: impl Metrics + 'static) -> Self {
+        self.http.metrics = Some(std::sync::Arc::new(metrics));
+        self
+    }
+
+    /// Pass `result` through, reporting it to the metrics hooks if it failed
+    /// while parsing. Request failures are already reported by the fetch.
+    fn observe<T>(&self, endpoint: &'static str, result: Result<T>) -> Result<T> {
+        if let (Some(metrics), Err(error)) = (&self.http.metrics, &result) {
+            let is_request_error = matches!(
+                error,
+                VlrError::Http { .. }
+                    | VlrError::UnexpectedStatus { .. }
+                    | VlrError::RateLimited { .. }
+                    | VlrError::ResponseBody { .. }
+            );
+            if !is_request_error {
+                metrics.on_parse_error(endpoint, error);
+            }
+        }
+        result
+    }
+
+    /// Fetch a batch of pages of one kind (matches, players, teams, or events)
+    /// by id.
+    ///
+    /// At most `concurrency` pages are in flight at once (at least one), which
+    /// keeps large batches from flooding vlr.gg. Every id is paired with its
+    /// own result, in the order of `ids`, so one failed page doesn't hide the
+    /// others.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The ids to fetch, e.g. match ids for [`Match`].
+    /// * `concurrency` - Maximum number of requests running at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() {
+    /// use vlr_scraper::{Team, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let teams = client.get_many::<Team>(&[2, 188, 6530], 2).await;
+    /// for (id, team) in teams {
+    ///     println!("{id}: {}", team.map(|t| t.info.name).unwrap_or_default());
+    /// }
+    /// # }
+    /// ```
+    #[instrument(skip(self, ids), fields(count = ids.len()))]
+    pub async fn get_many<F: Fetch>(
+        &self,
+        ids: &[F::Id],
+        concurrency: usize,
+    ) -> Vec<(F::Id, Result<F::Output>)> {
+        futures::stream::iter(ids.iter().cloned())
+            .map(|id| async move {
+                let result = F::fetch(self, id.clone()).await;
+                (id, result)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// Fetch a paginated list of events, filtered by type and region.
@@ -90,6 +268,7 @@ impl VlrClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "events")]
     #[instrument(skip(self))]
     pub async fn get_events(
         &self,
@@ -97,7 +276,63 @@ impl VlrClient {
         region: Region,
         page: u8,
     ) -> Result<EventsData> {
-        vlr_scraper::events::list::get_events(&self.http, event_type, region, page).await
+        self.observe(
+            "get_events",
+            vlr_scraper::events::list::get_events(&self.http, event_type, region, page).await,
+        )
+    }
+
+    /// Stream every event of a type and region, page after page.
+    ///
+    /// Pages are fetched lazily with [`VlrClient::get_events`]: the next page
+    /// is only requested once the events of the current one have been
+    /// consumed. The stream ends after the last page (or at the first empty
+    /// one). A page that fails to load is yielded as an `Err` and ends the
+    /// stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_type` - Whether to retrieve [`EventType::Upcoming`] or [`EventType::Completed`] events.
+    /// * `region` - Geographic filter (use [`Region::All`] for no filtering).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use futures::{pin_mut, StreamExt};
+    /// use vlr_scraper::{EventType, Region, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let events = client.events_stream(EventType::Completed, Region::Europe);
+    /// pin_mut!(events);
+    /// while let Some(event) = events.next().await {
+    ///     println!("{}", event?.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "events")]
+    pub fn events_stream(
+        &self,
+        event_type: EventType,
+        region: Region,
+    ) -> impl futures::Stream<Item = Result<Event>> + '_ {
+        futures::stream::unfold(Some(1), move |page: Option<u8>| {
+            let region = region.clone();
+            async move {
+                let page = page?;
+                let (events, next_page) = match self.get_events(event_type, region, page).await {
+                    Ok(data) => {
+                        let next_page = (!data.events.is_empty() && page < data.total_pages)
+                            .then_some(page + 1);
+                        (data.events.into_iter().map(Ok).collect(), next_page)
+                    }
+                    Err(e) => (vec![Err(e)], None),
+                };
+                Some((events, next_page))
+            }
+        })
+        .flat_map(futures::stream::iter)
     }
 
     /// Fetch all matches belonging to an event.
@@ -125,22 +360,53 @@ impl VlrClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "events")]
     #[instrument(skip(self))]
     pub async fn get_event_matchlist(&self, event_id: u32) -> Result<EventMatchList> {
-        vlr_scraper::events::matchlist::get_event_matchlist(&self.http, event_id).await
+        self.observe(
+            "get_event_matchlist",
+            vlr_scraper::events::matchlist::get_event_matchlist(&self.http, event_id, None).await,
+        )
     }
 
-    /// Fetch full details for a specific match by ID.
+    /// Fetch the matches being played right now, site-wide.
     ///
-    /// Returns a [`Match`] containing:
-    /// - [`MatchHeader`] — event info, date, and team names/scores
-    /// - Live streams and VOD links as [`MatchStream`] entries
-    /// - Per-map [`MatchGame`] data with team scores, player stats, and
-    ///   round-by-round outcomes
+    /// Reads vlr.gg's `/matches` feed and keeps the entries whose
+    /// [`EventMatchListItem::status`] is [`MatchListStatus::Live`]. Returns an
+    /// empty list when nothing is live.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// for m in client.get_live_matches().await? {
+    ///     let teams: Vec<_> = m.teams.iter().map(|t| t.name.as_str()).collect();
+    ///     println!("LIVE: {} ({})", teams.join(" vs "), m.event_text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "events")]
+    #[instrument(skip(self))]
+    pub async fn get_live_matches(&self) -> Result<EventMatchList> {
+        self.observe(
+            "get_live_matches",
+            vlr_scraper::events::matchlist::get_live_matches(&self.http).await,
+        )
+    }
+
+    /// Fetch a page of upcoming and live matches across all events.
+    ///
+    /// Reads vlr.gg's `/matches` feed, which lists live matches first and
+    /// then upcoming ones by start time. Live matches have no
+    /// [`date_time`](EventMatchListItem::date_time).
     ///
     /// # Arguments
     ///
-    /// * `match_id` - The VLR.gg match ID (found in [`EventMatchListItem::id`]).
+    /// * `page` - Page number (1-indexed).
     ///
     /// # Examples
     ///
@@ -149,31 +415,33 @@ impl VlrClient {
     /// use vlr_scraper::VlrClient;
     ///
     /// let client = VlrClient::new();
-    /// let m = client.get_match(429519).await?;
-    /// println!("{} — {}", m.header.event_title, m.header.event_series_name);
-    /// for game in &m.games {
+    /// for m in client.get_upcoming_matches(1).await? {
+    ///     let teams: Vec<_> = m.teams.iter().map(|t| t.name.as_str()).collect();
     ///     println!(
-    ///         "  {} — {} vs {}",
-    ///         game.map, game.teams[0].name, game.teams[1].name
+    ///         "{:?}: {} ({})",
+    ///         m.date_time,
+    ///         teams.join(" vs "),
+    ///         m.event_text
     ///     );
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "events")]
     #[instrument(skip(self))]
-    pub async fn get_match(&self, match_id: u32) -> Result<Match> {
-        vlr_scraper::matches::detail::get_match(&self.http, match_id).await
+    pub async fn get_upcoming_matches(&self, page: u8) -> Result<EventMatchList> {
+        self.observe(
+            "get_upcoming_matches",
+            vlr_scraper::events::matchlist::get_upcoming_matches(&self.http, page).await,
+        )
     }
 
-    /// Fetch a paginated list of matches a player has participated in.
+    /// Fetch a page of completed matches across all events, newest first.
     ///
-    /// Returns a [`PlayerMatchList`] (a `Vec<PlayerMatchListItem>`) where each
-    /// entry contains the match ID, league name and icon, participating teams
-    /// with scores, VOD links, and a match start timestamp.
+    /// Reads vlr.gg's `/matches/results` feed.
     ///
     /// # Arguments
     ///
-    /// * `player_id` - The VLR.gg player ID.
     /// * `page` - Page number (1-indexed).
     ///
     /// # Examples
@@ -183,70 +451,146 @@ impl VlrClient {
     /// use vlr_scraper::VlrClient;
     ///
     /// let client = VlrClient::new();
-    /// let matches = client.get_player_matchlist(17323, 1).await?;
-    /// for m in &matches {
-    ///     let teams: Vec<_> = m.teams.iter().map(|t| t.name.as_str()).collect();
-    ///     println!("[{}] {}", m.league_name, teams.join(" vs "));
+    /// for m in client.get_results(1).await? {
+    ///     let score: Vec<_> = m
+    ///         .teams
+    ///         .iter()
+    ///         .map(|t| format!("{} {}", t.name, t.score.unwrap_or_default()))
+    ///         .collect();
+    ///     println!("{}", score.join(" - "));
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "events")]
     #[instrument(skip(self))]
-    pub async fn get_player_matchlist(&self, player_id: u32, page: u8) -> Result<PlayerMatchList> {
-        vlr_scraper::players::matchlist::get_player_matchlist(&self.http, player_id, page).await
+    pub async fn get_results(&self, page: u8) -> Result<EventMatchList> {
+        self.observe(
+            "get_results",
+            vlr_scraper::events::matchlist::get_results(&self.http, page).await,
+        )
     }
 
-    /// Fetch a complete player profile including info, teams, agent stats, news, and event placements.
+    /// Fetch the matches of a single stage (series) of an event.
     ///
-    /// The returned [`Player`] contains:
-    /// - [`PlayerInfo`] — name, real name, avatar URL, country/country code, and social links
-    /// - Current and past [`PlayerTeam`] entries with team ID, name, logo, and join info
-    /// - [`PlayerAgentStats`] for the given timespan (rating, ACS, K/D, ADR, KAST, etc.)
-    /// - Recent [`PlayerNewsItem`] articles mentioning the player
-    /// - [`EventPlacement`] history with per-stage results and total winnings
+    /// Behaves like [`VlrClient::get_event_matchlist`], but only returns the
+    /// matches of the given stage, as filtered by vlr.gg itself.
     ///
     /// # Arguments
     ///
-    /// * `player_id` - The VLR.gg player ID.
-    /// * `timespan` - Time window for agent statistics (see [`AgentStatsTimespan`]).
+    /// * `event_id` - The VLR.gg event ID (found in [`Event::id`]).
+    /// * `series_id` - The stage's series ID (found in [`MatchHeader::stage_id`]).
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # async fn example() -> vlr_scraper::Result<()> {
-    /// use vlr_scraper::{AgentStatsTimespan, VlrClient};
+    /// use vlr_scraper::VlrClient;
     ///
     /// let client = VlrClient::new();
-    /// let player = client.get_player(17323, AgentStatsTimespan::All).await?;
+    /// let playoffs = client.get_event_matchlist_for_series(2097, 4030).await?;
+    /// println!("{} playoff matches", playoffs.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "events")]
+    #[instrument(skip(self))]
+    pub async fn get_event_matchlist_for_series(
+        &self,
+        event_id: u32,
+        series_id: u32,
+    ) -> Result<EventMatchList> {
+        self.observe(
+            "get_event_matchlist_for_series",
+            vlr_scraper::events::matchlist::get_event_matchlist(
+                &self.http,
+                event_id,
+                Some(series_id),
+            )
+            .await,
+        )
+    }
+
+    /// Fetch the group stage tables of an event.
     ///
-    /// println!("{} ({:?})", player.info.name, player.info.country);
-    /// for team in &player.current_teams {
-    ///     println!("  team: {}", team.name);
+    /// Returns one [`GroupStanding`] per group table on the event's overview
+    /// page, each with its [`StandingRow`]s in ranked order. Columns a table
+    /// doesn't show (such as round differential) read as 0; events without a
+    /// group stage yield an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_id` - The VLR.gg event ID (found in [`Event::id`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// for group in client.get_event_standings(2097).await? {
+    ///     println!("{}", group.name);
+    ///     for row in &group.rows {
+    ///         println!("  {} {}-{}", row.team_name, row.wins, row.losses);
+    ///     }
     /// }
-    /// for stat in &player.agent_stats {
-    ///     println!(
-    ///         "  {} — rating {:.2}, K/D {:.2}",
-    ///         stat.agent, stat.rating, stat.kd
-    ///     );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "events")]
+    #[instrument(skip(self))]
+    pub async fn get_event_standings(&self, event_id: u32) -> Result<Vec<GroupStanding>> {
+        self.observe(
+            "get_event_standings",
+            vlr_scraper::events::standings::get_event_standings(&self.http, event_id).await,
+        )
+    }
+
+    /// Fetch the playoff bracket drawn on an event's overview page.
+    ///
+    /// Returns a [`Bracket`] whose [`BracketColumn`]s follow the page's
+    /// render order (upper bracket rounds, then lower bracket rounds). Events
+    /// with only a group stage yield no columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_id` - The VLR.gg event ID (found in [`Event::id`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let bracket = client.get_event_bracket(2097).await?;
+    /// for column in &bracket.columns {
+    ///     println!("{} ({} matches)", column.label, column.matches.len());
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "events")]
     #[instrument(skip(self))]
-    pub async fn get_player(&self, player_id: u32, timespan: AgentStatsTimespan) -> Result<Player> {
-        vlr_scraper::players::info::get_player(&self.http, player_id, timespan).await
+    pub async fn get_event_bracket(&self, event_id: u32) -> Result<Bracket> {
+        self.observe(
+            "get_event_bracket",
+            vlr_scraper::events::bracket::get_event_bracket(&self.http, event_id).await,
+        )
     }
 
-    /// Fetch a paginated list of matches a team has participated in.
+    /// Fetch an event's overview page.
     ///
-    /// Returns a `Vec<MatchItem>` where each entry contains the match ID,
-    /// league name and icon, participating teams with scores, VOD links, and
-    /// a match start timestamp.
+    /// Returns an [`EventDetail`] with the event's title and [`EventStatus`],
+    /// the header's dates, prize pool, location, and organizer (each `None`
+    /// when not listed), its stage names, its official broadcast channels as
+    /// [`MatchStream`] entries (empty when the event lists none), its final
+    /// placements, and its participants.
     ///
     /// # Arguments
     ///
-    /// * `team_id` - The VLR.gg team ID.
-    /// * `page` - Page number (1-indexed).
+    /// * `event_id` - The VLR.gg event ID (found in [`Event::id`]).
     ///
     /// # Examples
     ///
@@ -255,28 +599,35 @@ impl VlrClient {
     /// use vlr_scraper::VlrClient;
     ///
     /// let client = VlrClient::new();
-    /// let matches = client.get_team_matchlist(6530, 1).await?;
-    /// for m in &matches {
-    ///     let teams: Vec<_> = m.teams.iter().map(|t| t.name.as_str()).collect();
-    ///     println!("[{}] {}", m.league_name, teams.join(" vs "));
+    /// let event = client.get_event(2097).await?;
+    /// for stream in &event.streams {
+    ///     println!("{} — {}", stream.name, stream.link);
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "events")]
     #[instrument(skip(self))]
-    pub async fn get_team_matchlist(&self, team_id: u32, page: u8) -> Result<Vec<MatchItem>> {
-        vlr_scraper::teams::matchlist::get_team_matchlist(&self.http, team_id, page).await
+    pub async fn get_event(&self, event_id: u32) -> Result<EventDetail> {
+        self.observe(
+            "get_event",
+            vlr_scraper::events::detail::get_event(&self.http, event_id).await,
+        )
     }
 
-    /// Fetch a team's roster transaction history (joins, leaves, inactive changes).
+    /// Fetch only the final standings of an event.
     ///
-    /// Returns a `Vec<TeamTransaction>` where each entry contains the date,
-    /// action type, player info (id, alias, real name, country code), position,
-    /// and an optional reference URL.
+    /// A lighter alternative to [`get_event`](Self::get_event) for "who won"
+    /// queries and prize tracking: parses just the prize distribution table
+    /// of the event page. Every listed place is returned; places without
+    /// prize money (shown as `"—"`) have no
+    /// [`prize`](EventFinalPlacement::prize) or
+    /// [`prize_money`](EventFinalPlacement::prize_money). Returns an empty
+    /// list when the event has none yet.
     ///
     /// # Arguments
     ///
-    /// * `team_id` - The VLR.gg team ID (found in team page URLs).
+    /// * `event_id` - The VLR.gg event ID (found in [`Event::id`]).
     ///
     /// # Examples
     ///
@@ -285,32 +636,35 @@ impl VlrClient {
     /// use vlr_scraper::VlrClient;
     ///
     /// let client = VlrClient::new();
-    /// let transactions = client.get_team_transactions(6530).await?;
-    /// for txn in &transactions {
-    ///     println!(
-    ///         "{:?} — {} {} ({})",
-    ///         txn.date, txn.action, txn.player_alias, txn.position
-    ///     );
+    /// let results = client.get_event_results(2097).await?;
+    /// if let Some(winner) = results.first() {
+    ///     println!("{}: {:?}", winner.place, winner.team_name);
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "events")]
+    #[doc(alias = "get_event_prizes")]
     #[instrument(skip(self))]
-    pub async fn get_team_transactions(&self, team_id: u32) -> Result<Vec<TeamTransaction>> {
-        vlr_scraper::teams::transactions::get_team_transactions(&self.http, team_id).await
+    pub async fn get_event_results(&self, event_id: u32) -> Result<Vec<EventFinalPlacement>> {
+        self.observe(
+            "get_event_results",
+            vlr_scraper::events::detail::get_event_results(&self.http, event_id).await,
+        )
     }
 
-    /// Fetch a complete team profile including info, roster, event placements, and total winnings.
+    /// Fetch full details for a specific match by ID.
     ///
-    /// The returned [`Team`] contains:
-    /// - [`TeamInfo`] — name, tag, logo URL, country/country code, and social links
-    /// - [`TeamRosterMember`] entries with player/staff info, roles, and captain status
-    /// - [`EventPlacement`] history with stage results and prize earnings
-    /// - Total career winnings as an optional string
+    /// Returns a [`Match`] containing:
+    /// - [`MatchHeader`] — event info, date, and team names/scores
+    /// - Live streams and VOD links as [`MatchStream`] entries
+    /// - Per-map [`MatchGame`] data with team scores, player stats, and
+    ///   round-by-round outcomes
+    /// - The map veto as a sequence of [`VetoAction`] steps
     ///
     /// # Arguments
     ///
-    /// * `team_id` - The VLR.gg team ID (found in team page URLs).
+    /// * `match_id` - The VLR.gg match ID (found in [`EventMatchListItem::id`]).
     ///
     /// # Examples
     ///
@@ -319,22 +673,1035 @@ impl VlrClient {
     /// use vlr_scraper::VlrClient;
     ///
     /// let client = VlrClient::new();
-    /// let team = client.get_team(6530).await?;
-    /// println!("{} ({:?})", team.info.name, team.info.tag);
-    /// for member in &team.roster {
-    ///     println!("  {} — {}", member.alias, member.role);
+    /// let m = client.get_match(429519).await?;
+    /// println!("{} — {}", m.header.event_title, m.header.event_series_name);
+    /// for game in &m.games {
+    ///     println!(
+    ///         "  {} — {} vs {}",
+    ///         game.map, game.teams[0].name, game.teams[1].name
+    ///     );
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg(feature = "matches")]
     #[instrument(skip(self))]
-    pub async fn get_team(&self, team_id: u32) -> Result<Team> {
-        vlr_scraper::teams::info::get_team(&self.http, team_id).await
+    pub async fn get_match(&self, match_id: u32) -> Result<Match> {
+        let options = MatchFetchOptions::default();
+        self.observe(
+            "get_match",
+            vlr_scraper::matches::detail::get_match(&self.http, match_id, &options).await,
+        )
     }
-}
 
-impl Default for VlrClient {
-    fn default() -> Self {
-        Self::new()
+    /// Fetch many matches, running up to `concurrency` requests at once.
+    ///
+    /// Unlike [`VlrClient::get_many`], results come back in the order the
+    /// matches finish loading, not the order of `ids`, so one slow match
+    /// doesn't hold up the others. Each result is paired with its match id.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The VLR.gg match IDs to fetch.
+    /// * `concurrency` - Maximum number of requests running at the same time
+    ///   (at least one).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// for (id, result) in client.get_matches(&[378829, 378830, 378831], 2).await {
+    ///     match result {
+    ///         Ok(m) => println!("{id}: {}", m.label()),
+    ///         Err(e) => eprintln!("{id} failed: {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "matches")]
+    #[instrument(skip(self, ids), fields(count = ids.len()))]
+    pub async fn get_matches(&self, ids: &[u32], concurrency: usize) -> Vec<(u32, Result<Match>)> {
+        futures::stream::iter(ids.iter().copied())
+            .map(|id| async move { (id, self.get_match(id).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Fetch only the per-map scoreboards of a match.
+    ///
+    /// Returns the same [`MatchGame`] entries as [`VlrClient::get_match`], but
+    /// fetches just the overview page (one request) and skips streams,
+    /// head-to-head, and the performance and economy tabs. Placeholder
+    /// matches yield an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `match_id` - The VLR.gg match ID (found in [`EventMatchListItem::id`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// for game in client.get_match_scoreboard(429519).await? {
+    ///     let players: usize = game.teams.iter().map(|t| t.players.len()).sum();
+    ///     println!("{}: {players} players", game.map);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "matches")]
+    #[instrument(skip(self))]
+    pub async fn get_match_scoreboard(&self, match_id: u32) -> Result<Vec<MatchGame>> {
+        self.observe(
+            "get_match_scoreboard",
+            vlr_scraper::matches::detail::get_match_scoreboard(&self.http, match_id).await,
+        )
+    }
+
+    /// Fetch match details, parsing only what `options` asks for.
+    ///
+    /// The match page is still fetched once, but games outside
+    /// [`MatchFetchOptions::only_games`] are skipped while parsing, which
+    /// bounds the work for long series when only some maps matter.
+    ///
+    /// # Arguments
+    ///
+    /// * `match_id` - The VLR.gg match ID (found in [`EventMatchListItem::id`]).
+    /// * `options` - Which parts of the match to parse (see [`MatchFetchOptions`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{MatchFetchOptions, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let options = MatchFetchOptions {
+    ///     only_games: Some(vec![0]),
+    /// };
+    /// let m = client.get_match_with_options(429519, &options).await?;
+    /// println!("map 1: {}", m.games[0].map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "matches")]
+    #[instrument(skip(self))]
+    pub async fn get_match_with_options(
+        &self,
+        match_id: u32,
+        options: &MatchFetchOptions,
+    ) -> Result<Match> {
+        self.observe(
+            "get_match_with_options",
+            vlr_scraper::matches::detail::get_match(&self.http, match_id, options).await,
+        )
+    }
+
+    /// Fetch full match details from a vlr.gg match URL.
+    ///
+    /// Accepts match links of the form `https://www.vlr.gg/{id}/{slug}` (with
+    /// or without the host), then behaves like [`VlrClient::get_match`].
+    /// Fails with [`VlrError::InvalidUrl`] if the URL doesn't point at a match.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A vlr.gg match URL or site-relative path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let m = client
+    ///     .get_match_by_url("https://www.vlr.gg/429519/paper-rex-vs-fnatic")
+    ///     .await?;
+    /// println!("{}", m.header.event_title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VlrError::InvalidUrl`]: crate::VlrError::InvalidUrl
+    #[cfg(feature = "matches")]
+    #[instrument(skip(self))]
+    pub async fn get_match_by_url(&self, url: &str) -> Result<Match> {
+        let match_id = vlr_scraper::vlr_url_id(url, vlr_scraper::VlrUrlKind::Match)?;
+        self.get_match(match_id).await
+    }
+
+    /// Fetch a paginated list of matches a player has participated in.
+    ///
+    /// Returns a [`PaginatedMatchList`] holding the page's
+    /// [`PlayerMatchListItem`]s along with the page number and the total
+    /// number of pages in the history. Each entry contains the match ID,
+    /// league name and icon, participating teams with scores, VOD links, and a
+    /// match start timestamp. The list derefs to a `Vec<PlayerMatchListItem>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The VLR.gg player ID.
+    /// * `page` - Page number (1-indexed).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let matches = client.get_player_matchlist(17323, 1).await?;
+    /// println!("page {} of {}", matches.page, matches.total_pages);
+    /// for m in &matches {
+    ///     let teams: Vec<_> = m.teams.iter().map(|t| t.name.as_str()).collect();
+    ///     println!("[{}] {}", m.league_name, teams.join(" vs "));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn get_player_matchlist(
+        &self,
+        player_id: u32,
+        page: u8,
+    ) -> Result<PaginatedMatchList> {
+        self.observe(
+            "get_player_matchlist",
+            vlr_scraper::players::matchlist::get_player_matchlist(&self.http, player_id, page)
+                .await,
+        )
+    }
+
+    /// Fetch the first `pages` pages of a player's match history, keeping only
+    /// the matches that pass `filter`.
+    ///
+    /// Pages are requested one at a time and paging stops early at the end of
+    /// the history.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The VLR.gg player ID.
+    /// * `pages` - Maximum number of match history pages to walk.
+    /// * `filter` - Result and/or opponent criteria (see [`MatchFilter`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{MatchFilter, MatchResult, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let filter = MatchFilter::result(MatchResult::Loss).against(2593);
+    /// let losses = client.get_player_matches_filtered(17323, 5, filter).await?;
+    /// println!("{} losses against FNATIC", losses.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn get_player_matches_filtered(
+        &self,
+        player_id: u32,
+        pages: u8,
+        filter: MatchFilter,
+    ) -> Result<PlayerMatchList> {
+        self.observe(
+            "get_player_matches_filtered",
+            vlr_scraper::players::matchlist::get_player_matches_filtered(
+                &self.http, player_id, pages, filter,
+            )
+            .await,
+        )
+    }
+
+    /// Fetch the player's wins from the first `pages` pages of their match history.
+    ///
+    /// Shorthand for [`VlrClient::get_player_matches_filtered`] with
+    /// [`MatchFilter::result`]`(`[`MatchResult::Win`]`)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The VLR.gg player ID.
+    /// * `pages` - Maximum number of match history pages to walk.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let wins = client.get_player_wins(17323, 3).await?;
+    /// println!("{} wins", wins.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn get_player_wins(&self, player_id: u32, pages: u8) -> Result<PlayerMatchList> {
+        self.get_player_matches_filtered(player_id, pages, MatchFilter::result(MatchResult::Win))
+            .await
+    }
+
+    /// Find a player by name using vlr.gg's search.
+    ///
+    /// Uses the same matching rules as [`VlrClient::resolve_team`]: exact
+    /// name (case-insensitive), then slug, then the top result. The returned
+    /// [`PlayerRef`] carries the result's flag code as `nation` and no
+    /// `team_id`. Returns `None` when the search finds no player.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The player name (handle) to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// if let Some(player) = client.resolve_player("TenZ").await? {
+    ///     println!("{} is player {}", player.name, player.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn resolve_player(&self, name: &str) -> Result<Option<PlayerRef>> {
+        self.observe(
+            "resolve_player",
+            vlr_scraper::search::resolve_player(&self.http, name).await,
+        )
+    }
+
+    /// Fetch a complete player profile including info, teams, agent stats, news, and event placements.
+    ///
+    /// The returned [`Player`] contains:
+    /// - [`PlayerInfo`] — name, real name, avatar URL, country/country code, and social links
+    /// - Current and past [`PlayerTeam`] entries with team ID, name, logo, and join info
+    /// - [`PlayerAgentStats`] for the given timespan (rating, ACS, K/D, ADR, KAST, etc.)
+    /// - Recent [`PlayerNewsItem`] articles mentioning the player
+    /// - [`EventPlacement`] history with per-stage results and total winnings
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The VLR.gg player ID.
+    /// * `timespan` - Time window for agent statistics (see [`AgentStatsTimespan`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{AgentStatsTimespan, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let player = client.get_player(17323, AgentStatsTimespan::All).await?;
+    ///
+    /// println!("{} ({:?})", player.info.name, player.info.country);
+    /// for team in &player.current_teams {
+    ///     println!("  team: {}", team.name);
+    /// }
+    /// for stat in &player.agent_stats {
+    ///     println!(
+    ///         "  {} — rating {:.2}, K/D {:.2}",
+    ///         stat.agent, stat.rating, stat.kd
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn get_player(&self, player_id: u32, timespan: AgentStatsTimespan) -> Result<Player> {
+        self.observe(
+            "get_player",
+            vlr_scraper::players::info::get_player(&self.http, player_id, timespan, None).await,
+        )
+    }
+
+    /// Fetch only a player's per-agent statistics.
+    ///
+    /// Returns the same [`PlayerAgentStats`] as [`Player::agent_stats`] from
+    /// [`VlrClient::get_player`], from a single request, without parsing the
+    /// rest of the profile. This is the efficient path for collecting agent
+    /// stats of many players.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The VLR.gg player ID.
+    /// * `timespan` - Time window for the statistics (see [`AgentStatsTimespan`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{AgentStatsTimespan, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let stats = client
+    ///     .get_player_agent_stats(17323, AgentStatsTimespan::Days60)
+    ///     .await?;
+    /// for stat in &stats {
+    ///     println!("{}: {} rounds", stat.agent, stat.rounds);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn get_player_agent_stats(
+        &self,
+        player_id: u32,
+        timespan: AgentStatsTimespan,
+    ) -> Result<Vec<PlayerAgentStats>> {
+        self.observe(
+            "get_player_agent_stats",
+            vlr_scraper::players::info::get_player_agent_stats(&self.http, player_id, timespan)
+                .await,
+        )
+    }
+
+    /// Fetch a complete player profile from a vlr.gg player URL.
+    ///
+    /// Accepts any link to the player's pages (e.g.
+    /// `https://www.vlr.gg/player/9/tenz` or its match history), then behaves
+    /// like [`VlrClient::get_player`]. Fails with [`VlrError::InvalidUrl`] if
+    /// the URL doesn't point at a player.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A vlr.gg player URL or site-relative path.
+    /// * `timespan` - Time window for agent statistics (see [`AgentStatsTimespan`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let player = client
+    ///     .get_player_by_url("https://www.vlr.gg/player/9/tenz", Default::default())
+    ///     .await?;
+    /// println!("{}", player.info.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VlrError::InvalidUrl`]: crate::VlrError::InvalidUrl
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn get_player_by_url(
+        &self,
+        url: &str,
+        timespan: AgentStatsTimespan,
+    ) -> Result<Player> {
+        let player_id = vlr_scraper::vlr_url_id(url, vlr_scraper::VlrUrlKind::Player)?;
+        self.get_player(player_id, timespan).await
+    }
+
+    /// Fetch a complete player profile together with their recent match history.
+    ///
+    /// Behaves like [`VlrClient::get_player`], but additionally fetches the first
+    /// `pages` pages of the player's match history (with bounded concurrency,
+    /// alongside the overview page) and attaches them as
+    /// [`Player::recent_matches`]. With `pages = 0` no match pages are fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_id` - The VLR.gg player ID.
+    /// * `timespan` - Time window for agent statistics (see [`AgentStatsTimespan`]).
+    /// * `pages` - Number of match history pages to include.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{AgentStatsTimespan, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let player = client
+    ///     .get_player_with_matches(17323, AgentStatsTimespan::All, 2)
+    ///     .await?;
+    /// println!("{} recent matches", player.recent_matches.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "players")]
+    #[instrument(skip(self))]
+    pub async fn get_player_with_matches(
+        &self,
+        player_id: u32,
+        timespan: AgentStatsTimespan,
+        pages: u8,
+    ) -> Result<Player> {
+        self.observe(
+            "get_player_with_matches",
+            vlr_scraper::players::info::get_player(&self.http, player_id, timespan, Some(pages))
+                .await,
+        )
+    }
+
+    /// Fetch a vlr.gg team rankings table.
+    ///
+    /// Returns the [`RankedTeam`] rows in ranking order, each with the team's
+    /// points, win–loss record, country, and logo. [`Region::All`] reads the
+    /// world overview, which lists the top teams of every region.
+    /// [`Region::LatinAmerica`] merges vlr.gg's south and north tables,
+    /// ordered by rating and ranked from 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The regional table to read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{Region, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let rankings = client.get_rankings(Region::Europe).await?;
+    /// for team in rankings.iter().take(10) {
+    ///     println!("#{} {} ({:.0})", team.rank, team.team_name, team.rating);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn get_rankings(&self, region: Region) -> Result<Vec<RankedTeam>> {
+        self.observe(
+            "get_rankings",
+            vlr_scraper::teams::rankings::get_rankings(&self.http, region).await,
+        )
+    }
+
+    /// Fetch a paginated list of matches a team has participated in.
+    ///
+    /// Returns a [`TeamMatchListPage`] holding the page's [`MatchItem`]s along
+    /// with the page number and the total number of pages in the history
+    /// (1 for a history without pagination). Each entry contains the match
+    /// ID, league name and icon, participating teams with scores, VOD links,
+    /// and a match start timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The VLR.gg team ID.
+    /// * `page` - Page number (1-indexed).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let matches = client.get_team_matchlist(6530, 1).await?;
+    /// println!("page {} of {}", matches.page, matches.total_pages);
+    /// for m in &matches {
+    ///     let teams: Vec<_> = m.teams.iter().map(|t| t.name.as_str()).collect();
+    ///     println!("[{}] {}", m.league_name, teams.join(" vs "));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn get_team_matchlist(&self, team_id: u32, page: u8) -> Result<TeamMatchListPage> {
+        self.observe(
+            "get_team_matchlist",
+            vlr_scraper::teams::matchlist::get_team_matchlist(&self.http, team_id, page).await,
+        )
+    }
+
+    /// Fetch a team's roster transaction history (joins, leaves, inactive changes).
+    ///
+    /// Returns a `Vec<TeamTransaction>` where each entry contains the date,
+    /// action type, player info (id, alias, real name, country code), position,
+    /// and an optional reference URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The VLR.gg team ID (found in team page URLs).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let transactions = client.get_team_transactions(6530).await?;
+    /// for txn in &transactions {
+    ///     println!(
+    ///         "{:?} — {} {} ({})",
+    ///         txn.date, txn.action, txn.player_alias, txn.position
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn get_team_transactions(&self, team_id: u32) -> Result<Vec<TeamTransaction>> {
+        self.observe(
+            "get_team_transactions",
+            vlr_scraper::teams::transactions::get_team_transactions(&self.http, team_id, 1).await,
+        )
+    }
+
+    /// Fetch a single page of a team's roster transaction history.
+    ///
+    /// Long-lived organizations spread their transactions across several pages;
+    /// [`VlrClient::get_team_transactions`] only returns the first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The VLR.gg team ID (found in team page URLs).
+    /// * `page` - Page number (1-indexed).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let transactions = client.get_team_transactions_paginated(6530, 2).await?;
+    /// println!("{} transactions on page 2", transactions.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn get_team_transactions_paginated(
+        &self,
+        team_id: u32,
+        page: u8,
+    ) -> Result<Vec<TeamTransaction>> {
+        self.observe(
+            "get_team_transactions_paginated",
+            vlr_scraper::teams::transactions::get_team_transactions(&self.http, team_id, page)
+                .await,
+        )
+    }
+
+    /// Fetch a team's complete roster transaction history across all pages.
+    ///
+    /// Pages are fetched sequentially and concatenated in order; transactions
+    /// repeated on several pages are only returned once.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The VLR.gg team ID (found in team page URLs).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let transactions = client.get_all_team_transactions(6530).await?;
+    /// println!("{} transactions in total", transactions.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn get_all_team_transactions(&self, team_id: u32) -> Result<Vec<TeamTransaction>> {
+        self.observe(
+            "get_all_team_transactions",
+            vlr_scraper::teams::transactions::get_all_team_transactions(&self.http, team_id).await,
+        )
+    }
+
+    /// Fetch site-wide agent pick rates for a region and time window.
+    ///
+    /// Returns one [`AgentMetaStat`] per agent with its overall pick rate and
+    /// a per-map breakdown ([`AgentMapStat`]). Pick rates are fractions
+    /// (0.45 = 45%) and are `None` where the agent wasn't picked. The page
+    /// doesn't publish per-agent win rates, so none are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - Region filter (use [`Region::All`] for the global meta).
+    /// * `timespan` - Time window for the statistics (see [`AgentStatsTimespan`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{AgentStatsTimespan, Region, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let stats = client
+    ///     .get_agent_stats(Region::All, AgentStatsTimespan::Days30)
+    ///     .await?;
+    /// for agent in &stats {
+    ///     println!("{}: {:?}", agent.agent, agent.pick_rate);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stats")]
+    #[instrument(skip(self))]
+    pub async fn get_agent_stats(
+        &self,
+        region: Region,
+        timespan: AgentStatsTimespan,
+    ) -> Result<Vec<AgentMetaStat>> {
+        self.observe(
+            "get_agent_stats",
+            vlr_scraper::stats::agents::get_agent_stats(&self.http, region, timespan).await,
+        )
+    }
+
+    /// Fetch the site-wide player stats leaderboard for a region and time window.
+    ///
+    /// Returns one [`StatLeaderboardRow`] per player, in the order vlr.gg
+    /// lists them. Percentages (KAST, headshot, clutch) are fractions
+    /// (0.74 = 74%).
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - Region filter (use [`Region::All`] for the global leaderboard).
+    /// * `timespan` - Time window for the statistics (see [`AgentStatsTimespan`]).
+    /// * `min_rounds` - Only include players with at least this many rounds played.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{AgentStatsTimespan, Region, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// let rows = client
+    ///     .get_stats(Region::Europe, AgentStatsTimespan::Days60, 200)
+    ///     .await?;
+    /// for row in &rows {
+    ///     println!(
+    ///         "{}: {:?} rating over {} rounds",
+    ///         row.player_name, row.rating, row.rounds
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stats")]
+    #[instrument(skip(self))]
+    pub async fn get_stats(
+        &self,
+        region: Region,
+        timespan: AgentStatsTimespan,
+        min_rounds: u32,
+    ) -> Result<Vec<StatLeaderboardRow>> {
+        self.observe(
+            "get_stats",
+            vlr_scraper::stats::leaderboard::get_stats(&self.http, region, timespan, min_rounds)
+                .await,
+        )
+    }
+
+    /// Fetch a page of the vlr.gg news listing, newest first.
+    ///
+    /// Each [`NewsItem`] carries the article id and slug, its title and
+    /// description, author, date, and comment count.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Page number (1-indexed).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// for article in client.get_news(1).await? {
+    ///     println!(
+    ///         "{} by {} ({} comments)",
+    ///         article.title, article.author, article.comment_count
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "news")]
+    #[instrument(skip(self))]
+    pub async fn get_news(&self, page: u8) -> Result<Vec<NewsItem>> {
+        self.observe(
+            "get_news",
+            vlr_scraper::news::get_news(&self.http, page).await,
+        )
+    }
+
+    /// Search vlr.gg for players, teams, and events by name.
+    ///
+    /// Returns the [`SearchResult`]s in the order vlr.gg ranks them. Use
+    /// [`SearchKind::All`] to search every category at once, or narrow the
+    /// request to one category. To pick a single team or player by name, see
+    /// [`VlrClient::resolve_team`] and [`VlrClient::resolve_player`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The text to search for.
+    /// * `kind` - Which category of results to request (see [`SearchKind`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::{SearchKind, SearchResult, VlrClient};
+    ///
+    /// let client = VlrClient::new();
+    /// for result in client.search("champions", SearchKind::Events).await? {
+    ///     if let SearchResult::Event { id, name } = result {
+    ///         println!("{id}: {name}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn search(&self, query: &str, kind: SearchKind) -> Result<Vec<SearchResult>> {
+        self.observe(
+            "search",
+            vlr_scraper::search::search(&self.http, query, kind).await,
+        )
+    }
+
+    /// Fetch a complete team profile including info, roster, event placements, and total winnings.
+    ///
+    /// The returned [`Team`] contains:
+    /// - [`TeamInfo`] — name, tag, logo URL, country/country code, and social links
+    /// - [`TeamRosterMember`] entries with player/staff info, roles, and captain status
+    /// - [`EventPlacement`] history with stage results and prize earnings
+    /// - Total career winnings as an optional string
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - The VLR.gg team ID (found in team page URLs).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let team = client.get_team(6530).await?;
+    /// println!("{} ({:?})", team.info.name, team.info.tag);
+    /// for member in &team.roster {
+    ///     println!("  {} — {}", member.alias, member.role);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn get_team(&self, team_id: u32) -> Result<Team> {
+        self.observe(
+            "get_team",
+            vlr_scraper::teams::info::get_team(&self.http, team_id).await,
+        )
+    }
+
+    /// Find a team by name using vlr.gg's search.
+    ///
+    /// Matching rules, in order: the first result whose name equals `name`
+    /// (ignoring case and surrounding whitespace), then the first whose URL
+    /// slug equals `name` written as a slug (`"Team Liquid"` → `team-liquid`),
+    /// then the top result. Returns `None` when the search finds no team.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The team name to look up.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// if let Some(team) = client.resolve_team("Sentinels").await? {
+    ///     let team = client.get_team(team.id).await?;
+    ///     println!("{}", team.info.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn resolve_team(&self, name: &str) -> Result<Option<TeamRef>> {
+        self.observe(
+            "resolve_team",
+            vlr_scraper::search::resolve_team(&self.http, name).await,
+        )
+    }
+
+    /// Fetch a complete team profile from a vlr.gg team URL.
+    ///
+    /// Accepts any link to the team's pages (e.g.
+    /// `https://www.vlr.gg/team/2/sentinels` or its transactions page), then
+    /// behaves like [`VlrClient::get_team`]. Fails with
+    /// [`VlrError::InvalidUrl`] if the URL doesn't point at a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A vlr.gg team URL or site-relative path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> vlr_scraper::Result<()> {
+    /// use vlr_scraper::VlrClient;
+    ///
+    /// let client = VlrClient::new();
+    /// let team = client
+    ///     .get_team_by_url("https://www.vlr.gg/team/2/sentinels")
+    ///     .await?;
+    /// println!("{}", team.info.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`VlrError::InvalidUrl`]: crate::VlrError::InvalidUrl
+    #[cfg(feature = "teams")]
+    #[instrument(skip(self))]
+    pub async fn get_team_by_url(&self, url: &str) -> Result<Team> {
+        let team_id = vlr_scraper::vlr_url_id(url, vlr_scraper::VlrUrlKind::Team)?;
+        self.get_team(team_id).await
+    }
+}
+
+impl Default for VlrClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for a [`VlrClient`] with a request timeout, user agent, and retries.
+///
+/// The built client sends `Accept-Language: en` like [`VlrClient::new`].
+/// Cache, metrics, error policy, and base URL are set on the built client
+/// with the `with_*` methods.
+#[derive(Debug, Default, Clone)]
+pub struct VlrClientBuilder {
+    timeout: Option<std::time::Duration>,
+    user_agent: Option<String>,
+    max_retries: u32,
+}
+
+impl VlrClientBuilder {
+    /// A builder with no timeout, reqwest's default user agent, and no
+    /// retries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up on a request that hasn't completed within `timeout`.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send `user_agent` as the `User-Agent` header. vlr.gg may rate-limit
+    /// generic HTTP library user agents, so bulk scrapers should identify
+    /// themselves.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Repeat a request up to `max_retries` more times when it fails without
+    /// a response (connection errors, timeouts), is rate limited (`429`), or
+    /// hits a server error (`5xx`).
+    ///
+    /// Retries wait with exponential backoff starting at half a second, or
+    /// as long as a rate-limited response's `Retry-After` header asks. When a
+    /// rate limit outlasts every retry, or asks to wait more than 30 seconds,
+    /// the request fails with [`VlrError::RateLimited`].
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Build the client.
+    ///
+    /// Fails with [`VlrError::ClientBuild`] when the HTTP client can't be
+    /// created, e.g. because the user agent isn't a valid header value.
+    pub fn build(self) -> Result<VlrClient> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::ACCEPT_LANGUAGE,
+            reqwest::header::HeaderValue::from_static("en"),
+        );
+        let mut http = reqwest::Client::builder().default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        let http = http.build().map_err(VlrError::ClientBuild)?;
+
+        let mut client = VlrClient::with_client(http);
+        client.http.max_retries = self.max_retries;
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_rejects_invalid_user_agent() {
+        let result = VlrClient::builder().user_agent("bad\nagent").build();
+        assert!(matches!(result, Err(VlrError::ClientBuild(_))));
+    }
+
+    #[test]
+    fn test_builder_stores_retries() {
+        let client = VlrClient::builder().max_retries(3).build().unwrap();
+        assert_eq!(client.http.max_retries, 3);
+    }
+
+    #[cfg(feature = "matches")]
+    #[tokio::test]
+    async fn test_get_match_reports_its_endpoint() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::vlr_scraper::tests::{serve, OK};
+
+        #[derive(Default)]
+        struct Endpoints(Mutex<Vec<&'static str>>);
+
+        impl Metrics for Endpoints {
+            fn on_parse_error(&self, endpoint: &'static str, _: &VlrError) {
+                self.0.lock().unwrap().push(endpoint);
+            }
+        }
+
+        let endpoints = Arc::new(Endpoints::default());
+        let client = VlrClient::new()
+            .with_base_url(serve(vec![OK]))
+            .with_metrics(endpoints.clone());
+        assert!(client.get_match(1).await.is_err());
+        assert_eq!(*endpoints.0.lock().unwrap(), ["get_match"]);
     }
 }
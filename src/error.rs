@@ -16,6 +16,20 @@ pub enum VlrError {
         status: reqwest::StatusCode,
     },
 
+    /// The underlying HTTP client could not be built from a
+    /// [`VlrClientBuilder`](crate::VlrClientBuilder)'s settings (e.g. a user
+    /// agent that isn't a valid header value).
+    #[error("failed to build HTTP client: {0}")]
+    ClientBuild(#[source] reqwest::Error),
+
+    /// vlr.gg kept answering `429 Too Many Requests` after every retry.
+    /// `retry_after` is the wait the last response asked for, if any.
+    #[error("rate limited by {url}")]
+    RateLimited {
+        url: String,
+        retry_after: Option<std::time::Duration>,
+    },
+
     /// Failed to read the response body as text.
     #[error("failed to read response body from {url}: {source}")]
     ResponseBody { url: String, source: reqwest::Error },
@@ -32,6 +46,22 @@ pub enum VlrError {
     #[error("failed to parse date: {0}")]
     DateParse(#[from] chrono::ParseError),
 
+    /// Failed to parse a monetary amount from scraped text.
+    #[error("failed to parse money amount: {0:?}")]
+    MoneyParse(String),
+
+    /// Reading or writing an export stream failed.
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An item could not be serialized to or deserialized from JSON.
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A URL isn't a vlr.gg link to the expected kind of page.
+    #[error("not a vlr.gg {expected} URL: {url}")]
+    InvalidUrl { url: String, expected: &'static str },
+
     /// An expected HTML element was not found on the page.
     #[error("expected element not found: {context}")]
     ElementNotFound { context: &'static str },
@@ -44,3 +74,79 @@ impl<'a> From<SelectorErrorKind<'a>> for VlrError {
 }
 
 pub type Result<T> = std::result::Result<T, VlrError>;
+
+/// What to do when a single item on a page (an event, a match in a list, a
+/// game of a match) fails to parse.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorPolicy {
+    /// Fail the whole request with the item's error.
+    Strict,
+    /// Skip the item and log the error as a warning.
+    #[default]
+    Lenient,
+}
+
+impl ErrorPolicy {
+    /// Collect per-item parse results according to the policy.
+    #[cfg(any(
+        feature = "events",
+        feature = "matches",
+        feature = "news",
+        feature = "players",
+        feature = "teams"
+    ))]
+    pub(crate) fn collect<T>(
+        self,
+        items: impl IntoIterator<Item = Result<T>>,
+        item: &'static str,
+    ) -> Result<Vec<T>> {
+        match self {
+            ErrorPolicy::Strict => items.into_iter().collect(),
+            ErrorPolicy::Lenient => Ok(items
+                .into_iter()
+                .filter_map(|result| {
+                    result
+                        .inspect_err(
+                            |e| tracing::warn!(error = %e, item, "skipping unparsable item"),
+                        )
+                        .ok()
+                })
+                .collect()),
+        }
+    }
+}
+
+// The tests only cover `ErrorPolicy::collect`, which needs a scraper feature
+#[cfg(all(
+    test,
+    any(
+        feature = "events",
+        feature = "matches",
+        feature = "news",
+        feature = "players",
+        feature = "teams"
+    )
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_policy_collect() {
+        let items = || {
+            vec![
+                Ok(1),
+                Err(VlrError::ElementNotFound { context: "item" }),
+                Ok(3),
+            ]
+        };
+        assert_eq!(
+            ErrorPolicy::Lenient.collect(items(), "item").unwrap(),
+            vec![1, 3]
+        );
+        assert!(matches!(
+            ErrorPolicy::Strict.collect(items(), "item"),
+            Err(VlrError::ElementNotFound { context: "item" })
+        ));
+        assert_eq!(ErrorPolicy::default(), ErrorPolicy::Lenient);
+    }
+}
@@ -0,0 +1,161 @@
+//! Newline-delimited JSON (NDJSON) helpers for bulk scrapes.
+//!
+//! Each item is written as one JSON object per line, so large scrapes can be
+//! streamed to disk as they are fetched and read back lazily, one item at a
+//! time, instead of buffering whole `Vec`s.
+
+use std::io::{BufRead, Write};
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::Result;
+
+/// Write `items` to `writer` as newline-delimited JSON, one item per line.
+///
+/// Returns the number of items written. The writer is flushed before
+/// returning; wrap files in a [`std::io::BufWriter`] for throughput.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn example() -> vlr_scraper::Result<()> {
+/// use std::fs::File;
+/// use std::io::BufWriter;
+///
+/// use vlr_scraper::VlrClient;
+///
+/// let client = VlrClient::new();
+/// let matches = client.get_event_matchlist(2095).await?;
+///
+/// let file = BufWriter::new(File::create("matches.ndjson")?);
+/// vlr_scraper::export::write_ndjson(file, &matches)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_ndjson<W, T, I>(mut writer: W, items: I) -> Result<usize>
+where
+    W: Write,
+    T: Serialize,
+    I: IntoIterator<Item = T>,
+{
+    let mut count = 0;
+    for item in items {
+        serde_json::to_writer(&mut writer, &item)?;
+        writer.write_all(b"\n")?;
+        count += 1;
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
+/// Read newline-delimited JSON from `reader`, yielding one item per line.
+///
+/// Items are parsed lazily as the iterator advances. Blank lines are
+/// skipped; a malformed line yields an error for that line without ending
+/// the iteration.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn example() -> vlr_scraper::Result<()> {
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Row {
+///     id: u32,
+/// }
+///
+/// let file = BufReader::new(File::open("matches.ndjson")?);
+/// for row in vlr_scraper::export::read_ndjson::<_, Row>(file) {
+///     println!("{}", row?.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_ndjson<R, T>(reader: R) -> NdjsonReader<R, T>
+where
+    R: BufRead,
+    T: DeserializeOwned,
+{
+    NdjsonReader {
+        reader,
+        line: String::new(),
+        _item: PhantomData,
+    }
+}
+
+/// Iterator over the items of an NDJSON stream, created by [`read_ndjson`].
+pub struct NdjsonReader<R, T> {
+    reader: R,
+    line: String,
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<R, T> Iterator for NdjsonReader<R, T>
+where
+    R: BufRead,
+    T: DeserializeOwned,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) if self.line.trim().is_empty() => continue,
+                Ok(_) => return Some(serde_json::from_str(&self.line).map_err(Into::into)),
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        id: u32,
+        name: String,
+        score: Option<u8>,
+    }
+
+    #[test]
+    fn test_ndjson_round_trip() {
+        let rows = vec![
+            Row {
+                id: 1,
+                name: "Sentinels".to_string(),
+                score: Some(2),
+            },
+            Row {
+                id: 2,
+                name: "Line\nbreak".to_string(),
+                score: None,
+            },
+        ];
+
+        let mut buf = Vec::new();
+        assert_eq!(write_ndjson(&mut buf, &rows).unwrap(), 2);
+        assert_eq!(buf.iter().filter(|&&b| b == b'\n').count(), 2);
+
+        let read: Vec<Row> = read_ndjson(buf.as_slice()).collect::<Result<_>>().unwrap();
+        assert_eq!(read, rows);
+    }
+
+    #[test]
+    fn test_read_ndjson_skips_blank_lines_and_reports_bad_lines() {
+        let input = "{\"id\":1,\"name\":\"a\",\"score\":null}\n\nnot json\n";
+        let results: Vec<Result<Row>> = read_ndjson(input.as_bytes()).collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+}
@@ -0,0 +1,88 @@
+//! Uniform fetching by id, for batch requests.
+//!
+//! [`Fetch`] ties a page type to the id it is fetched by, so
+//! [`VlrClient::get_many`](crate::VlrClient::get_many) can fetch a batch of
+//! matches, players, teams, or events the same way:
+//!
+//! ```no_run
+//! # async fn example() {
+//! use vlr_scraper::{Match, VlrClient};
+//!
+//! let client = VlrClient::new();
+//! for (id, result) in client.get_many::<Match>(&[378829, 378830], 4).await {
+//!     match result {
+//!         Ok(m) => println!("{id}: {}", m.label()),
+//!         Err(e) => eprintln!("{id}: {e}"),
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::future::Future;
+
+use crate::client::VlrClient;
+use crate::error::Result;
+#[cfg(feature = "events")]
+use crate::model::EventDetail;
+#[cfg(feature = "matches")]
+use crate::model::Match;
+#[cfg(feature = "teams")]
+use crate::model::Team;
+#[cfg(feature = "players")]
+use crate::model::{AgentStatsTimespan, Player};
+
+/// A page that can be fetched from vlr.gg by its id.
+pub trait Fetch {
+    /// What identifies the page, e.g. a match id.
+    type Id: Clone;
+    /// The parsed page.
+    type Output;
+
+    /// Fetch and parse the page for `id` with `client`.
+    fn fetch(client: &VlrClient, id: Self::Id) -> impl Future<Output = Result<Self::Output>>;
+}
+
+/// Full match details, as returned by [`VlrClient::get_match`].
+#[cfg(feature = "matches")]
+impl Fetch for Match {
+    type Id = u32;
+    type Output = Match;
+
+    fn fetch(client: &VlrClient, id: u32) -> impl Future<Output = Result<Match>> {
+        client.get_match(id)
+    }
+}
+
+/// Player profiles with agent stats over the default timespan, as returned
+/// by [`VlrClient::get_player`].
+#[cfg(feature = "players")]
+impl Fetch for Player {
+    type Id = u32;
+    type Output = Player;
+
+    fn fetch(client: &VlrClient, id: u32) -> impl Future<Output = Result<Player>> {
+        client.get_player(id, AgentStatsTimespan::default())
+    }
+}
+
+/// Team profiles, as returned by [`VlrClient::get_team`].
+#[cfg(feature = "teams")]
+impl Fetch for Team {
+    type Id = u32;
+    type Output = Team;
+
+    fn fetch(client: &VlrClient, id: u32) -> impl Future<Output = Result<Team>> {
+        client.get_team(id)
+    }
+}
+
+/// Event overview pages, as returned by [`VlrClient::get_event`].
+#[cfg(feature = "events")]
+impl Fetch for EventDetail {
+    type Id = u32;
+    type Output = EventDetail;
+
+    fn fetch(client: &VlrClient, id: u32) -> impl Future<Output = Result<EventDetail>> {
+        client.get_event(id)
+    }
+}
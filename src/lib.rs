@@ -34,14 +34,27 @@
 //! # }
 //! ```
 
+pub mod cache;
 mod client;
 pub mod error;
+pub mod export;
+pub mod fetch;
+pub mod maps;
+pub mod metrics;
 pub mod model;
 mod vlr_scraper;
 
+// Re-export the cache trait and its in-memory implementation.
+#[cfg(feature = "lru-cache")]
+pub use cache::LruCache;
+pub use cache::{Cache, MemoryCache};
 // Re-export the client as the primary public API.
-pub use client::VlrClient;
+pub use client::{VlrClient, VlrClientBuilder};
 // Re-export error types at the crate root for convenience.
-pub use error::{Result, VlrError};
+pub use error::{ErrorPolicy, Result, VlrError};
+// Re-export the batch fetching trait.
+pub use fetch::Fetch;
+// Re-export the metrics trait and its no-op implementation.
+pub use metrics::{Metrics, NoopMetrics};
 // Re-export all model types at the crate root for convenience.
 pub use model::*;
@@ -0,0 +1,49 @@
+//! The competitive map pool.
+//!
+//! vlr.gg has no page listing the active pool, so it is maintained here and
+//! updated when Riot rotates maps.
+
+use crate::model::Map;
+
+/// Maps in the competitive (VCT) pool, alphabetically. Current as of the
+/// VCT 2025 Champions pool.
+const ACTIVE_MAP_POOL: [Map; 7] = [
+    Map::Abyss,
+    Map::Ascent,
+    Map::Bind,
+    Map::Corrode,
+    Map::Haven,
+    Map::Lotus,
+    Map::Sunset,
+];
+
+/// The maps currently in the competitive pool, alphabetically.
+///
+/// # Examples
+///
+/// ```
+/// use vlr_scraper::maps::active_map_pool;
+///
+/// for map in active_map_pool() {
+///     println!("{map}");
+/// }
+/// ```
+pub fn active_map_pool() -> &'static [Map] {
+    &ACTIVE_MAP_POOL
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_active_map_pool_round_trips() {
+        for map in active_map_pool() {
+            assert_eq!(Map::from_str(&map.to_string()), Ok(*map));
+            assert_eq!(Map::from_str(&map.to_string().to_uppercase()), Ok(*map));
+        }
+        assert!(active_map_pool().is_sorted());
+    }
+}
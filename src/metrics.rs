@@ -0,0 +1,86 @@
+//! Per-call telemetry hooks for [`VlrClient`](crate::VlrClient).
+//!
+//! A client built with
+//! [`VlrClient::with_metrics`](crate::VlrClient::with_metrics) reports every
+//! page it fetches from the network, and every call that fails because a
+//! page couldn't be parsed, to the installed [`Metrics`]. Without one, the
+//! client skips reporting entirely.
+//!
+//! ```no_run
+//! # async fn example() -> vlr_scraper::Result<()> {
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::time::Duration;
+//!
+//! use vlr_scraper::{Metrics, VlrClient};
+//!
+//! #[derive(Default)]
+//! struct RequestCounter(AtomicUsize);
+//!
+//! impl Metrics for RequestCounter {
+//!     fn on_request_end(&self, url: &str, status: Option<u16>, elapsed: Duration) {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!         println!("{url}: {status:?} in {elapsed:?}");
+//!     }
+//! }
+//!
+//! let client = VlrClient::new().with_metrics(RequestCounter::default());
+//! client.get_match(378829).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::VlrError;
+
+/// Callbacks invoked by the client around requests and parsing.
+///
+/// Every method has an empty default, so implementations only override the
+/// hooks they care about. Implementations must be shareable across tasks,
+/// since a client can run several requests concurrently.
+pub trait Metrics: Send + Sync {
+    /// Called before a page is requested from the network. Pages served from
+    /// the cache are not reported.
+    fn on_request_start(&self, url: &str) {
+        let _ = url;
+    }
+
+    /// Called once a network request finished, successfully or not.
+    ///
+    /// `status` is the HTTP status code, or `None` when no response arrived
+    /// (network, DNS, TLS failures). `elapsed` spans from sending the request
+    /// to reading the full body.
+    fn on_request_end(&self, url: &str, status: Option<u16>, elapsed: Duration) {
+        let _ = (url, status, elapsed);
+    }
+
+    /// Called when a client method fails because its page couldn't be
+    /// parsed. `endpoint` is the name of the method, like `"get_team"`.
+    ///
+    /// Failed requests are reported through
+    /// [`on_request_end`](Metrics::on_request_end) instead.
+    fn on_parse_error(&self, endpoint: &'static str, error: &VlrError) {
+        let _ = (endpoint, error);
+    }
+}
+
+/// A [`Metrics`] that ignores every event.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+impl<M: Metrics + ?Sized> Metrics for Arc<M> {
+    fn on_request_start(&self, url: &str) {
+        (**self).on_request_start(url);
+    }
+
+    fn on_request_end(&self, url: &str, status: Option<u16>, elapsed: Duration) {
+        (**self).on_request_end(url, status, elapsed);
+    }
+
+    fn on_parse_error(&self, endpoint: &'static str, error: &VlrError) {
+        (**self).on_parse_error(endpoint, error);
+    }
+}
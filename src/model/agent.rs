@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+/// The role class of a Valorant agent.
+///
+/// Parses case-insensitively from vlr.gg's labels (`"Duelist"`,
+/// `"controller"`), and displays as the proper name.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum_macros::Display,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum AgentRole {
+    Duelist,
+    Initiator,
+    Controller,
+    Sentinel,
+}
+
+/// A Valorant agent.
+///
+/// Parses case-insensitively from the names vlr.gg shows (`"Jett"`,
+/// `"kayo"`), and displays as the proper name.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum_macros::Display,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum Agent {
+    Astra,
+    Breach,
+    Brimstone,
+    Chamber,
+    Clove,
+    Cypher,
+    Deadlock,
+    Fade,
+    Gekko,
+    Harbor,
+    Iso,
+    Jett,
+    #[strum(to_string = "KAY/O", serialize = "kayo")]
+    KayO,
+    Killjoy,
+    Neon,
+    Omen,
+    Phoenix,
+    Raze,
+    Reyna,
+    Sage,
+    Skye,
+    Sova,
+    Tejo,
+    Veto,
+    Viper,
+    Vyse,
+    Waylay,
+    Yoru,
+}
+
+impl Agent {
+    /// The agent's role class.
+    pub fn role(&self) -> AgentRole {
+        match self {
+            Agent::Iso
+            | Agent::Jett
+            | Agent::Neon
+            | Agent::Phoenix
+            | Agent::Raze
+            | Agent::Reyna
+            | Agent::Waylay
+            | Agent::Yoru => AgentRole::Duelist,
+            Agent::Breach
+            | Agent::Fade
+            | Agent::Gekko
+            | Agent::KayO
+            | Agent::Skye
+            | Agent::Sova
+            | Agent::Tejo => AgentRole::Initiator,
+            Agent::Astra
+            | Agent::Brimstone
+            | Agent::Clove
+            | Agent::Harbor
+            | Agent::Omen
+            | Agent::Viper => AgentRole::Controller,
+            Agent::Chamber
+            | Agent::Cypher
+            | Agent::Deadlock
+            | Agent::Killjoy
+            | Agent::Sage
+            | Agent::Veto
+            | Agent::Vyse => AgentRole::Sentinel,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_agent() {
+        assert_eq!(Agent::from_str("jett"), Ok(Agent::Jett));
+        assert_eq!(Agent::from_str("KAY/O"), Ok(Agent::KayO));
+        assert_eq!(Agent::from_str("kayo"), Ok(Agent::KayO));
+        assert_eq!(Agent::KayO.to_string(), "KAY/O");
+        assert!(Agent::from_str("Not An Agent").is_err());
+        assert_eq!(Agent::Omen.role(), AgentRole::Controller);
+        assert_eq!(Agent::Killjoy.role(), AgentRole::Sentinel);
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// The playoff bracket of an event, as drawn on its overview page.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Bracket {
+    /// Bracket columns in render order: upper bracket rounds first, then
+    /// lower bracket rounds. Empty for events without a bracket.
+    pub columns: Vec<BracketColumn>,
+}
+
+/// One round (column) of a bracket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BracketColumn {
+    /// The column heading, e.g. `"Upper Quarterfinals"`.
+    pub label: String,
+    /// Whether the column belongs to the lower bracket.
+    pub is_lower: bool,
+    pub matches: Vec<BracketMatch>,
+}
+
+/// A single match slot in a bracket column.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BracketMatch {
+    /// `None` for slots that don't link to a match page yet.
+    pub match_id: Option<u32>,
+    pub team1: String,
+    pub team2: String,
+    pub score1: Option<u8>,
+    pub score2: Option<u8>,
+    /// The label of the column the match sits in.
+    pub round_label: String,
+}
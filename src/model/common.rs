@@ -1,15 +1,53 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+use super::money::Money;
+
+/// Version of the serialized layout of [`Match`](super::Match),
+/// [`Player`](super::Player), and [`Team`](super::Team), stored in their
+/// `schema_version` field.
+///
+/// Bump it whenever a field of these types, or of a type they contain, is
+/// removed, renamed, or changes meaning, so readers of stored JSON can detect
+/// and migrate data written by an older version. A new field only avoids the
+/// bump when older JSON still loads without it, i.e. when it's an `Option` or
+/// marked `#[serde(default)]`. JSON written before `schema_version` existed
+/// reads as version 0.
+pub const SCHEMA_VERSION: u16 = 1;
 
 /// A social media link from a profile.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Social {
+    /// [`platform_kind`](Self::platform_kind) as lowercase text, e.g.
+    /// `"twitter"`.
     pub platform: String,
+    #[serde(default)]
+    pub platform_kind: SocialPlatform,
     pub url: String,
     pub display_text: String,
+    /// Follower count, when the page decorates the link with one.
+    pub followers: Option<u64>,
+}
+
+/// The platform a social link points to, inferred from its URL.
+///
+/// Displays lowercase (`"twitter"`, `"youtube"`).
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum SocialPlatform {
+    /// Twitter, including `x.com` links.
+    Twitter,
+    Twitch,
+    Instagram,
+    YouTube,
+    TikTok,
+    #[default]
+    Other,
 }
 
 /// A placement history at a single event.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventPlacement {
     pub event_id: u32,
     pub event_slug: String,
@@ -20,10 +58,11 @@ pub struct EventPlacement {
 }
 
 /// A single placement entry within an event (stage + result).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlacementEntry {
     pub stage: String,
     pub placement: String,
     pub prize: Option<String>,
+    pub prize_money: Option<Money>,
     pub team_name: Option<String>,
 }
@@ -1,6 +1,10 @@
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 
+use super::match_detail::MatchStream;
+use super::money::Money;
+
 /// Filter for the type of events to retrieve.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EventType {
@@ -9,7 +13,7 @@ pub enum EventType {
 }
 
 /// Paginated response containing a list of events.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventsData {
     pub events: Vec<Event>,
     pub page: u8,
@@ -17,7 +21,7 @@ pub struct EventsData {
 }
 
 /// A single esports event (tournament/league).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
     pub status: EventStatus,
     pub region: String,
@@ -27,12 +31,137 @@ pub struct Event {
     pub href: String,
     pub icon_url: String,
     pub price: String,
+    pub price_money: Option<Money>,
     pub dates: String,
+    /// First day of the event, parsed from [`dates`](Self::dates). `None`
+    /// while the dates are TBD.
+    pub start_date: Option<NaiveDate>,
+    /// Last day of the event; equal to `start_date` for single-day events.
+    pub end_date: Option<NaiveDate>,
+    /// The competitive tier the listing marks the event with, if any.
+    pub tier: Option<EventTier>,
+    /// Whether the listing shows a live indicator (matches being played now).
+    #[serde(default)]
+    pub has_live_matches: bool,
+}
+
+impl Event {
+    /// Number of days the event runs, counting both the first and last day
+    /// (so a single-day event lasts 1). `None` while the dates are TBD.
+    pub fn duration_days(&self) -> Option<u32> {
+        let (start, end) = self.start_date.zip(self.end_date)?;
+        u32::try_from((end - start).num_days() + 1).ok()
+    }
+
+    /// Whether the event starts and ends in different calendar months.
+    pub fn spans_months(&self) -> bool {
+        self.start_date
+            .zip(self.end_date)
+            .is_some_and(|(start, end)| (start.year(), start.month()) != (end.year(), end.month()))
+    }
+}
+
+/// Details parsed from a single event's overview page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventDetail {
+    pub id: u32,
+    pub title: String,
+    /// Whether the event is upcoming, ongoing, or completed, read from the
+    /// event page itself.
+    pub status: EventStatus,
+    /// The header's date range as shown, e.g. `"Aug 1, 2024 - Aug 25, 2024"`.
+    pub dates: Option<String>,
+    /// The header's prize pool text; `None` when the event has none listed.
+    pub prize_pool: Option<String>,
+    pub location: Option<String>,
+    pub organizer: Option<String>,
+    /// Stage names from the page's stage navigation (e.g. `"Group Stage"`,
+    /// `"Playoffs"`), in page order.
+    pub stages: Vec<String>,
+    pub streams: Vec<MatchStream>,
+    /// Final standings from the prize distribution table, best first.
+    pub placements: Vec<EventFinalPlacement>,
+    /// Teams taking part, in the order the teams card lists them.
+    pub participants: Vec<EventParticipant>,
+}
+
+/// A group's table from an event's group stage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupStanding {
+    /// The group's name as shown in the table header, e.g. `"Group A"`.
+    pub name: String,
+    /// Table rows, in the order the page ranks them.
+    pub rows: Vec<StandingRow>,
+}
+
+/// A team's row in a group stage table. Columns the table doesn't show are 0.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandingRow {
+    pub team_id: Option<u32>,
+    pub team_name: String,
+    pub wins: u32,
+    pub losses: u32,
+    /// Maps won minus maps lost.
+    pub map_diff: i32,
+    /// Rounds won minus rounds lost.
+    pub round_diff: i32,
+}
+
+/// A team slot in an event's list of participants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventParticipant {
+    /// `None` for qualified-but-TBD slots.
+    pub team_id: Option<u32>,
+    pub team_slug: Option<String>,
+    pub team_name: String,
+    pub logo_url: Option<String>,
+    pub seed: Option<u32>,
+    pub group: Option<String>,
+    pub status: ParticipantStatus,
+}
+
+/// Whether a participant is still in the running.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum ParticipantStatus {
+    #[default]
+    Active,
+    Eliminated,
+}
+
+/// A team's final placement in an event, as listed in its prize distribution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventFinalPlacement {
+    /// Placement as shown, e.g. `"1st"` or `"5th–6th"`.
+    pub place: String,
+    /// The best rank covered by [`place`](Self::place), e.g. `5` for
+    /// `"5th–6th"`.
+    pub rank: Option<u32>,
+    /// `None` when the slot is still TBD.
+    pub team_id: Option<u32>,
+    pub team_slug: Option<String>,
+    pub team_name: Option<String>,
+    pub team_logo_url: Option<String>,
+    /// The prize as shown; `None` for blank or `"—"` cells.
+    pub prize: Option<String>,
+    pub prize_money: Option<Money>,
+    /// Whether the prize is a concrete amount rather than a placeholder
+    /// like `"TBD"` or `"—"`, so unconfirmed prizes can be left out of sums.
+    pub is_confirmed: bool,
 }
 
 /// The current status of an event.
 #[derive(
-    Debug, Default, Clone, Serialize, EnumString, strum_macros::Display, strum_macros::FromRepr,
+    Debug,
+    Default,
+    Clone,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum_macros::Display,
+    strum_macros::FromRepr,
 )]
 #[strum(serialize_all = "lowercase")]
 pub enum EventStatus {
@@ -44,9 +173,40 @@ pub enum EventStatus {
     Unknown,
 }
 
+/// The competitive tier of an event, as marked on the events listing.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum EventTier {
+    /// Top-tier VCT events.
+    Vct,
+    /// Valorant Challengers Leagues.
+    Vcl,
+    /// Third-tier events.
+    T3,
+    /// Game Changers events.
+    Gc,
+    Collegiate,
+    Offseason,
+}
+
 /// Region filter for event queries.
-#[derive(Debug, Clone, strum_macros::Display)]
-#[strum(serialize_all = "kebab-case")]
+///
+/// Displays, parses, and (de)serializes as the kebab-case slug, e.g.
+/// `"north-america"`.
+#[derive(Debug, Clone, Serialize, Deserialize, EnumString, strum_macros::Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case", ascii_case_insensitive)]
 pub enum Region {
     All,
     NorthAmerica,
@@ -61,3 +221,28 @@ pub enum Region {
     GameChangers,
     Collegiate,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_region() {
+        assert!(matches!(
+            Region::from_str("north-america"),
+            Ok(Region::NorthAmerica)
+        ));
+        assert!(matches!(Region::from_str("KOREA"), Ok(Region::Korea)));
+        assert!(Region::from_str("atlantis").is_err());
+        for region in [Region::MiddleEastNorthAfrica, Region::GameChangers] {
+            let slug = region.to_string();
+            assert_eq!(
+                serde_json::to_string(&region).unwrap(),
+                format!("\"{slug}\"")
+            );
+            assert!(Region::from_str(&slug).is_ok());
+        }
+    }
+}
@@ -1,24 +1,46 @@
 use chrono::NaiveDateTime;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A list of matches belonging to a particular event.
 pub type EventMatchList = Vec<EventMatchListItem>;
 
 /// Summary information for a single match within an event.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventMatchListItem {
     pub id: u32,
     pub slug: String,
     pub href: String,
+    /// Scheduled time of the match; `None` while it is live or when the page
+    /// shows no time (e.g. "TBD").
     pub date_time: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub status: MatchListStatus,
     pub teams: Vec<EventMatchListTeam>,
     pub tags: Vec<String>,
+    /// Whether one of the [`tags`](Self::tags) marks a VOD.
+    #[serde(default)]
+    pub has_vod: bool,
+    /// The series format tag (e.g. `"Bo3"`), if one of the tags is a format.
+    pub format_tag: Option<String>,
     pub event_text: String,
     pub event_series_text: String,
 }
 
+/// Whether a match list entry is upcoming, being played, or finished.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum MatchListStatus {
+    Upcoming,
+    Live,
+    Completed,
+    #[default]
+    Unknown,
+}
+
 /// Team info as shown in a match list entry.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventMatchListTeam {
     pub name: String,
     pub is_winner: bool,
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+/// A Valorant map.
+///
+/// Parses case-insensitively from the names vlr.gg shows (`"Ascent"`,
+/// `"ICEBOX"`), and displays as the proper name.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumString,
+    strum_macros::Display,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum Map {
+    Abyss,
+    Ascent,
+    Bind,
+    Breeze,
+    Corrode,
+    Fracture,
+    Haven,
+    Icebox,
+    Lotus,
+    Pearl,
+    Split,
+    Sunset,
+}
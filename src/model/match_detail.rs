@@ -1,9 +1,27 @@
-use chrono::NaiveDateTime;
-use serde::Serialize;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::agent::Agent;
+use super::map::Map;
+
+/// Options controlling how much of a match page is parsed.
+#[derive(Debug, Clone, Default)]
+pub struct MatchFetchOptions {
+    /// Zero-based indices (in page order) of the games to parse. `None`
+    /// parses every game; other games are skipped and left out of
+    /// [`Match::games`].
+    pub only_games: Option<Vec<usize>>,
+}
 
 /// Full details of a single match, including all games played.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Match {
+    /// The [`SCHEMA_VERSION`](super::SCHEMA_VERSION) this value was
+    /// produced with; 0 for JSON written before versioning.
+    #[serde(default)]
+    pub schema_version: u16,
     pub id: u32,
     pub header: MatchHeader,
     pub streams: Vec<MatchStream>,
@@ -13,26 +31,243 @@ pub struct Match {
     pub past_matches: Vec<TeamPastMatches>,
     pub performance: Option<MatchPerformance>,
     pub economy: Option<MatchEconomy>,
+    /// The team that started a grand final with a map advantage, if any.
+    pub map_advantage_team: Option<u32>,
+    /// The map pick/ban sequence, in order. Empty when the page records no veto.
+    #[serde(default)]
+    pub veto: Vec<VetoAction>,
+    /// Whether the page is a "TBD vs TBD" bracket placeholder. Such matches
+    /// only carry their header's status and date (the Unix epoch when not
+    /// scheduled yet); teams and games are empty.
+    #[serde(default)]
+    pub is_placeholder: bool,
+}
+
+impl Match {
+    /// Every player row across all games and both teams, in page order.
+    ///
+    /// A player who played several maps appears once per map; see
+    /// [`Match::unique_players`] for one entry per player.
+    pub fn players(&self) -> impl Iterator<Item = &MatchGamePlayer> {
+        self.games
+            .iter()
+            .flat_map(|g| &g.teams)
+            .flat_map(|t| &t.players)
+    }
+
+    /// The distinct players of the match, deduplicated by id and in order of
+    /// first appearance. Rows without a player id are skipped.
+    pub fn unique_players(&self) -> Vec<PlayerRef> {
+        let mut seen = std::collections::HashSet::new();
+        self.players()
+            .filter(|p| p.id != 0 && seen.insert(p.id))
+            .map(PlayerRef::from)
+            .collect()
+    }
+
+    /// Total playing time of the series, summed over maps with a known
+    /// duration. `None` only if no map has one.
+    pub fn total_duration(&self) -> Option<Duration> {
+        self.games
+            .iter()
+            .filter_map(|g| g.duration_secs)
+            .map(|secs| Duration::from_secs(u64::from(secs)))
+            .reduce(|a, b| a + b)
+    }
+
+    /// Performance tables of the game with the given
+    /// [`game_id`](MatchGame::game_id). `None` when the performance tab
+    /// wasn't parsed or has no data for that game.
+    pub fn performance_for(&self, game_id: u32) -> Option<&GamePerformance> {
+        self.performance
+            .as_ref()?
+            .per_game
+            .iter()
+            .find(|g| g.game_id == game_id)
+    }
+
+    /// Kill matrix of the map with the given name (case-insensitive).
+    /// `None` for maps without performance data.
+    pub fn kill_matrix(&self, map: &str) -> Option<&[KillMatrixEntry]> {
+        let game_id = self
+            .games
+            .iter()
+            .find(|g| g.map.eq_ignore_ascii_case(map))?
+            .game_id?;
+        self.performance_for(game_id)
+            .map(|p| p.kill_matrix.as_slice())
+    }
+
+    /// A display label for the series, like `"Grand Final (Bo5)"`.
+    ///
+    /// Uses the round from [`MatchHeader::event_series_name`] (the part after
+    /// the stage, e.g. `"Grand Final"` in `"Playoffs: Grand Final"`) and the
+    /// best-of count, leaving out whichever is missing. Falls back to the
+    /// event title when neither is known.
+    pub fn label(&self) -> String {
+        let header = &self.header;
+        let round = header
+            .event_series_name
+            .rsplit(':')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        match (round.is_empty(), header.best_of()) {
+            (false, Some(n)) => format!("{round} (Bo{n})"),
+            (false, None) => round.to_string(),
+            (true, Some(n)) => format!("Bo{n}"),
+            (true, None) => header.event_title.trim().to_string(),
+        }
+    }
+
+    /// Whether either team has won enough maps to take the series.
+    ///
+    /// Uses the series score from the header when available and falls back to
+    /// counting map wins. Returns `false` when the format's best-of count is
+    /// unknown.
+    pub fn is_series_complete(&self) -> bool {
+        let Some(best_of) = self.header.best_of() else {
+            return false;
+        };
+        let required = best_of / 2 + 1;
+        (0..self.header.teams.len()).any(|i| {
+            let wins = self.header.teams[i].score.unwrap_or_else(|| {
+                self.games
+                    .iter()
+                    .filter(|g| g.teams.get(i).is_some_and(|t| t.is_winner))
+                    .count() as u8
+            });
+            wins >= required
+        })
+    }
 }
 
 /// Header metadata for a match (event info, date, teams).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchHeader {
     pub event_icon: String,
     pub event_title: String,
     pub event_series_name: String,
     pub event_id: u32,
     pub event_slug: String,
+    /// The event's region (e.g. `"EMEA"`, `"North America"`), when the event
+    /// title or series name names one.
+    pub event_region: Option<String>,
+    /// The stage's `series_id`, when the event link points at a specific stage.
+    pub stage_id: Option<u32>,
+    /// The stage's URL slug (e.g. `"playoffs"`), when the event link includes one.
+    pub stage_slug: Option<String>,
+    /// When the match is scheduled, as given by vlr.gg's `data-utc-ts`
+    /// attribute. The value is UTC; [`date_utc`](Self::date_utc) returns the
+    /// same instant with its timezone attached. Placeholder matches that
+    /// aren't scheduled yet hold the Unix epoch.
     pub date: NaiveDateTime,
     pub patch: String,
+    /// [`patch`](Self::patch) as a number; `None` when missing or non-numeric.
+    pub patch_version: Option<PatchVersion>,
+    /// The format text as shown, e.g. `"Bo3"`.
     pub format: String,
+    /// [`format`](Self::format) parsed into a [`MatchFormat`].
+    #[serde(default)]
+    pub format_kind: MatchFormat,
     pub status: String,
+    #[serde(default)]
+    pub status_kind: MatchStatus,
     pub note: String,
     pub teams: Vec<MatchHeaderTeam>,
 }
 
+impl MatchHeader {
+    /// [`date`](Self::date) as a timezone-aware UTC timestamp.
+    pub fn date_utc(&self) -> DateTime<Utc> {
+        self.date.and_utc()
+    }
+
+    /// The number of maps in the series, parsed from [`MatchHeader::format`]
+    /// (`"Bo3"`, `"Best of 5"`, ...).
+    pub fn best_of(&self) -> Option<u8> {
+        MatchFormat::from(self.format.as_str()).best_of()
+    }
+}
+
+/// A game patch like `8.11`. Orders numerically, so `8.2 < 8.11`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PatchVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl PatchVersion {
+    /// Parse `"8.11"` or `"Patch 8.11"`. A bare major version (`"9"`) has
+    /// minor 0. Returns `None` for anything else.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let text = text.strip_prefix("Patch").unwrap_or(text).trim();
+        let (major, minor) = text.split_once('.').unwrap_or((text, "0"));
+        Some(Self {
+            major: major.parse().ok()?,
+            minor: minor.parse().ok()?,
+        })
+    }
+}
+
+impl std::fmt::Display for PatchVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The format of a series.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MatchFormat {
+    /// A best-of-`n` series, e.g. `BestOf(3)` for `"Bo3"`.
+    BestOf(u8),
+    Showmatch,
+    #[default]
+    Unknown,
+}
+
+impl MatchFormat {
+    /// The number of maps for a best-of series.
+    pub fn best_of(self) -> Option<u8> {
+        match self {
+            MatchFormat::BestOf(n) => Some(n),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for MatchFormat {
+    /// Parse `"Bo1"`/`"Bo3"`/`"Bo5"`, `"Best of 3"`, and `"Showmatch"`
+    /// (case-insensitive); anything else is [`MatchFormat::Unknown`].
+    fn from(text: &str) -> Self {
+        let text = text.trim().to_lowercase();
+        if text.contains("showmatch") {
+            return MatchFormat::Showmatch;
+        }
+        text.strip_prefix("best of")
+            .or_else(|| text.strip_prefix("bo"))
+            .and_then(|n| n.trim().parse().ok())
+            .filter(|&n| n > 0)
+            .map_or(MatchFormat::Unknown, MatchFormat::BestOf)
+    }
+}
+
+/// The state of a match, derived from the header's status note and scores.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum MatchStatus {
+    Upcoming,
+    Live,
+    Final,
+    #[default]
+    Unknown,
+}
+
 /// A team as shown in the match header.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchHeaderTeam {
     pub id: u32,
     pub slug: String,
@@ -42,25 +277,58 @@ pub struct MatchHeaderTeam {
     pub icon: String,
 }
 
-/// A stream or VOD link associated with a match.
-#[derive(Debug, Clone, Serialize)]
+/// One step of a match's map veto.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VetoAction {
+    /// The team that banned or picked; `None` for the remaining map or when
+    /// the team couldn't be matched to the header.
+    pub team_id: Option<u32>,
+    pub action: VetoActionKind,
+    pub map: String,
+}
+
+/// What a veto step did with its map.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum VetoActionKind {
+    Ban,
+    Pick,
+    /// The map left over after all bans and picks (the decider).
+    Remains,
+}
+
+/// A stream or VOD link associated with a match or event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchStream {
     pub name: String,
     pub link: String,
+    pub language: Option<String>,
 }
 
 /// Stats for a single game (map) within a match.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchGame {
+    /// vlr.gg's id for the game, shared by the overview, performance, and
+    /// economy tabs.
+    pub game_id: Option<u32>,
     pub map: String,
+    /// [`map`](Self::map) as a typed [`Map`]; `None` for names that aren't a
+    /// known map.
+    pub map_kind: Option<Map>,
     pub picked_by: Option<u32>,
     pub duration: Option<String>,
+    /// [`duration`](Self::duration) in seconds.
+    pub duration_secs: Option<u32>,
+    /// Note attached to the map, such as a technical pause or remake.
+    pub note: Option<String>,
     pub teams: Vec<MatchGameTeam>,
     pub rounds: Vec<MatchGameRound>,
 }
 
 /// Per-team stats for a single game.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchGameTeam {
     pub name: String,
     pub score: Option<u8>,
@@ -68,18 +336,65 @@ pub struct MatchGameTeam {
     pub score_ct: Option<u8>,
     pub is_winner: bool,
     pub players: Vec<MatchGamePlayer>,
+    /// The agents this team's players played, sorted by name; empty for
+    /// maps that weren't played.
+    #[serde(default)]
+    pub composition: Vec<String>,
 }
 
 /// The outcome of a single round within a game.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchGameRound {
     pub round: u8,
     pub winning_team: u32,
-    pub winning_site: String,
+    /// The side the winning team played the round on.
+    pub winning_site: Side,
+    /// The side the first team (in header order) played the round on.
+    pub team1_side: Side,
+    /// The side the second team played the round on.
+    pub team2_side: Side,
+    /// How the winning team won the round.
+    #[serde(default)]
+    pub win_type: RoundWinType,
+}
+
+/// How a round was won, read from the icon in its round square.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RoundWinType {
+    /// The losing team was eliminated.
+    Elimination,
+    /// The spike was defused.
+    Defuse,
+    /// The spike detonated.
+    Detonate,
+    /// The round timer ran out before the spike was planted.
+    Time,
+    /// The round square had no recognizable icon.
+    #[default]
+    Unknown,
+}
+
+/// The side a team plays a round on. Serializes as vlr.gg's `"t"` / `"ct"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Side {
+    #[serde(rename = "t")]
+    Attack,
+    #[serde(rename = "ct")]
+    Defense,
+}
+
+impl Side {
+    /// The side the other team is on.
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Attack => Side::Defense,
+            Side::Defense => Side::Attack,
+        }
+    }
 }
 
 /// A previous head-to-head encounter between the two teams.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeadToHeadMatch {
     pub match_id: u32,
     pub match_slug: String,
@@ -93,14 +408,14 @@ pub struct HeadToHeadMatch {
 }
 
 /// A team's recent past matches.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamPastMatches {
     pub team_id: u32,
     pub matches: Vec<PastMatch>,
 }
 
 /// A single past match from a team's recent history.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PastMatch {
     pub match_id: u32,
     pub match_slug: String,
@@ -113,14 +428,29 @@ pub struct PastMatch {
 }
 
 /// Overall performance data from the performance tab.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchPerformance {
+    /// Kill matrix across all maps.
+    pub kill_matrix: Vec<KillMatrixEntry>,
+    /// Advanced stats across all maps.
+    pub player_performances: Vec<PlayerPerformance>,
+    /// The same tables for each map that has performance data.
+    #[serde(default)]
+    pub per_game: Vec<GamePerformance>,
+}
+
+/// Performance tables of a single map.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GamePerformance {
+    /// Matches [`MatchGame::game_id`].
+    pub game_id: u32,
+    pub map: String,
     pub kill_matrix: Vec<KillMatrixEntry>,
     pub player_performances: Vec<PlayerPerformance>,
 }
 
 /// A single cell in the kill matrix (killer vs victim).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KillMatrixEntry {
     pub killer_id: u32,
     pub victim_id: u32,
@@ -129,7 +459,7 @@ pub struct KillMatrixEntry {
 }
 
 /// Detailed performance stats for a single player.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerPerformance {
     pub player_id: u32,
     pub player_name: String,
@@ -148,13 +478,27 @@ pub struct PlayerPerformance {
 }
 
 /// Economy data from the economy tab.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchEconomy {
+    /// Totals across all maps.
+    pub teams: Vec<TeamEconomy>,
+    /// Per-map breakdowns; maps without an economy table (not yet played)
+    /// are left out.
+    #[serde(default)]
+    pub per_game: Vec<GameEconomy>,
+}
+
+/// Economy breakdown of a single map.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameEconomy {
+    /// Matches [`MatchGame::game_id`].
+    pub game_id: u32,
+    pub map: String,
     pub teams: Vec<TeamEconomy>,
 }
 
 /// Economy breakdown for a single team.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamEconomy {
     pub team_name: String,
     pub pistol_won: u8,
@@ -169,13 +513,59 @@ pub struct TeamEconomy {
 }
 
 /// A player's participation in a single game.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchGamePlayer {
     pub nation: String,
     pub id: u32,
+    /// The id of the team the player played for, resolved from the scoreboard.
+    pub team_id: Option<u32>,
     pub name: String,
+    /// The team tag shown next to the name on the scoreboard, e.g. `"SEN"`.
+    pub team_tag: Option<String>,
     pub slug: String,
+    /// The first agent in [`agents`](Self::agents); empty when none is shown.
     pub agent: String,
+    /// Every agent shown for the player on this map, in page order; more
+    /// than one after a mid-map agent change.
+    #[serde(default)]
+    pub agents: Vec<String>,
+    /// [`agent`](Self::agent) as a typed [`Agent`]; `None` when empty or not
+    /// a known agent.
+    pub agent_kind: Option<Agent>,
+    pub rating: Option<f32>,
+    pub acs: Option<u16>,
+    pub kills: Option<u16>,
+    pub deaths: Option<u16>,
+    pub assists: Option<u16>,
+    pub kd_diff: Option<i16>,
+    pub kast: Option<f32>,
+    pub adr: Option<f32>,
+    /// Total damage dealt, only present on layouts that show a damage column.
+    pub total_damage: Option<u32>,
+    pub hs_pct: Option<f32>,
+    pub first_kills: Option<u16>,
+    pub first_deaths: Option<u16>,
+    pub fk_diff: Option<i16>,
+    pub kpr: Option<f32>,
+    pub dpr: Option<f32>,
+    pub apr: Option<f32>,
+    /// Whether vlr.gg highlights this row as the map's (or series') MVP.
+    #[serde(default)]
+    pub is_mvp: bool,
+    /// Multikill counts, when the overview scoreboard shows them; the
+    /// performance tab has them for every match (see [`PlayerPerformance`]).
+    pub multikills: Option<MultiKills>,
+    /// Stats from attack rounds only; `None` when the scoreboard only shows
+    /// combined stats.
+    pub t_side: Option<PlayerSideStats>,
+    /// Stats from defense rounds only; `None` when the scoreboard only shows
+    /// combined stats.
+    pub ct_side: Option<PlayerSideStats>,
+}
+
+/// A player's scoreboard stats restricted to the rounds of one side.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSideStats {
     pub rating: Option<f32>,
     pub acs: Option<u16>,
     pub kills: Option<u16>,
@@ -189,3 +579,34 @@ pub struct MatchGamePlayer {
     pub first_deaths: Option<u16>,
     pub fk_diff: Option<i16>,
 }
+
+/// How many rounds a player got two, three, four, or five kills in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MultiKills {
+    pub two_k: u8,
+    pub three_k: u8,
+    pub four_k: u8,
+    pub five_k: u8,
+}
+
+/// Identity of a player who appeared in a match, without per-game stats.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlayerRef {
+    pub id: u32,
+    pub name: String,
+    pub slug: String,
+    pub nation: String,
+    pub team_id: Option<u32>,
+}
+
+impl From<&MatchGamePlayer> for PlayerRef {
+    fn from(player: &MatchGamePlayer) -> Self {
+        Self {
+            id: player.id,
+            name: player.name.clone(),
+            slug: player.slug.clone(),
+            nation: player.nation.clone(),
+            team_id: player.team_id,
+        }
+    }
+}
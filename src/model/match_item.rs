@@ -1,11 +1,58 @@
+use std::ops::Deref;
+
 use chrono::NaiveDateTime;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A list of match items (used by both player and team match histories).
 pub type MatchItemList = Vec<MatchItem>;
 
+/// One page of a match history, with the position in the history.
+///
+/// Derefs to the page's [`MatchItemList`], so it can be used like the plain
+/// list returned before pagination info was added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginatedMatchList {
+    pub items: MatchItemList,
+    pub page: u8,
+    /// Number of pages in the history; 1 when there is no pagination control.
+    pub total_pages: u8,
+}
+
+impl PaginatedMatchList {
+    /// Whether the history continues past this page.
+    pub fn has_next_page(&self) -> bool {
+        self.page < self.total_pages
+    }
+}
+
+impl Deref for PaginatedMatchList {
+    type Target = MatchItemList;
+
+    fn deref(&self) -> &MatchItemList {
+        &self.items
+    }
+}
+
+impl IntoIterator for PaginatedMatchList {
+    type Item = MatchItem;
+    type IntoIter = std::vec::IntoIter<MatchItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PaginatedMatchList {
+    type Item = &'a MatchItem;
+    type IntoIter = std::slice::Iter<'a, MatchItem>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
 /// A single match entry in a match history.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchItem {
     pub id: u32,
     pub slug: String,
@@ -15,11 +62,67 @@ pub struct MatchItem {
     pub teams: Vec<MatchItemTeam>,
     pub vods: Vec<String>,
     pub match_start: Option<NaiveDateTime>,
+    /// The result from the perspective of the history's owner (the first team).
+    pub result: Option<MatchResult>,
+}
+
+impl MatchItem {
+    /// The other team in the match, assuming the history's owner is listed first.
+    pub fn opponent(&self) -> Option<&MatchItemTeam> {
+        self.teams.get(1)
+    }
+}
+
+/// The outcome of a match for the player or team whose history it comes from.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum MatchResult {
+    Win,
+    Loss,
+    Draw,
+}
+
+/// Client-side filter over a match history. Unset criteria match everything.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MatchFilter {
+    /// Only keep matches with this result.
+    pub result: Option<MatchResult>,
+    /// Only keep matches against the team with this vlr.gg id.
+    pub opponent_id: Option<u32>,
+}
+
+impl MatchFilter {
+    /// A filter that keeps only matches with the given result.
+    pub fn result(result: MatchResult) -> Self {
+        Self {
+            result: Some(result),
+            ..Self::default()
+        }
+    }
+
+    /// Restrict the filter to matches against the given team.
+    pub fn against(mut self, team_id: u32) -> Self {
+        self.opponent_id = Some(team_id);
+        self
+    }
+
+    /// Whether the match passes every criterion of the filter.
+    pub fn matches(&self, item: &MatchItem) -> bool {
+        self.result.is_none_or(|r| item.result == Some(r))
+            && self
+                .opponent_id
+                .is_none_or(|id| item.opponent().and_then(|t| t.id) == Some(id))
+    }
 }
 
 /// Team information as shown in a match history item.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchItemTeam {
+    /// The team's vlr.gg id, when the item links to the team page.
+    pub id: Option<u32>,
+    pub slug: Option<String>,
     pub name: String,
     pub tag: String,
     pub logo_url: String,
@@ -1,15 +1,31 @@
+mod agent;
+mod bracket;
 mod common;
 mod event;
 mod event_matchlist;
+mod map;
 mod match_detail;
 mod match_item;
+mod money;
+mod news;
+mod ordering;
 mod player;
+mod search;
+mod stats;
 mod team;
 
+pub use agent::*;
+pub use bracket::*;
 pub use common::*;
 pub use event::*;
 pub use event_matchlist::*;
+pub use map::*;
 pub use match_detail::*;
 pub use match_item::*;
+pub use money::*;
+pub use news::*;
+pub use ordering::*;
 pub use player::*;
+pub use search::*;
+pub use stats::*;
 pub use team::*;
@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::VlrError;
+
+/// The currency a monetary amount is denominated in.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum Currency {
+    Usd,
+    Eur,
+    Gbp,
+    Jpy,
+    Krw,
+    Brl,
+}
+
+impl Currency {
+    /// Match a currency symbol (`$`, `€`, ...) or ISO code (`USD`, `EUR`, ...).
+    fn from_marker(marker: &str) -> Option<Self> {
+        match marker.to_ascii_uppercase().as_str() {
+            "$" | "US$" | "USD" => Some(Currency::Usd),
+            "€" | "EUR" => Some(Currency::Eur),
+            "£" | "GBP" => Some(Currency::Gbp),
+            "¥" | "JPY" => Some(Currency::Jpy),
+            "₩" | "KRW" => Some(Currency::Krw),
+            "R$" | "BRL" => Some(Currency::Brl),
+            _ => None,
+        }
+    }
+}
+
+/// A monetary amount parsed from prize or winnings text.
+///
+/// Parsing via [`FromStr`] accepts currency symbols or ISO codes on either
+/// side of the number, thousands separators, and `K`/`M`/`B` suffixes
+/// (`"$1.2M"`, `"€50,000"`, `"250K USD"`). Amounts without any currency
+/// marker are assumed to be USD, which is what vlr.gg displays. Placeholder
+/// text such as `"TBD"` or `"—"` fails to parse, so scraped fields holding a
+/// `Money` are `None` in that case.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Money {
+    pub amount: f64,
+    pub currency: Currency,
+}
+
+/// Currency markers to strip from the start of an amount, longest first so
+/// `"R$"`/`"US$"` win over `"$"`.
+const PREFIX_MARKERS: &[&str] = &["US$", "R$", "$", "€", "£", "¥", "₩"];
+
+/// Currency markers to strip from the end of an amount.
+const SUFFIX_MARKERS: &[&str] = &["USD", "EUR", "GBP", "JPY", "KRW", "BRL", "€", "$"];
+
+impl FromStr for Money {
+    type Err = VlrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || VlrError::MoneyParse(s.to_string());
+
+        let mut rest = s.replace('\u{a0}', " ");
+        rest = rest.trim().trim_end_matches('+').trim().to_string();
+
+        let mut currency = None;
+        if let Some(marker) = PREFIX_MARKERS.iter().find(|m| rest.starts_with(**m)) {
+            currency = Currency::from_marker(marker);
+            rest = rest[marker.len()..].trim_start().to_string();
+        }
+        if let Some(marker) = SUFFIX_MARKERS
+            .iter()
+            .find(|m| rest.to_ascii_uppercase().ends_with(**m))
+        {
+            let suffix_currency = Currency::from_marker(marker);
+            if currency.is_some() && currency != suffix_currency {
+                return Err(err());
+            }
+            currency = suffix_currency;
+            rest = rest[..rest.len() - marker.len()].trim_end().to_string();
+        }
+
+        let multiplier = match rest.chars().last() {
+            Some('k' | 'K') => 1_000.0,
+            Some('m' | 'M') => 1_000_000.0,
+            Some('b' | 'B') => 1_000_000_000.0,
+            _ => 1.0,
+        };
+        if multiplier > 1.0 {
+            rest.pop();
+        }
+
+        let digits = rest.trim().replace(',', "");
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            return Err(err());
+        }
+        let amount: f64 = digits.parse().map_err(|_| err())?;
+
+        Ok(Money {
+            // Round to cents so suffix multiplication doesn't leave float noise
+            amount: (amount * multiplier * 100.0).round() / 100.0,
+            currency: currency.unwrap_or(Currency::Usd),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn money(amount: f64, currency: Currency) -> Money {
+        Money { amount, currency }
+    }
+
+    #[test]
+    fn test_parse_plain_dollars() {
+        assert_eq!(
+            "$250".parse::<Money>().unwrap(),
+            money(250.0, Currency::Usd)
+        );
+        assert_eq!(
+            "$1,000,000".parse::<Money>().unwrap(),
+            money(1_000_000.0, Currency::Usd)
+        );
+        assert_eq!(
+            "$12,500.50".parse::<Money>().unwrap(),
+            money(12_500.5, Currency::Usd)
+        );
+    }
+
+    #[test]
+    fn test_parse_other_currencies() {
+        assert_eq!(
+            "€50,000".parse::<Money>().unwrap(),
+            money(50_000.0, Currency::Eur)
+        );
+        assert_eq!(
+            "£7,500".parse::<Money>().unwrap(),
+            money(7_500.0, Currency::Gbp)
+        );
+        assert_eq!(
+            "R$20,000".parse::<Money>().unwrap(),
+            money(20_000.0, Currency::Brl)
+        );
+        assert_eq!(
+            "10000 €".parse::<Money>().unwrap(),
+            money(10_000.0, Currency::Eur)
+        );
+        assert_eq!(
+            "5,000 KRW".parse::<Money>().unwrap(),
+            money(5_000.0, Currency::Krw)
+        );
+    }
+
+    #[test]
+    fn test_parse_suffixes() {
+        assert_eq!(
+            "$1.2M".parse::<Money>().unwrap(),
+            money(1_200_000.0, Currency::Usd)
+        );
+        assert_eq!(
+            "$250K".parse::<Money>().unwrap(),
+            money(250_000.0, Currency::Usd)
+        );
+        assert_eq!(
+            "€3.5k".parse::<Money>().unwrap(),
+            money(3_500.0, Currency::Eur)
+        );
+        assert_eq!("$2B".parse::<Money>().unwrap(), money(2e9, Currency::Usd));
+    }
+
+    #[test]
+    fn test_parse_symbol_and_code() {
+        assert_eq!(
+            "$2,250,000 USD".parse::<Money>().unwrap(),
+            money(2_250_000.0, Currency::Usd)
+        );
+        assert!("$100 EUR".parse::<Money>().is_err());
+    }
+
+    #[test]
+    fn test_parse_whitespace_and_plus() {
+        assert_eq!(
+            "\u{a0}$1,000+ ".parse::<Money>().unwrap(),
+            money(1_000.0, Currency::Usd)
+        );
+        assert_eq!(
+            "1500".parse::<Money>().unwrap(),
+            money(1_500.0, Currency::Usd)
+        );
+    }
+
+    #[test]
+    fn test_parse_placeholders() {
+        for text in ["TBD", "—", "–", "-", "", "  ", "$", "N/A", "$TBD"] {
+            assert!(text.parse::<Money>().is_err(), "expected {text:?} to fail");
+        }
+    }
+}
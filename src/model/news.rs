@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// An article from the vlr.gg news listing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsItem {
+    pub id: u32,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub author: String,
+    /// Publication date as shown on the listing, e.g. `"April 27, 2024"`.
+    pub date: String,
+    /// Number of comments; 0 when the listing shows none.
+    pub comment_count: u32,
+}
@@ -0,0 +1,113 @@
+use std::cmp::Ordering;
+
+use chrono::{NaiveDateTime, NaiveTime};
+
+use super::event_matchlist::EventMatchListItem;
+use super::match_detail::Match;
+use super::match_item::MatchItem;
+use super::team::TeamTransaction;
+
+/// A scraped item with a (possibly unknown) point in time, used to sort feeds
+/// chronologically without per-type key closures.
+pub trait Dated {
+    /// The item's timestamp, or `None` when vlr.gg shows it as TBD.
+    fn timestamp(&self) -> Option<NaiveDateTime>;
+}
+
+impl Dated for MatchItem {
+    fn timestamp(&self) -> Option<NaiveDateTime> {
+        self.match_start
+    }
+}
+
+impl Dated for EventMatchListItem {
+    fn timestamp(&self) -> Option<NaiveDateTime> {
+        self.date_time
+    }
+}
+
+impl Dated for Match {
+    fn timestamp(&self) -> Option<NaiveDateTime> {
+        Some(self.header.date)
+    }
+}
+
+impl Dated for TeamTransaction {
+    fn timestamp(&self) -> Option<NaiveDateTime> {
+        self.date.map(|d| d.and_time(NaiveTime::MIN))
+    }
+}
+
+/// Compare two items oldest first, placing items without a timestamp last.
+pub fn compare_by_date<T: Dated>(a: &T, b: &T) -> Ordering {
+    match (a.timestamp(), b.timestamp()) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Sort items oldest first; items without a timestamp go last. The sort is
+/// stable, so undated items keep their scraped order.
+pub fn sort_by_date<T: Dated>(items: &mut [T]) {
+    items.sort_by(compare_by_date);
+}
+
+/// Sort items newest first; items without a timestamp still go last.
+pub fn sort_by_date_desc<T: Dated>(items: &mut [T]) {
+    items.sort_by(|a, b| match (a.timestamp(), b.timestamp()) {
+        (Some(a), Some(b)) => b.cmp(&a),
+        _ => compare_by_date(a, b),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn item(id: u32, day: Option<u32>) -> EventMatchListItem {
+        EventMatchListItem {
+            id,
+            slug: String::new(),
+            href: String::new(),
+            status: Default::default(),
+            date_time: day.map(|d| {
+                NaiveDate::from_ymd_opt(2024, 8, d)
+                    .unwrap()
+                    .and_time(NaiveTime::MIN)
+            }),
+            teams: Vec::new(),
+            tags: Vec::new(),
+            has_vod: false,
+            format_tag: None,
+            event_text: String::new(),
+            event_series_text: String::new(),
+        }
+    }
+
+    fn ids(items: &[EventMatchListItem]) -> Vec<u32> {
+        items.iter().map(|i| i.id).collect()
+    }
+
+    #[test]
+    fn test_sort_by_date_places_tbd_last() {
+        let mut items = vec![
+            item(1, None),
+            item(2, Some(5)),
+            item(3, Some(1)),
+            item(4, None),
+        ];
+        sort_by_date(&mut items);
+        assert_eq!(ids(&items), vec![3, 2, 1, 4]);
+    }
+
+    #[test]
+    fn test_sort_by_date_desc_places_tbd_last() {
+        let mut items = vec![item(1, None), item(2, Some(1)), item(3, Some(5))];
+        sort_by_date_desc(&mut items);
+        assert_eq!(ids(&items), vec![3, 2, 1]);
+    }
+}
@@ -1,7 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
+use super::agent::{Agent, AgentRole};
 use super::common::{EventPlacement, Social};
 use super::match_item::{MatchItem, MatchItemList, MatchItemTeam};
+use super::money::Money;
 
 /// Backward-compatible alias for [`MatchItemList`].
 pub type PlayerMatchList = MatchItemList;
@@ -13,8 +15,12 @@ pub type PlayerMatchListItem = MatchItem;
 pub type PlayerMatchListTeam = MatchItemTeam;
 
 /// Complete player profile data from a player overview page.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
+    /// The [`SCHEMA_VERSION`](super::SCHEMA_VERSION) this value was
+    /// produced with; 0 for JSON written before versioning.
+    #[serde(default)]
+    pub schema_version: u16,
     pub info: PlayerInfo,
     pub current_teams: Vec<PlayerTeam>,
     pub past_teams: Vec<PlayerTeam>,
@@ -22,10 +28,13 @@ pub struct Player {
     pub news: Vec<PlayerNewsItem>,
     pub event_placements: Vec<EventPlacement>,
     pub total_winnings: Option<String>,
+    pub total_winnings_money: Option<Money>,
+    #[serde(default)]
+    pub recent_matches: Vec<PlayerMatchListItem>,
 }
 
 /// Basic profile information for a player.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerInfo {
     pub id: u32,
     pub name: String,
@@ -34,10 +43,17 @@ pub struct PlayerInfo {
     pub country: Option<String>,
     pub country_code: Option<String>,
     pub socials: Vec<Social>,
+    /// Matches played in the selected timespan, when the header shows it.
+    pub matches_played: Option<u32>,
+    /// Win rate in the selected timespan as a fraction (0.55 = 55%).
+    pub win_rate: Option<f32>,
+    /// The role vlr.gg's own badge labels the player with, if the header
+    /// shows one.
+    pub primary_role: Option<AgentRole>,
 }
 
 /// A team associated with a player (current or past).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerTeam {
     pub id: u32,
     pub slug: String,
@@ -48,9 +64,12 @@ pub struct PlayerTeam {
 }
 
 /// Agent usage and performance statistics for a player.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerAgentStats {
     pub agent: String,
+    /// [`agent`](Self::agent) as a typed [`Agent`]; `None` when it's not a
+    /// known agent.
+    pub agent_kind: Option<Agent>,
     pub usage_count: u32,
     pub usage_pct: f32,
     pub rounds: u32,
@@ -68,6 +87,78 @@ pub struct PlayerAgentStats {
     pub assists: u32,
     pub first_kills: u32,
     pub first_deaths: u32,
+    /// Clutch success rate as a fraction, when the table has a clutch column.
+    pub clutch_pct: Option<f32>,
+    /// Share of rounds with the opening kill as a fraction, when shown.
+    pub first_kill_pct: Option<f32>,
+    /// The rating model behind [`rating`](Self::rating) (e.g. `"2.0"`), when
+    /// the table header names one.
+    pub rating_version: Option<String>,
+}
+
+/// A numeric column of [`PlayerAgentStats`], used to rank a player's agents.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Hash,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum StatMetric {
+    UsageCount,
+    UsagePct,
+    Rounds,
+    Rating,
+    Acs,
+    Kd,
+    Adr,
+    Kast,
+    Kpr,
+    Apr,
+    Fkpr,
+    Fdpr,
+    Kills,
+    Deaths,
+    Assists,
+    FirstKills,
+    FirstDeaths,
+}
+
+impl PlayerAgentStats {
+    /// The value of `metric` for this agent, as an `f32`.
+    pub fn metric(&self, metric: StatMetric) -> f32 {
+        match metric {
+            StatMetric::UsageCount => self.usage_count as f32,
+            StatMetric::UsagePct => self.usage_pct,
+            StatMetric::Rounds => self.rounds as f32,
+            StatMetric::Rating => self.rating,
+            StatMetric::Acs => self.acs,
+            StatMetric::Kd => self.kd,
+            StatMetric::Adr => self.adr,
+            StatMetric::Kast => self.kast,
+            StatMetric::Kpr => self.kpr,
+            StatMetric::Apr => self.apr,
+            StatMetric::Fkpr => self.fkpr,
+            StatMetric::Fdpr => self.fdpr,
+            StatMetric::Kills => self.kills as f32,
+            StatMetric::Deaths => self.deaths as f32,
+            StatMetric::Assists => self.assists as f32,
+            StatMetric::FirstKills => self.first_kills as f32,
+            StatMetric::FirstDeaths => self.first_deaths as f32,
+        }
+    }
+}
+
+/// Sort agent stats by `metric`, highest first. The sort is stable, so ties
+/// keep the page's order.
+pub fn sort_agent_stats_by(stats: &mut [PlayerAgentStats], metric: StatMetric) {
+    stats.sort_by(|a, b| b.metric(metric).total_cmp(&a.metric(metric)));
 }
 
 /// Time window for agent statistics.
@@ -80,6 +171,7 @@ pub struct PlayerAgentStats {
     Eq,
     PartialEq,
     Serialize,
+    Deserialize,
     strum_macros::Display,
     strum_macros::EnumString,
 )]
@@ -96,9 +188,66 @@ pub enum AgentStatsTimespan {
 }
 
 /// A news article mentioning the player.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerNewsItem {
     pub href: String,
     pub date: String,
     pub title: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(agent: &str, rating: f32, usage_count: u32) -> PlayerAgentStats {
+        PlayerAgentStats {
+            agent: agent.to_string(),
+            agent_kind: agent.parse().ok(),
+            usage_count,
+            usage_pct: 0.0,
+            rounds: 0,
+            rating,
+            acs: 0.0,
+            kd: 0.0,
+            adr: 0.0,
+            kast: 0.0,
+            kpr: 0.0,
+            apr: 0.0,
+            fkpr: 0.0,
+            fdpr: 0.0,
+            kills: 0,
+            deaths: 0,
+            assists: 0,
+            first_kills: 0,
+            first_deaths: 0,
+            clutch_pct: None,
+            first_kill_pct: None,
+            rating_version: None,
+        }
+    }
+
+    fn agents(stats: &[PlayerAgentStats]) -> Vec<&str> {
+        stats.iter().map(|s| s.agent.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_agent_stats_by_metric() {
+        let mut list = vec![
+            stats("Jett", 1.10, 40),
+            stats("Raze", 1.25, 12),
+            stats("Neon", 0.95, 25),
+        ];
+        sort_agent_stats_by(&mut list, StatMetric::Rating);
+        assert_eq!(agents(&list), vec!["Raze", "Jett", "Neon"]);
+
+        sort_agent_stats_by(&mut list, StatMetric::UsageCount);
+        assert_eq!(agents(&list), vec!["Jett", "Neon", "Raze"]);
+        assert_eq!(list[0].metric(StatMetric::UsageCount), 40.0);
+    }
+
+    #[test]
+    fn test_stat_metric_names() {
+        assert_eq!(StatMetric::FirstKills.to_string(), "first_kills");
+        assert_eq!("acs".parse::<StatMetric>().unwrap(), StatMetric::Acs);
+    }
+}
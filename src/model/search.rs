@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// Which category of vlr.gg search results to request.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum SearchKind {
+    /// Players, teams, and events together.
+    #[default]
+    All,
+    Players,
+    Teams,
+    Events,
+}
+
+/// A single entry of the vlr.gg search results.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SearchResult {
+    Player { id: u32, name: String },
+    Team { id: u32, name: String },
+    Event { id: u32, name: String },
+}
+
+impl SearchResult {
+    /// The vlr.gg id of the player, team, or event.
+    pub fn id(&self) -> u32 {
+        match self {
+            Self::Player { id, .. } | Self::Team { id, .. } | Self::Event { id, .. } => *id,
+        }
+    }
+
+    /// The name shown in the search results.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Player { name, .. } | Self::Team { name, .. } | Self::Event { name, .. } => name,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// Site-wide pick rates for a single agent, from the agent stats page.
+///
+/// Only pick rates are available: the page's win-rate columns are per-map
+/// attack/defense splits, not per agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentMetaStat {
+    pub agent: String,
+    pub icon_url: String,
+    /// Pick rate across all maps as a fraction (0.45 = 45%).
+    pub pick_rate: Option<f32>,
+    pub maps: Vec<AgentMapStat>,
+}
+
+/// An agent's pick rate on one map.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentMapStat {
+    pub map: String,
+    /// Pick rate on this map as a fraction (0.45 = 45%).
+    pub pick_rate: Option<f32>,
+}
+
+/// One player's row in the site-wide player stats leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatLeaderboardRow {
+    pub player_id: u32,
+    pub player_name: String,
+    /// Tag of the player's current team, if any.
+    pub team: Option<String>,
+    /// Agents played in the window, most played first.
+    pub agents: Vec<String>,
+    pub rounds: u32,
+    pub rating: Option<f32>,
+    pub acs: Option<f32>,
+    pub kd: Option<f32>,
+    /// KAST as a fraction (0.74 = 74%).
+    pub kast: Option<f32>,
+    pub adr: Option<f32>,
+    pub kpr: Option<f32>,
+    pub apr: Option<f32>,
+    pub fkpr: Option<f32>,
+    pub fdpr: Option<f32>,
+    /// Headshot percentage as a fraction.
+    pub hs_pct: Option<f32>,
+    /// Clutch success rate as a fraction; `None` when no clutches were played.
+    pub clutch_pct: Option<f32>,
+}
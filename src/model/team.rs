@@ -1,31 +1,90 @@
 use chrono::NaiveDate;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use super::common::{EventPlacement, Social};
+use super::match_item::{MatchItem, PaginatedMatchList};
+use super::money::Money;
+
+/// One page of a team's match history.
+pub type TeamMatchListPage = PaginatedMatchList;
 
 /// Complete team profile data from a team overview page.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Team {
+    /// The [`SCHEMA_VERSION`](super::SCHEMA_VERSION) this value was
+    /// produced with; 0 for JSON written before versioning.
+    #[serde(default)]
+    pub schema_version: u16,
     pub info: TeamInfo,
     pub roster: Vec<TeamRosterMember>,
     pub event_placements: Vec<EventPlacement>,
     pub total_winnings: Option<String>,
+    pub total_winnings_money: Option<Money>,
+    #[serde(default)]
+    pub earnings_by_year: Vec<TeamYearEarnings>,
+    /// The team's next scheduled match, from the page's upcoming match card.
+    pub next_match: Option<MatchItem>,
+    /// Points of the ranking-over-time chart, oldest first. Empty when the
+    /// page has no chart or only draws it client-side.
+    #[serde(default)]
+    pub ranking_history: Vec<TeamRankingPoint>,
+}
+
+/// A team's world ranking on a given day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamRankingPoint {
+    pub date: NaiveDate,
+    pub rank: u32,
+}
+
+/// A team's row in a vlr.gg rankings table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankedTeam {
+    pub rank: u16,
+    pub team_id: u32,
+    pub team_name: String,
+    pub country_code: Option<String>,
+    /// The team's ranking points; 0 when the table shows none.
+    pub rating: f32,
+    /// Win–loss record as shown, e.g. `"20–5"`.
+    pub record: Option<String>,
+    /// Empty when the row has no logo.
+    pub logo_url: String,
+}
+
+/// A team's prize earnings for a single year.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamYearEarnings {
+    pub year: String,
+    pub amount: String,
+    pub amount_money: Option<Money>,
 }
 
 /// Basic profile information for a team.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamInfo {
     pub id: u32,
     pub name: String,
     pub tag: Option<String>,
     pub logo_url: Option<String>,
+    /// Dark-mode logo variant, when vlr.gg provides one.
+    pub logo_url_dark: Option<String>,
     pub country: Option<String>,
     pub country_code: Option<String>,
     pub socials: Vec<Social>,
 }
 
+/// Identity of a team, as returned by name lookups.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TeamRef {
+    pub id: u32,
+    pub slug: String,
+    pub name: String,
+    pub logo_url: Option<String>,
+}
+
 /// A member of a team's roster (player or staff).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamRosterMember {
     pub id: u32,
     pub slug: String,
@@ -35,11 +94,33 @@ pub struct TeamRosterMember {
     pub country_code: Option<String>,
     pub avatar_url: Option<String>,
     pub role: String,
+    /// [`role`](Self::role) classified; unusual roles keep their text in
+    /// [`TeamRole::Other`].
+    #[serde(default)]
+    pub role_kind: TeamRole,
     pub is_captain: bool,
+    /// Whether the role text marks the member as the in-game leader.
+    #[serde(default)]
+    pub is_igl: bool,
+}
+
+/// What a roster member does for the team.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TeamRole {
+    /// A playing member, including the in-game leader.
+    #[default]
+    Player,
+    /// A head or assistant coach.
+    Coach,
+    Manager,
+    Analyst,
+    Substitute,
+    /// Any other role, as shown on the page.
+    Other(String),
 }
 
 /// A single roster transaction (join, leave, or inactive change).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TeamTransaction {
     pub date: Option<NaiveDate>,
     pub action: String,
@@ -0,0 +1,194 @@
+use itertools::Itertools;
+use scraper::{CaseSensitivity, ElementRef, Selector};
+use tracing::{debug, instrument};
+
+use crate::error::Result;
+use crate::model::{Bracket, BracketColumn, BracketMatch};
+use crate::vlr_scraper::{self, normalize_number, select_text, HttpClient};
+
+#[instrument(skip(client))]
+pub(crate) async fn get_event_bracket(client: &HttpClient, event_id: u32) -> Result<Bracket> {
+    let url = client.url(&format!("/event/{event_id}"));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let bracket = parse_bracket(&document)?;
+    debug!(
+        event_id,
+        columns = bracket.columns.len(),
+        "parsed event bracket"
+    );
+    Ok(bracket)
+}
+
+/// Parse the bracket columns from `div.event-brackets`, in render order.
+/// Columns inside a `mod-lower` container belong to the lower bracket.
+fn parse_bracket(document: &scraper::Html) -> Result<Bracket> {
+    let column_selector = Selector::parse("div.event-brackets div.bracket-col")?;
+    let label_selector = Selector::parse("div.bracket-col-label")?;
+    let item_selector = Selector::parse("a.bracket-item, div.bracket-item")?;
+
+    let columns = document
+        .select(&column_selector)
+        .map(|col| {
+            let label = select_text(&col, &label_selector);
+            let matches = col
+                .select(&item_selector)
+                .map(|item| parse_bracket_match(&item, &label))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(BracketColumn {
+                is_lower: is_lower_bracket(&col),
+                label,
+                matches,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|c| !c.matches.is_empty())
+        .collect();
+
+    Ok(Bracket { columns })
+}
+
+fn parse_bracket_match(item: &ElementRef, round_label: &str) -> Result<BracketMatch> {
+    let match_id = item
+        .value()
+        .attr("href")
+        .and_then(|href| href.strip_prefix('/'))
+        .and_then(|s| s.split('/').next())
+        .and_then(|id| id.parse().ok());
+
+    let team_selector = Selector::parse("div.bracket-item-team")?;
+    let name_selector = Selector::parse("div.bracket-item-team-name")?;
+    let score_selector = Selector::parse("div.bracket-item-team-score")?;
+    let teams = item
+        .select(&team_selector)
+        .map(|team| {
+            let name = select_text(&team, &name_selector);
+            let name = if name.is_empty() {
+                "TBD".to_string()
+            } else {
+                name
+            };
+            let score = normalize_number(&select_text(&team, &score_selector))
+                .parse()
+                .ok();
+            (name, score)
+        })
+        .collect_vec();
+    let mut teams = teams.into_iter();
+    let (team1, score1) = teams.next().unwrap_or_else(|| ("TBD".to_string(), None));
+    let (team2, score2) = teams.next().unwrap_or_else(|| ("TBD".to_string(), None));
+
+    Ok(BracketMatch {
+        match_id,
+        team1,
+        team2,
+        score1,
+        score2,
+        round_label: round_label.to_string(),
+    })
+}
+
+/// Whether an element sits inside the lower bracket container.
+fn is_lower_bracket(col: &ElementRef) -> bool {
+    col.ancestors().filter_map(ElementRef::wrap).any(|e| {
+        e.value()
+            .has_class("mod-lower", CaseSensitivity::CaseSensitive)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bracket() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="event-brackets">
+                <div class="bracket-container mod-upper">
+                    <div class="bracket-col">
+                        <div class="bracket-col-label">Upper Semifinals</div>
+                        <a class="bracket-item" href="/378829/sentinels-vs-fnatic">
+                            <div class="bracket-item-team">
+                                <div class="bracket-item-team-name">Sentinels</div>
+                                <div class="bracket-item-team-score">2</div>
+                            </div>
+                            <div class="bracket-item-team">
+                                <div class="bracket-item-team-name">FNATIC</div>
+                                <div class="bracket-item-team-score">1</div>
+                            </div>
+                        </a>
+                    </div>
+                    <div class="bracket-col">
+                        <div class="bracket-col-label">Upper Final</div>
+                        <div class="bracket-item">
+                            <div class="bracket-item-team">
+                                <div class="bracket-item-team-name">Sentinels</div>
+                            </div>
+                            <div class="bracket-item-team"></div>
+                        </div>
+                    </div>
+                </div>
+                <div class="bracket-container mod-lower">
+                    <div class="bracket-col">
+                        <div class="bracket-col-label">Lower Round 1</div>
+                        <a class="bracket-item" href="/378830/fnatic-vs-loud">
+                            <div class="bracket-item-team">
+                                <div class="bracket-item-team-name">FNATIC</div>
+                                <div class="bracket-item-team-score">2</div>
+                            </div>
+                            <div class="bracket-item-team">
+                                <div class="bracket-item-team-name">LOUD</div>
+                                <div class="bracket-item-team-score">0</div>
+                            </div>
+                        </a>
+                    </div>
+                </div>
+            </div>"#,
+        );
+        let bracket = parse_bracket(&document).unwrap();
+        let labels = bracket
+            .columns
+            .iter()
+            .map(|c| c.label.as_str())
+            .collect_vec();
+        assert_eq!(
+            labels,
+            vec!["Upper Semifinals", "Upper Final", "Lower Round 1"]
+        );
+        assert_eq!(
+            bracket.columns.iter().map(|c| c.is_lower).collect_vec(),
+            vec![false, false, true]
+        );
+
+        let first = &bracket.columns[0].matches[0];
+        assert_eq!(first.match_id, Some(378829));
+        assert_eq!(
+            (first.team1.as_str(), first.team2.as_str()),
+            ("Sentinels", "FNATIC")
+        );
+        assert_eq!((first.score1, first.score2), (Some(2), Some(1)));
+        assert_eq!(first.round_label, "Upper Semifinals");
+
+        let pending = &bracket.columns[1].matches[0];
+        assert_eq!(pending.match_id, None);
+        assert_eq!(pending.team2, "TBD");
+        assert_eq!(pending.score1, None);
+    }
+
+    #[test]
+    fn test_parse_bracket_group_stage_only() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="event-groups-container"><div class="wf-card">Group A</div></div>"#,
+        );
+        assert!(parse_bracket(&document).unwrap().columns.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_event_bracket() {
+        let client = HttpClient::default();
+        let bracket = get_event_bracket(&client, 2097).await.unwrap();
+        for column in &bracket.columns {
+            assert!(!column.matches.is_empty());
+        }
+    }
+}
@@ -0,0 +1,629 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use itertools::Itertools;
+use scraper::{ElementRef, Selector};
+use tracing::{debug, instrument};
+
+use crate::error::{Result, VlrError};
+use crate::model::{
+    EventDetail, EventFinalPlacement, EventParticipant, EventStatus, MatchStream, Money,
+    ParticipantStatus,
+};
+use crate::vlr_scraper::{self, normalize_img_url, select_text, HttpClient};
+
+const EVENT_DATE_FORMAT: &str = "%b %d, %Y";
+
+#[instrument(skip(client))]
+pub(crate) async fn get_event(client: &HttpClient, event_id: u32) -> Result<EventDetail> {
+    let url = client.url(&format!("/event/{event_id}"));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let event = parse_event_detail(&document, event_id)?;
+    debug!(
+        event_id,
+        title = %event.title,
+        streams = event.streams.len(),
+        "parsed event detail"
+    );
+    Ok(event)
+}
+
+/// Fetch an event's overview page and parse only its final standings.
+#[instrument(skip(client))]
+pub(crate) async fn get_event_results(
+    client: &HttpClient,
+    event_id: u32,
+) -> Result<Vec<EventFinalPlacement>> {
+    let url = client.url(&format!("/event/{event_id}"));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let placements = parse_final_placements(&document)?;
+    debug!(event_id, count = placements.len(), "parsed event results");
+    Ok(placements)
+}
+
+fn parse_event_detail(document: &scraper::Html, event_id: u32) -> Result<EventDetail> {
+    let header_selector = Selector::parse("div.event-header")?;
+    let header = document
+        .select(&header_selector)
+        .next()
+        .ok_or(VlrError::ElementNotFound {
+            context: "event header (div.event-header)",
+        })?;
+
+    let title_selector = Selector::parse("h1.wf-title")?;
+    let title = select_text(&header, &title_selector);
+
+    let status = parse_event_status(&header, chrono::Utc::now().date_naive())?;
+    let dates = parse_desc_item(&header, "dates")?;
+    let prize_pool = parse_desc_item(&header, "prize pool")?;
+    let location = parse_desc_item(&header, "location")?;
+    let organizer = parse_desc_item(&header, "organizer")?;
+
+    let stages = parse_stages(document)?;
+    let streams = parse_streams(document)?;
+    let placements = parse_final_placements(document)?;
+    let participants = parse_participants(document)?;
+
+    Ok(EventDetail {
+        id: event_id,
+        title,
+        status,
+        dates,
+        prize_pool,
+        location,
+        organizer,
+        stages,
+        streams,
+        placements,
+        participants,
+    })
+}
+
+/// Parse the participating teams from the event's teams card.
+fn parse_participants(document: &scraper::Html) -> Result<Vec<EventParticipant>> {
+    let team_selector = Selector::parse("div.event-teams-container div.event-team")?;
+    let name_selector = Selector::parse(".event-team-name")?;
+    let img_selector = Selector::parse("img")?;
+    let note_selector = Selector::parse(".event-team-note")?;
+
+    let participants = document
+        .select(&team_selector)
+        .map(|team| {
+            let name_el = team.select(&name_selector).next();
+            let team_name = name_el
+                .map(|e| {
+                    e.text()
+                        .map(str::trim)
+                        .find(|t| !t.is_empty())
+                        .unwrap_or_default()
+                        .to_string()
+                })
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| "TBD".to_string());
+            let (team_id, team_slug) = name_el
+                .and_then(|e| e.value().attr("href"))
+                .and_then(|href| href.strip_prefix("/team/"))
+                .map(|s| {
+                    let mut parts = s.split('/');
+                    let id = parts.next().and_then(|id| id.parse().ok());
+                    let slug = parts.next().filter(|s| !s.is_empty()).map(str::to_string);
+                    (id, slug)
+                })
+                .unwrap_or_default();
+            let logo_url = team
+                .select(&img_selector)
+                .next()
+                .and_then(|img| img.value().attr("src"))
+                .map(normalize_img_url);
+
+            let notes = team
+                .select(&note_selector)
+                .map(|n| n.text().map(str::trim).join(" ").trim().to_string())
+                .filter(|n| !n.is_empty())
+                .collect_vec();
+            let seed = notes.iter().find_map(|n| parse_seed(n));
+            let group = notes.iter().find_map(|n| parse_group(n));
+
+            let eliminated = team.value().classes().any(|c| c == "mod-eliminated")
+                || notes
+                    .iter()
+                    .any(|n| n.to_lowercase().contains("eliminated"));
+            let status = if eliminated {
+                ParticipantStatus::Eliminated
+            } else {
+                ParticipantStatus::Active
+            };
+
+            EventParticipant {
+                team_id,
+                team_slug,
+                team_name,
+                logo_url,
+                seed,
+                group,
+                status,
+            }
+        })
+        .collect();
+
+    Ok(participants)
+}
+
+/// Read a seed from a note like `"Americas #1"` or `"Seed 3"`.
+fn parse_seed(note: &str) -> Option<u32> {
+    let lower = note.to_lowercase();
+    let rest = match lower.split_once('#') {
+        Some((_, rest)) => rest,
+        None => lower.split_once("seed")?.1,
+    };
+    let digits: String = rest
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Read a group name from a note like `"Group A"`, returning `"A"`.
+fn parse_group(note: &str) -> Option<String> {
+    let words = note.split_whitespace().collect_vec();
+    words
+        .iter()
+        .position(|w| w.eq_ignore_ascii_case("group"))
+        .and_then(|i| words.get(i + 1))
+        .map(|g| g.trim_matches(|c: char| !c.is_alphanumeric()).to_string())
+        .filter(|g| !g.is_empty())
+}
+
+/// Read the event status from an explicit `mod-{status}` marker in the
+/// header, falling back to comparing its "Dates" range with `today`.
+fn parse_event_status(header: &ElementRef, today: NaiveDate) -> Result<EventStatus> {
+    let marker_selector = Selector::parse(".event-desc-item-status, .event-header-status")?;
+    let marked = header.select(&marker_selector).find_map(|e| {
+        e.value()
+            .classes()
+            .filter_map(|c| c.strip_prefix("mod-"))
+            .chain(e.text().map(str::trim))
+            .find_map(|s| EventStatus::from_str(&s.to_lowercase()).ok())
+    });
+    if let Some(status) = marked {
+        return Ok(status);
+    }
+
+    let dates = parse_desc_item(header, "dates")?.unwrap_or_default();
+    Ok(status_from_dates(&dates, today))
+}
+
+/// The value of the header's `div.event-desc-item` with the given label
+/// (case-insensitive). `None` when the item is missing or empty.
+fn parse_desc_item(header: &ElementRef, label: &str) -> Result<Option<String>> {
+    let item_selector = Selector::parse("div.event-desc-item")?;
+    let label_selector = Selector::parse("div.event-desc-item-label")?;
+    let value_selector = Selector::parse("div.event-desc-item-value")?;
+    Ok(header
+        .select(&item_selector)
+        .find(|item| select_text(item, &label_selector).eq_ignore_ascii_case(label))
+        .map(|item| {
+            select_text(&item, &value_selector)
+                .split_whitespace()
+                .join(" ")
+        })
+        .filter(|value| !value.is_empty()))
+}
+
+/// Stage names from the stage navigation, i.e. the sub-nav links that point
+/// at a `series_id`.
+fn parse_stages(document: &scraper::Html) -> Result<Vec<String>> {
+    let link_selector = Selector::parse("a.wf-subnav-item[href*='series_id']")?;
+    let title_selector = Selector::parse(".wf-subnav-item-title")?;
+    let stages = document
+        .select(&link_selector)
+        .map(|link| {
+            let title = select_text(&link, &title_selector);
+            if title.is_empty() {
+                link.text().map(str::trim).join(" ").trim().to_string()
+            } else {
+                title
+            }
+        })
+        .filter(|s| !s.is_empty())
+        .unique()
+        .collect();
+    Ok(stages)
+}
+
+/// Classify a `"Aug 1, 2024 - Aug 25, 2024"` date range relative to `today`.
+/// A single date counts as both start and end.
+fn status_from_dates(dates: &str, today: NaiveDate) -> EventStatus {
+    let mut parts = dates
+        .split(['-', '–'])
+        .map(|d| NaiveDate::parse_from_str(d.trim(), EVENT_DATE_FORMAT).ok());
+    let Some(Some(start)) = parts.next() else {
+        return EventStatus::Unknown;
+    };
+    let end = parts.next().flatten().unwrap_or(start);
+    if today < start {
+        EventStatus::Upcoming
+    } else if today > end {
+        EventStatus::Completed
+    } else {
+        EventStatus::Ongoing
+    }
+}
+
+/// Parse the final standings from the event's prize distribution table.
+fn parse_final_placements(document: &scraper::Html) -> Result<Vec<EventFinalPlacement>> {
+    let label_selector = Selector::parse("h2.wf-label, div.wf-label")?;
+    let row_selector = Selector::parse("table tbody tr")?;
+    let place_selector = Selector::parse("td.prize-table-place")?;
+    let prize_selector = Selector::parse("td.prize-table-prize")?;
+    let team_selector = Selector::parse("td.prize-table-team a[href^='/team/']")?;
+    let team_name_selector = Selector::parse(".standing-item-team-name")?;
+    let img_selector = Selector::parse("img")?;
+
+    // Find the "Prize Distribution" label, then read its next sibling card
+    let card = document
+        .select(&label_selector)
+        .find(|el| {
+            el.text()
+                .map(|t| t.trim())
+                .collect::<String>()
+                .contains("Prize Distribution")
+        })
+        .and_then(|label| label.next_siblings().filter_map(ElementRef::wrap).next());
+
+    let card = match card {
+        Some(c) => c,
+        None => return Ok(Vec::new()),
+    };
+
+    let placements = card
+        .select(&row_selector)
+        .filter_map(|row| {
+            let place = select_text(&row, &place_selector);
+            if place.is_empty() {
+                return None;
+            }
+            let rank = place
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect::<String>()
+                .parse()
+                .ok();
+
+            // Places without prize money show a dash
+            let prize = Some(select_text(&row, &prize_selector))
+                .filter(|p| !p.is_empty() && !p.chars().all(|c| matches!(c, '-' | '–' | '—')));
+            let prize_money: Option<Money> = prize.as_deref().and_then(|p| p.parse().ok());
+            let is_confirmed = prize_money.is_some();
+
+            let team = row.select(&team_selector).next();
+            let (team_id, team_slug) = team
+                .and_then(|a| a.value().attr("href"))
+                .and_then(|href| href.strip_prefix("/team/"))
+                .and_then(|s| s.split('/').collect_tuple())
+                .map(|(id, slug): (&str, &str)| (id.parse().ok(), Some(slug.to_string())))
+                .unwrap_or_default();
+            let team_name = team
+                .map(|a| select_text(&a, &team_name_selector))
+                .filter(|n| !n.is_empty());
+            let team_logo_url = team
+                .and_then(|a| a.select(&img_selector).next())
+                .and_then(|img| img.value().attr("src"))
+                .map(normalize_img_url);
+
+            Some(EventFinalPlacement {
+                place,
+                rank,
+                team_id,
+                team_slug,
+                team_name,
+                team_logo_url,
+                prize,
+                prize_money,
+                is_confirmed,
+            })
+        })
+        .collect();
+
+    Ok(placements)
+}
+
+/// Parse the official broadcast channels listed in the event's streams card.
+fn parse_streams(document: &scraper::Html) -> Result<Vec<MatchStream>> {
+    let label_selector = Selector::parse("h2.wf-label, div.wf-label")?;
+    let link_selector = Selector::parse("a[href]")?;
+    let flag_selector = Selector::parse("i.flag")?;
+
+    // Find the "Streams" label, then read the channels from its next sibling card
+    let card = document
+        .select(&label_selector)
+        .find(|el| {
+            el.text()
+                .map(|t| t.trim())
+                .collect::<String>()
+                .contains("Streams")
+        })
+        .and_then(|label| label.next_siblings().filter_map(ElementRef::wrap).next());
+
+    let card = match card {
+        Some(c) => c,
+        None => return Ok(Vec::new()),
+    };
+
+    let streams = card
+        .select(&link_selector)
+        .filter_map(|a| {
+            let link = a
+                .value()
+                .attr("href")
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            let name: String = a
+                .text()
+                .map(|t| t.trim())
+                .filter(|t| !t.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if link.is_empty() || name.is_empty() {
+                return None;
+            }
+            let language = a.select(&flag_selector).next().and_then(|f| {
+                f.value()
+                    .classes()
+                    .find(|c| c.starts_with("mod-"))
+                    .map(|c| c.strip_prefix("mod-").unwrap_or_default().to_string())
+            });
+            Some(MatchStream {
+                name,
+                link,
+                language,
+            })
+        })
+        .collect();
+
+    Ok(streams)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_event_status() {
+        let today = NaiveDate::from_ymd_opt(2024, 8, 10).unwrap();
+        let status = |items: &str| {
+            let document = scraper::Html::parse_document(&format!(
+                r#"<div class="event-header"><div class="event-desc-items">{items}</div></div>"#
+            ));
+            let header = document
+                .select(&Selector::parse("div.event-header").unwrap())
+                .next()
+                .unwrap();
+            parse_event_status(&header, today).unwrap()
+        };
+        let dates = |value: &str| {
+            format!(
+                r#"<div class="event-desc-item">
+                    <div class="event-desc-item-label">Dates</div>
+                    <div class="event-desc-item-value">{value}</div>
+                </div>"#
+            )
+        };
+
+        assert!(matches!(
+            status(&dates("Aug 1, 2024 - Aug 25, 2024")),
+            EventStatus::Ongoing
+        ));
+        assert!(matches!(
+            status(&dates("Jun 7, 2024 - Jun 23, 2024")),
+            EventStatus::Completed
+        ));
+        assert!(matches!(
+            status(&dates("Sep 12, 2024 - Sep 29, 2024")),
+            EventStatus::Upcoming
+        ));
+        assert!(matches!(status(&dates("TBD")), EventStatus::Unknown));
+        assert!(matches!(
+            status(&format!(
+                r#"<span class="event-desc-item-status mod-completed"></span>{}"#,
+                dates("Aug 1, 2024 - Aug 25, 2024")
+            )),
+            EventStatus::Completed
+        ));
+    }
+
+    #[test]
+    fn test_parse_event_detail_header() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="event-header">
+                <h1 class="wf-title">Champions Tour 2024: Masters Shanghai</h1>
+                <div class="event-desc-items">
+                    <div class="event-desc-item">
+                        <div class="event-desc-item-label">Dates</div>
+                        <div class="event-desc-item-value">May 23, 2024 - Jun 9, 2024</div>
+                    </div>
+                    <div class="event-desc-item">
+                        <div class="event-desc-item-label">Prize pool</div>
+                        <div class="event-desc-item-value">$1,000,000 USD</div>
+                    </div>
+                    <div class="event-desc-item">
+                        <div class="event-desc-item-label">Location</div>
+                        <div class="event-desc-item-value">
+                            <i class="flag mod-cn"></i> Shanghai, China
+                        </div>
+                    </div>
+                </div>
+            </div>
+            <div class="wf-subnav">
+                <a class="wf-subnav-item" href="/event/1999/masters-shanghai">
+                    <div class="wf-subnav-item-title">Overview</div>
+                </a>
+                <a class="wf-subnav-item" href="/event/matches/1999/?series_id=3800">
+                    <div class="wf-subnav-item-title">Swiss Stage</div>
+                </a>
+                <a class="wf-subnav-item" href="/event/matches/1999/?series_id=3801">
+                    <div class="wf-subnav-item-title">Playoffs</div>
+                </a>
+            </div>"#,
+        );
+        let event = parse_event_detail(&document, 1999).unwrap();
+        assert_eq!(event.title, "Champions Tour 2024: Masters Shanghai");
+        assert_eq!(event.dates.as_deref(), Some("May 23, 2024 - Jun 9, 2024"));
+        assert_eq!(event.prize_pool.as_deref(), Some("$1,000,000 USD"));
+        assert_eq!(event.location.as_deref(), Some("Shanghai, China"));
+        assert_eq!(event.organizer, None);
+        assert_eq!(event.stages, vec!["Swiss Stage", "Playoffs"]);
+        assert!(event.participants.is_empty());
+        assert!(event.placements.is_empty());
+
+        let document = scraper::Html::parse_document(
+            r#"<div class="event-header"><h1 class="wf-title">Showmatch</h1></div>"#,
+        );
+        let event = parse_event_detail(&document, 1).unwrap();
+        assert_eq!(event.prize_pool, None);
+        assert!(event.stages.is_empty());
+    }
+
+    #[test]
+    fn test_parse_participants() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="event-teams-container">
+                <div class="wf-card event-team">
+                    <a class="event-team-name" href="/team/2/sentinels">Sentinels</a>
+                    <img src="//owcdn.net/img/sen.png">
+                    <div class="event-team-note">Americas #1</div>
+                    <div class="event-team-note">Group A</div>
+                </div>
+                <div class="wf-card event-team mod-eliminated">
+                    <a class="event-team-name" href="/team/2593/fnatic">FNATIC</a>
+                    <div class="event-team-note">Seed 3</div>
+                </div>
+                <div class="wf-card event-team">
+                    <div class="event-team-name">TBD</div>
+                    <div class="event-team-note">Pacific #2</div>
+                </div>
+            </div>"#,
+        );
+        let participants = parse_participants(&document).unwrap();
+        assert_eq!(participants.len(), 3);
+
+        let sen = &participants[0];
+        assert_eq!(sen.team_id, Some(2));
+        assert_eq!(sen.team_slug.as_deref(), Some("sentinels"));
+        assert_eq!(sen.team_name, "Sentinels");
+        assert_eq!(
+            sen.logo_url.as_deref(),
+            Some("https://owcdn.net/img/sen.png")
+        );
+        assert_eq!(sen.seed, Some(1));
+        assert_eq!(sen.group.as_deref(), Some("A"));
+        assert_eq!(sen.status, ParticipantStatus::Active);
+
+        let fnc = &participants[1];
+        assert_eq!(fnc.seed, Some(3));
+        assert_eq!(fnc.group, None);
+        assert_eq!(fnc.status, ParticipantStatus::Eliminated);
+
+        let tbd = &participants[2];
+        assert_eq!(tbd.team_id, None);
+        assert_eq!(tbd.team_name, "TBD");
+        assert_eq!(tbd.logo_url, None);
+        assert_eq!(tbd.seed, Some(2));
+    }
+
+    #[test]
+    fn test_parse_final_placements() {
+        let document = scraper::Html::parse_document(
+            r#"<h2 class="wf-label mod-large">Prize Distribution</h2>
+            <div class="wf-card"><table class="wf-table"><tbody>
+                <tr>
+                    <td class="prize-table-place">1st</td>
+                    <td class="prize-table-prize">$1,000,000</td>
+                    <td class="prize-table-team">
+                        <a class="standing-item-team" href="/team/2593/fnatic">
+                            <img src="//owcdn.net/img/fnc.png">
+                            <div class="standing-item-team-name">FNATIC</div>
+                        </a>
+                    </td>
+                </tr>
+                <tr>
+                    <td class="prize-table-place">5th–6th</td>
+                    <td class="prize-table-prize"></td>
+                    <td class="prize-table-team">TBD</td>
+                </tr>
+                <tr>
+                    <td class="prize-table-place">13th–16th</td>
+                    <td class="prize-table-prize">—</td>
+                    <td class="prize-table-team">
+                        <a class="standing-item-team" href="/team/1034/nrg">
+                            <div class="standing-item-team-name">NRG</div>
+                        </a>
+                    </td>
+                </tr>
+                <tr>
+                    <td class="prize-table-place">17th</td>
+                    <td class="prize-table-prize">TBD</td>
+                    <td class="prize-table-team">TBD</td>
+                </tr>
+            </tbody></table></div>"#,
+        );
+        let placements = parse_final_placements(&document).unwrap();
+        assert_eq!(placements.len(), 4);
+
+        let first = &placements[0];
+        assert_eq!(first.place, "1st");
+        assert_eq!(first.rank, Some(1));
+        assert_eq!(first.team_id, Some(2593));
+        assert_eq!(first.team_slug.as_deref(), Some("fnatic"));
+        assert_eq!(first.team_name.as_deref(), Some("FNATIC"));
+        assert_eq!(
+            first.team_logo_url.as_deref(),
+            Some("https://owcdn.net/img/fnc.png")
+        );
+        assert_eq!(first.prize.as_deref(), Some("$1,000,000"));
+        assert!(first.prize_money.is_some());
+        assert!(first.is_confirmed);
+
+        let tbd = &placements[1];
+        assert_eq!(tbd.rank, Some(5));
+        assert_eq!(tbd.team_id, None);
+        assert_eq!(tbd.prize, None);
+        assert!(!tbd.is_confirmed);
+
+        let unpaid = &placements[2];
+        assert_eq!(unpaid.rank, Some(13));
+        assert_eq!(unpaid.team_id, Some(1034));
+        assert_eq!(unpaid.prize, None);
+        assert!(unpaid.prize_money.is_none());
+        assert!(!unpaid.is_confirmed);
+
+        let unconfirmed = &placements[3];
+        assert_eq!(unconfirmed.prize.as_deref(), Some("TBD"));
+        assert!(!unconfirmed.is_confirmed);
+
+        let document = scraper::Html::parse_document(r#"<div class="event-header"></div>"#);
+        assert!(parse_final_placements(&document).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_event() {
+        let client = HttpClient::default();
+        let event = get_event(&client, 2097).await.unwrap();
+
+        assert_eq!(event.id, 2097);
+        assert!(!event.title.is_empty());
+        for stream in &event.streams {
+            assert!(!stream.name.is_empty());
+            assert!(!stream.link.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_event_results() {
+        let client = HttpClient::default();
+        let placements = get_event_results(&client, 2097).await.unwrap();
+        assert!(placements.iter().all(|p| !p.place.is_empty()));
+    }
+}
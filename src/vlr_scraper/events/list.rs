@@ -1,23 +1,24 @@
 use std::str::FromStr;
 
+use chrono::{Datelike, NaiveDate};
 use itertools::Itertools;
-use scraper::{ElementRef, Selector};
+use scraper::{CaseSensitivity, ElementRef, Selector};
 use tracing::{debug, instrument};
 
-use crate::error::{Result, VlrError};
-use crate::model::{Event, EventStatus, EventType, EventsData, Region};
-use crate::vlr_scraper::{self, normalize_img_url, select_text};
+use crate::error::{ErrorPolicy, Result, VlrError};
+use crate::model::{Event, EventStatus, EventTier, EventType, EventsData, Region};
+use crate::vlr_scraper::{self, normalize_img_url, select_text, HttpClient, BASE_URL};
 
 #[instrument(skip(client), fields(region = %region, page))]
 pub(crate) async fn get_events(
-    client: &reqwest::Client,
+    client: &HttpClient,
     event_type: EventType,
     region: Region,
     page: u8,
 ) -> Result<EventsData> {
-    let url = format!("https://www.vlr.gg/events/{region}?page={page}");
+    let url = client.url(&format!("/events/{region}?page={page}"));
     let document = vlr_scraper::get_document(client, &url).await?;
-    let events = parse_events(&event_type, &document)?;
+    let events = parse_events(&event_type, &document, client.error_policy)?;
     let total_pages = parse_total_pages(event_type, &document)?;
 
     debug!(count = events.len(), total_pages, "parsed events page");
@@ -48,7 +49,11 @@ fn parse_total_pages(event_type: EventType, document: &scraper::Html) -> Result<
     Ok(total_pages)
 }
 
-fn parse_events(event_type: &EventType, document: &scraper::Html) -> Result<Vec<Event>> {
+fn parse_events(
+    event_type: &EventType,
+    document: &scraper::Html,
+    policy: ErrorPolicy,
+) -> Result<Vec<Event>> {
     let event_item_selector = match event_type {
         EventType::Upcoming => {
             "div#wrapper div.events-container div.events-container-col:first-child a.event-item"
@@ -58,11 +63,7 @@ fn parse_events(event_type: &EventType, document: &scraper::Html) -> Result<Vec<
         }
     };
     let selector = Selector::parse(event_item_selector)?;
-    let events: Vec<Event> = document
-        .select(&selector)
-        .filter_map(|el| parse_event(el).ok())
-        .collect();
-    Ok(events)
+    policy.collect(document.select(&selector).map(parse_event), "event")
 }
 
 fn parse_event(element: ElementRef) -> Result<Event> {
@@ -71,7 +72,7 @@ fn parse_event(element: ElementRef) -> Result<Event> {
         .strip_prefix("/event/")
         .and_then(|s| s.split('/').map(|s| s.to_string()).collect_tuple())
         .unwrap_or_default();
-    let href = format!("https://www.vlr.gg{href}");
+    let href = format!("{BASE_URL}{href}");
 
     let icon_selector = Selector::parse("div.event-item-thumb img")?;
     let icon_url = element
@@ -93,21 +94,30 @@ fn parse_event(element: ElementRef) -> Result<Event> {
     let price_selector =
         Selector::parse("div.event-item-inner div.event-item-desc-item.mod-prize")?;
     let price = select_text(&element, &price_selector);
+    let price_money = price.parse().ok();
 
     let dates_selector =
         Selector::parse("div.event-item-inner div.event-item-desc-item.mod-dates")?;
     let dates = select_text(&element, &dates_selector);
+    let year = infer_event_year(&dates, &title);
+    let (start_date, end_date) = parse_event_dates(&dates, year);
 
     let region_selector =
         Selector::parse("div.event-item-inner div.event-item-desc-item.mod-location i")?;
     let region = element
         .select(&region_selector)
         .next()
-        .and_then(|r| r.value().classes().find(|c| c.starts_with("mod-")))
-        .map(|c| c.strip_prefix("mod-").unwrap_or_default())
-        .unwrap_or_default()
-        .trim()
-        .to_string();
+        .map(|r| parse_event_region(&r))
+        .unwrap_or_default();
+
+    let tier = parse_event_tier(&element)?;
+
+    // Ongoing events with matches in progress carry a `mod-live` marker
+    let live_selector = Selector::parse(".mod-live")?;
+    let has_live_matches = element
+        .value()
+        .has_class("mod-live", CaseSensitivity::CaseSensitive)
+        || element.select(&live_selector).next().is_some();
 
     Ok(Event {
         id: id
@@ -120,17 +130,269 @@ fn parse_event(element: ElementRef) -> Result<Event> {
         icon_url,
         status,
         price,
+        price_money,
         dates,
+        start_date,
+        end_date,
+        tier,
+        has_live_matches,
     })
 }
 
+/// The year an event's dates fall in: the listing usually omits it, so take
+/// a year from the dates text, then from the title ("Champions Tour 2024"),
+/// then the current year.
+fn infer_event_year(dates: &str, title: &str) -> i32 {
+    [dates, title]
+        .iter()
+        .flat_map(|text| text.split(|c: char| !c.is_ascii_digit()))
+        .find(|word| word.len() == 4 && word.starts_with("20"))
+        .and_then(|year| year.parse().ok())
+        .unwrap_or_else(|| chrono::Utc::now().year())
+}
+
+/// Parse a listing date range like `"Jun 7—23"`, `"Jul 12—Aug 4"`, or a
+/// single `"Sep 1"` into its first and last day, in `year` unless a day
+/// names its own (`"Dec 28, 2024"`).
+///
+/// A range whose end comes before its start crosses New Year. Without a year
+/// (`"Dec 28—Jan 5"`) it ends the following year; with a trailing year that
+/// only the end carries (`"Dec 28—Jan 5, 2025"`) it starts the year before.
+fn parse_event_dates(dates: &str, year: i32) -> (Option<NaiveDate>, Option<NaiveDate>) {
+    // A day with its month (or the range's month) and an optional ", 2024"
+    let day = |text: &str, month: Option<&str>| {
+        let (text, own_year) = match text.split_once(',') {
+            Some((text, own_year)) => (text, own_year.trim().parse::<i32>().ok()),
+            None => (text, None),
+        };
+        let text = text.trim();
+        let (month, day) = match text.split_once(' ') {
+            Some((month, day)) => (month, day),
+            None => (month?, text),
+        };
+        let date_year = own_year.unwrap_or(year);
+        let date =
+            NaiveDate::parse_from_str(&format!("{month} {day} {date_year}"), "%b %d %Y").ok()?;
+        Some((date, month.to_string(), own_year.is_some()))
+    };
+
+    let mut parts = dates.split(['—', '–', '-']);
+    let Some((mut start, month, start_has_year)) = parts.next().and_then(|s| day(s, None)) else {
+        return (None, None);
+    };
+    let end = match parts.next() {
+        Some(end) => match day(end, Some(&month)) {
+            Some((end, _, end_has_year)) if end < start => {
+                if end_has_year && !start_has_year {
+                    start = start.with_year(end.year() - 1).unwrap_or(start);
+                    Some(end)
+                } else if !end_has_year {
+                    end.with_year(start.year() + 1)
+                } else {
+                    Some(end)
+                }
+            }
+            Some((end, _, _)) => Some(end),
+            None => None,
+        },
+        None => Some(start),
+    };
+    (Some(start), end)
+}
+
+/// Flag classes vlr.gg uses for global (non-country) events.
+const INTERNATIONAL_FLAGS: [&str; 3] = ["mod-un", "mod-int", "mod-world"];
+
+/// Read the event's region from its location icon: the `mod-{country}` flag
+/// class, or `"international"` for the global flag (or a flag-less globe icon).
+fn parse_event_region(icon: &ElementRef) -> String {
+    let mut classes = icon.value().classes();
+    if let Some(class) = classes.find(|c| c.starts_with("mod-")) {
+        if INTERNATIONAL_FLAGS.contains(&class) {
+            return "international".to_string();
+        }
+        return class
+            .strip_prefix("mod-")
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+    }
+    if icon.value().classes().any(|c| c.contains("globe")) {
+        return "international".to_string();
+    }
+    String::new()
+}
+
+/// Read the event's tier from a `mod-<tier>` class on the item or a tier
+/// badge inside it. Returns `None` for unmarked (community) events.
+fn parse_event_tier(element: &ElementRef) -> Result<Option<EventTier>> {
+    let from_classes = element
+        .value()
+        .classes()
+        .filter_map(|c| c.strip_prefix("mod-"))
+        .find_map(|c| EventTier::from_str(c).ok());
+    if from_classes.is_some() {
+        return Ok(from_classes);
+    }
+
+    let badge_selector = Selector::parse("div.event-item-desc-item.mod-tier, .event-item-tier")?;
+    let badge = select_text(element, &badge_selector);
+    Ok(EventTier::from_str(&badge).ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_event_dates() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d);
+        assert_eq!(
+            parse_event_dates("Jun 7—23", 2024),
+            (date(6, 7), date(6, 23))
+        );
+        assert_eq!(
+            parse_event_dates("Jul 12—Aug 4", 2024),
+            (date(7, 12), date(8, 4))
+        );
+        assert_eq!(parse_event_dates("Sep 1", 2024), (date(9, 1), date(9, 1)));
+        assert_eq!(
+            parse_event_dates("Dec 28—Jan 5", 2024),
+            (date(12, 28), NaiveDate::from_ymd_opt(2025, 1, 5))
+        );
+        assert_eq!(
+            parse_event_dates("Dec 28—Jan 5, 2025", 2025),
+            (date(12, 28), NaiveDate::from_ymd_opt(2025, 1, 5))
+        );
+        assert_eq!(
+            parse_event_dates("Dec 28, 2024—Jan 5, 2025", 2024),
+            (date(12, 28), NaiveDate::from_ymd_opt(2025, 1, 5))
+        );
+        assert_eq!(
+            parse_event_dates("Jun 7, 2022 - Jun 23, 2022", 2022),
+            (
+                NaiveDate::from_ymd_opt(2022, 6, 7),
+                NaiveDate::from_ymd_opt(2022, 6, 23)
+            )
+        );
+        assert_eq!(parse_event_dates("Jun 7—TBD", 2024), (date(6, 7), None));
+        assert_eq!(parse_event_dates("TBD", 2024), (None, None));
+
+        assert_eq!(
+            infer_event_year("Jun 7—23", "Champions Tour 2023: Masters"),
+            2023
+        );
+        assert_eq!(
+            infer_event_year("Jun 7, 2022 - Jun 23, 2022", "Masters"),
+            2022
+        );
+    }
+
+    #[test]
+    fn test_event_duration() {
+        let html = scraper::Html::parse_fragment(
+            r#"<a class="event-item" href="/event/2097/valorant-champions-2024">
+                <div class="event-item-inner">
+                    <div class="event-item-title">Valorant Champions 2024</div>
+                    <div class="event-item-desc-item mod-dates">Jul 30—Aug 25</div>
+                </div>
+            </a>"#,
+        );
+        let element = html
+            .select(&Selector::parse("a.event-item").unwrap())
+            .next()
+            .unwrap();
+        let mut event = parse_event(element).unwrap();
+        assert_eq!(event.duration_days(), Some(27));
+        assert!(event.spans_months());
+
+        event.end_date = event.start_date;
+        assert_eq!(event.duration_days(), Some(1));
+        assert!(!event.spans_months());
+
+        event.end_date = None;
+        assert_eq!(event.duration_days(), None);
+        assert!(!event.spans_months());
+    }
+
+    #[test]
+    fn test_parse_event_region() {
+        let selector = Selector::parse("a.event-item").unwrap();
+        let parse = |html: &str| {
+            let html = scraper::Html::parse_fragment(html);
+            let item = html.select(&selector).next().unwrap();
+            parse_event(item).unwrap().region
+        };
+        let item = |icon: &str| {
+            format!(
+                r#"<a class="event-item" href="/event/2097/valorant-champions-2024">
+                    <div class="event-item-inner">
+                        <div class="event-item-desc-item mod-location">{icon}</div>
+                    </div>
+                </a>"#
+            )
+        };
+
+        assert_eq!(
+            parse(&item(r#"<i class="flag mod-un"></i>"#)),
+            "international"
+        );
+        assert_eq!(
+            parse(&item(r#"<i class="fa fa-globe"></i>"#)),
+            "international"
+        );
+        assert_eq!(parse(&item(r#"<i class="flag mod-kr"></i>"#)), "kr");
+        assert_eq!(parse(&item("")), "");
+    }
+
+    #[test]
+    fn test_parse_event_live_indicator() {
+        let selector = Selector::parse("a.event-item").unwrap();
+        let parse = |html: &str| {
+            let html = scraper::Html::parse_fragment(html);
+            let item = html.select(&selector).next().unwrap();
+            parse_event(item).unwrap()
+        };
+
+        let live = parse(
+            r#"<a class="event-item" href="/event/2097/champions">
+                <div class="event-item-inner"><span class="mod-live">LIVE</span></div>
+            </a>"#,
+        );
+        assert!(live.has_live_matches);
+
+        let idle = parse(r#"<a class="event-item" href="/event/2097/champions"></a>"#);
+        assert!(!idle.has_live_matches);
+    }
+
+    #[test]
+    fn test_parse_event_tier() {
+        let selector = Selector::parse("a.event-item").unwrap();
+
+        let html = scraper::Html::parse_fragment(
+            r#"<a class="event-item mod-vct" href="/event/1/x"></a>"#,
+        );
+        let item = html.select(&selector).next().unwrap();
+        assert_eq!(parse_event_tier(&item).unwrap(), Some(EventTier::Vct));
+
+        let html = scraper::Html::parse_fragment(
+            r#"<a class="event-item" href="/event/1/x">
+                <div class="event-item-desc-item mod-tier">GC</div>
+            </a>"#,
+        );
+        let item = html.select(&selector).next().unwrap();
+        assert_eq!(parse_event_tier(&item).unwrap(), Some(EventTier::Gc));
+
+        let html = scraper::Html::parse_fragment(
+            r#"<a class="event-item mod-flex" href="/event/1/x"></a>"#,
+        );
+        let item = html.select(&selector).next().unwrap();
+        assert_eq!(parse_event_tier(&item).unwrap(), None);
+    }
+
     #[tokio::test]
     async fn test_get_upcoming_events() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let events_data = get_events(&client, EventType::Upcoming, Region::All, 1).await;
         assert!(events_data.is_ok());
         let events_data = events_data.unwrap();
@@ -139,7 +401,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_completed_events() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let events_data = get_events(&client, EventType::Completed, Region::All, 2).await;
         assert!(events_data.is_ok());
         let events_data = events_data.unwrap();
@@ -1,11 +1,11 @@
 use chrono::{NaiveDate, NaiveTime};
 use itertools::Itertools;
 use scraper::{CaseSensitivity, ElementRef, Selector};
-use tracing::{debug, instrument, warn};
+use tracing::{debug, instrument};
 
-use crate::error::Result;
-use crate::model::{EventMatchList, EventMatchListItem, EventMatchListTeam};
-use crate::vlr_scraper::{self, select_text};
+use crate::error::{ErrorPolicy, Result};
+use crate::model::{EventMatchList, EventMatchListItem, EventMatchListTeam, MatchListStatus};
+use crate::vlr_scraper::{self, normalize_number, select_text, HttpClient, BASE_URL};
 
 const MATCH_DATE_FORMAT: &str = "%a, %B %e, %Y";
 const MATCH_DATE_FORMAT_ALT: &str = "%a, %b %e, %Y";
@@ -13,20 +13,60 @@ const MATCH_TIME_FORMAT: &str = "%I:%M %p";
 
 #[instrument(skip(client))]
 pub(crate) async fn get_event_matchlist(
-    client: &reqwest::Client,
+    client: &HttpClient,
     event_id: u32,
+    series_id: Option<u32>,
 ) -> Result<EventMatchList> {
-    let url = format!("https://www.vlr.gg/event/matches/{event_id}");
+    let url = match series_id {
+        Some(series_id) => client.url(&format!("/event/matches/{event_id}/?series_id={series_id}")),
+        None => client.url(&format!("/event/matches/{event_id}")),
+    };
     let document = vlr_scraper::get_document(client, &url).await?;
-    let matches = parse_matches(&document)?;
+    let matches = parse_matches(&document, client.error_policy)?;
     debug!(count = matches.len(), event_id, "parsed match list");
     Ok(matches)
 }
 
-fn parse_matches(document: &scraper::Html) -> Result<EventMatchList> {
+/// Fetch the site-wide `/matches` feed and keep only the matches being
+/// played right now.
+#[instrument(skip(client))]
+pub(crate) async fn get_live_matches(client: &HttpClient) -> Result<EventMatchList> {
+    let url = client.url("/matches");
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let matches = parse_matches(&document, client.error_policy)?
+        .into_iter()
+        .filter(|m| m.status == MatchListStatus::Live)
+        .collect_vec();
+    debug!(count = matches.len(), "parsed live matches");
+    Ok(matches)
+}
+
+/// Fetch a page of the site-wide `/matches` feed: live matches first, then
+/// upcoming ones, across all events.
+#[instrument(skip(client))]
+pub(crate) async fn get_upcoming_matches(client: &HttpClient, page: u8) -> Result<EventMatchList> {
+    let url = client.url(&format!("/matches/?page={page}"));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let matches = parse_matches(&document, client.error_policy)?;
+    debug!(count = matches.len(), page, "parsed upcoming matches");
+    Ok(matches)
+}
+
+/// Fetch a page of the site-wide `/matches/results` feed of completed
+/// matches, newest first.
+#[instrument(skip(client))]
+pub(crate) async fn get_results(client: &HttpClient, page: u8) -> Result<EventMatchList> {
+    let url = client.url(&format!("/matches/results/?page={page}"));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let matches = parse_matches(&document, client.error_policy)?;
+    debug!(count = matches.len(), page, "parsed match results");
+    Ok(matches)
+}
+
+fn parse_matches(document: &scraper::Html, policy: ErrorPolicy) -> Result<EventMatchList> {
     let match_item_selector = "div#wrapper :is(div.wf-label.mod-large,div.wf-card a.match-item)";
     let selector = Selector::parse(match_item_selector)?;
-    let mut matches = vec![];
+    let mut items = vec![];
     let mut last_date = None;
     for element in document.select(&selector) {
         if element
@@ -42,13 +82,10 @@ fn parse_matches(document: &scraper::Html) -> Result<EventMatchList> {
                 );
             }
         } else {
-            match parse_match_item(&element, last_date) {
-                Ok(item) => matches.push(item),
-                Err(e) => warn!(error = %e, "skipping unparsable match item"),
-            }
+            items.push(parse_match_item(&element, last_date));
         }
     }
-    Ok(matches)
+    policy.collect(items, "match list item")
 }
 
 fn parse_match_item(element: &ElementRef, date: Option<NaiveDate>) -> Result<EventMatchListItem> {
@@ -57,48 +94,95 @@ fn parse_match_item(element: &ElementRef, date: Option<NaiveDate>) -> Result<Eve
         .strip_prefix("/")
         .and_then(|s| s.split('/').map(|s| s.to_string()).collect_tuple())
         .unwrap_or_default();
-    let href = format!("https://www.vlr.gg{href}");
+    let href = format!("{BASE_URL}{href}");
 
-    let time_selector = Selector::parse("div.match-item-time")?;
-    let time = select_text(element, &time_selector);
+    let time_selector = selector!("div.match-item-time");
+    let time = select_text(element, time_selector);
     let time = NaiveTime::parse_from_str(&time, MATCH_TIME_FORMAT).ok();
-    let date_time = date.and_then(|d| time.map(|t| d.and_time(t)));
 
-    let teams_selector = Selector::parse("div.match-item-vs div.match-item-vs-team")?;
-    let teams = element.select(&teams_selector).collect_vec();
+    let status_selector = selector!("div.match-item-eta div.ml");
+    let status = element
+        .select(status_selector)
+        .next()
+        .map(|ml| parse_status(&ml))
+        .unwrap_or_default();
+
+    // A live match's time cell is its scheduled start, not when it is played
+    let date_time = match status {
+        MatchListStatus::Live => None,
+        _ => date.and_then(|d| time.map(|t| d.and_time(t))),
+    };
+
+    let teams_selector = selector!("div.match-item-vs div.match-item-vs-team");
+    let teams = element.select(teams_selector).collect_vec();
     let teams = parse_teams(&teams)?;
 
-    let tags_selector = Selector::parse("div.match-item-vod div.wf-tag")?;
+    let tags_selector = selector!("div.match-item-vod div.wf-tag");
     let tags = element
-        .select(&tags_selector)
+        .select(tags_selector)
         .filter_map(|t| t.text().last())
         .map(|t| t.trim().to_string())
         .collect_vec();
+    let has_vod = tags.iter().any(|t| t.to_lowercase().contains("vod"));
+    let format_tag = tags.iter().find(|t| is_format_tag(t)).cloned();
 
-    let event_text_selector = Selector::parse("div.match-item-event.text-of")?;
+    let event_text_selector = selector!("div.match-item-event.text-of");
     let event_text = element
-        .select(&event_text_selector)
+        .select(event_text_selector)
         .filter_map(|t| t.text().last())
         .map(|t| t.trim().to_string())
         .last()
         .unwrap_or_default();
 
     let event_series_text_selector =
-        Selector::parse("div.match-item-event.text-of div.match-item-event-series.text-of")?;
-    let event_series_text = select_text(element, &event_series_text_selector);
+        selector!("div.match-item-event.text-of div.match-item-event-series.text-of");
+    let event_series_text = select_text(element, event_series_text_selector);
 
     Ok(EventMatchListItem {
         id: id.parse()?,
         slug,
         href,
         date_time,
+        status,
         teams,
         tags,
+        has_vod,
+        format_tag,
         event_text,
         event_series_text,
     })
 }
 
+/// Read the status from the `div.ml` marker (`mod-live`, `mod-upcoming`,
+/// `mod-completed`), falling back to its status text.
+fn parse_status(ml: &ElementRef) -> MatchListStatus {
+    let has = |class| ml.value().has_class(class, CaseSensitivity::CaseSensitive);
+    if has("mod-live") {
+        return MatchListStatus::Live;
+    }
+    if has("mod-upcoming") {
+        return MatchListStatus::Upcoming;
+    }
+    if has("mod-completed") {
+        return MatchListStatus::Completed;
+    }
+    let text = ml.text().collect::<String>().trim().to_lowercase();
+    match text.as_str() {
+        "live" => MatchListStatus::Live,
+        "completed" | "final" => MatchListStatus::Completed,
+        "upcoming" => MatchListStatus::Upcoming,
+        _ => MatchListStatus::Unknown,
+    }
+}
+
+/// Whether a tag names a series format, like `"Bo3"` or `"Best of 5"`.
+fn is_format_tag(tag: &str) -> bool {
+    let tag = tag.trim().to_lowercase();
+    tag.strip_prefix("best of")
+        .or_else(|| tag.strip_prefix("bo"))
+        .is_some_and(|n| !n.trim().is_empty() && n.trim().chars().all(|c| c.is_ascii_digit()))
+}
+
 fn parse_teams(teams: &[ElementRef]) -> Result<Vec<EventMatchListTeam>> {
     teams.iter().map(parse_team).collect()
 }
@@ -108,11 +192,11 @@ fn parse_team(team: &ElementRef) -> Result<EventMatchListTeam> {
         .value()
         .has_class("mod-winner", CaseSensitivity::CaseSensitive);
 
-    let name_selector = Selector::parse("div.match-item-vs-team-name div.text-of")?;
-    let name = select_text(team, &name_selector);
+    let name_selector = selector!("div.match-item-vs-team-name div.text-of");
+    let name = select_text(team, name_selector);
 
-    let score_selector = Selector::parse("div.match-item-vs-team-score")?;
-    let score = select_text(team, &score_selector);
+    let score_selector = selector!("div.match-item-vs-team-score");
+    let score = normalize_number(&select_text(team, score_selector));
     let score = score.parse().ok();
 
     Ok(EventMatchListTeam {
@@ -127,9 +211,104 @@ mod tests {
     use super::*;
     use crate::model::{EventType, Region};
 
+    #[test]
+    fn test_parse_match_item_status() {
+        let status = |eta: &str| {
+            let html = scraper::Html::parse_fragment(&format!(
+                r#"<a class="match-item" href="/378829/sentinels-vs-fnatic-masters">
+                    <div class="match-item-eta">{eta}</div>
+                </a>"#
+            ));
+            let element = html
+                .select(&Selector::parse("a.match-item").unwrap())
+                .next()
+                .unwrap();
+            parse_match_item(&element, None).unwrap().status
+        };
+
+        assert_eq!(
+            status(r#"<div class="ml mod-live"><div class="ml-status">LIVE</div></div>"#),
+            MatchListStatus::Live
+        );
+        assert_eq!(
+            status(r#"<div class="ml mod-upcoming"><div class="ml-eta">2h 15m</div></div>"#),
+            MatchListStatus::Upcoming
+        );
+        assert_eq!(
+            status(r#"<div class="ml"><div class="ml-status">Completed</div></div>"#),
+            MatchListStatus::Completed
+        );
+        assert_eq!(status(""), MatchListStatus::Unknown);
+    }
+
+    #[test]
+    fn test_parse_matches_feed() {
+        let document = scraper::Html::parse_document(
+            r#"<div id="wrapper">
+                <div class="wf-label mod-large">Sat, March 8, 2025 <span>Today</span></div>
+                <div class="wf-card">
+                    <a class="match-item" href="/449001/g2-vs-sentinels-champions-tour">
+                        <div class="match-item-time">3:00 PM</div>
+                        <div class="match-item-eta"><div class="ml mod-live"><div class="ml-status">LIVE</div></div></div>
+                    </a>
+                    <a class="match-item" href="/449002/nrg-vs-loud-champions-tour">
+                        <div class="match-item-time">6:00 PM</div>
+                        <div class="match-item-eta"><div class="ml mod-upcoming"><div class="ml-eta">2h 15m</div></div></div>
+                    </a>
+                </div>
+            </div>"#,
+        );
+        let matches = parse_matches(&document, ErrorPolicy::Strict).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].id, 449001);
+        assert_eq!(matches[0].status, MatchListStatus::Live);
+        assert_eq!(matches[0].date_time, None);
+        assert_eq!(
+            matches[1].date_time,
+            NaiveDate::from_ymd_opt(2025, 3, 8)
+                .unwrap()
+                .and_hms_opt(18, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_match_item_tags() {
+        let html = scraper::Html::parse_fragment(
+            r#"<a class="match-item" href="/378829/sentinels-vs-fnatic-masters">
+                <div class="match-item-time">4:00 PM</div>
+                <div class="match-item-vod">
+                    <div class="wf-tag mod-big">Stats</div>
+                    <div class="wf-tag mod-big">VOD</div>
+                    <div class="wf-tag mod-big">Bo5</div>
+                </div>
+            </a>"#,
+        );
+        let element = html
+            .select(&Selector::parse("a.match-item").unwrap())
+            .next()
+            .unwrap();
+        let item = parse_match_item(&element, None).unwrap();
+        assert_eq!(item.tags, vec!["Stats", "VOD", "Bo5"]);
+        assert!(item.has_vod);
+        assert_eq!(item.format_tag.as_deref(), Some("Bo5"));
+
+        let html = scraper::Html::parse_fragment(
+            r#"<a class="match-item" href="/378830/nrg-vs-loud-masters">
+                <div class="match-item-vod"><div class="wf-tag">Stats</div></div>
+            </a>"#,
+        );
+        let element = html
+            .select(&Selector::parse("a.match-item").unwrap())
+            .next()
+            .unwrap();
+        let item = parse_match_item(&element, None).unwrap();
+        assert!(!item.has_vod);
+        assert_eq!(item.format_tag, None);
+    }
+
     #[tokio::test]
     async fn test_get_matches() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
 
         let events = crate::vlr_scraper::events::list::get_events(
             &client,
@@ -141,7 +320,42 @@ mod tests {
         .unwrap();
         let event_id = events.events[0].id;
 
-        let matches = get_event_matchlist(&client, event_id).await.unwrap();
+        let matches = get_event_matchlist(&client, event_id, None).await.unwrap();
         assert!(!matches.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_get_matches_for_series() {
+        let client = HttpClient::default();
+        let all = get_event_matchlist(&client, 2097, None).await.unwrap();
+        let stage = get_event_matchlist(&client, 2097, Some(4030))
+            .await
+            .unwrap();
+        assert!(stage.len() <= all.len());
+    }
+
+    #[tokio::test]
+    async fn test_get_upcoming_matches() {
+        let client = HttpClient::default();
+        let matches = get_upcoming_matches(&client, 1).await.unwrap();
+        assert!(!matches.is_empty());
+        assert!(matches
+            .iter()
+            .all(|m| m.status != MatchListStatus::Completed));
+    }
+
+    #[tokio::test]
+    async fn test_get_results() {
+        let client = HttpClient::default();
+        let matches = get_results(&client, 1).await.unwrap();
+        assert!(!matches.is_empty());
+        assert!(matches.iter().all(|m| m.date_time.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_get_live_matches() {
+        let client = HttpClient::default();
+        let live = get_live_matches(&client).await.unwrap();
+        assert!(live.iter().all(|m| m.status == MatchListStatus::Live));
+    }
 }
@@ -1,2 +1,5 @@
+pub(crate) mod bracket;
+pub(crate) mod detail;
 pub(crate) mod list;
 pub(crate) mod matchlist;
+pub(crate) mod standings;
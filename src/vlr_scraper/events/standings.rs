@@ -0,0 +1,179 @@
+use itertools::Itertools;
+use scraper::{ElementRef, Selector};
+use tracing::{debug, instrument};
+
+use crate::error::Result;
+use crate::model::{GroupStanding, StandingRow};
+use crate::vlr_scraper::{self, normalize_number, HttpClient};
+
+#[instrument(skip(client))]
+pub(crate) async fn get_event_standings(
+    client: &HttpClient,
+    event_id: u32,
+) -> Result<Vec<GroupStanding>> {
+    let url = client.url(&format!("/event/{event_id}"));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let standings = parse_standings(&document)?;
+    debug!(event_id, groups = standings.len(), "parsed event standings");
+    Ok(standings)
+}
+
+/// Parse every group table (`table.mod-group`) on an event page.
+fn parse_standings(document: &scraper::Html) -> Result<Vec<GroupStanding>> {
+    let table_selector = Selector::parse("table.wf-table.mod-group")?;
+    document.select(&table_selector).map(parse_group).collect()
+}
+
+fn parse_group(table: ElementRef) -> Result<GroupStanding> {
+    let th_selector = Selector::parse("thead th")?;
+    let row_selector = Selector::parse("tbody tr")?;
+    let td_selector = Selector::parse("td")?;
+    let team_link_selector = Selector::parse("a[href^='/team/']")?;
+    let team_name_selector = Selector::parse(".event-group-team-name, .text-of")?;
+
+    let headers = table
+        .select(&th_selector)
+        .map(|th| cell_text(&th))
+        .collect_vec();
+    let name = headers.first().cloned().unwrap_or_default();
+    let columns = StandingColumns::new(&headers);
+
+    let rows = table
+        .select(&row_selector)
+        .filter_map(|row| {
+            let cells = row.select(&td_selector).collect_vec();
+            let team_cell = cells.first()?;
+            let link = team_cell.select(&team_link_selector).next();
+            let team_id = link
+                .and_then(|a| a.value().attr("href"))
+                .and_then(|href| href.strip_prefix("/team/"))
+                .and_then(|s| s.split('/').next())
+                .and_then(|id| id.parse().ok());
+            let team_name = team_cell
+                .select(&team_name_selector)
+                .next()
+                .map(|e| cell_text(&e))
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| cell_text(team_cell));
+            if team_name.is_empty() {
+                return None;
+            }
+
+            let number = |index: Option<usize>| {
+                index
+                    .and_then(|i| cells.get(i))
+                    .and_then(|c| normalize_number(&cell_text(c)).parse::<i32>().ok())
+                    .unwrap_or(0)
+            };
+            Some(StandingRow {
+                team_id,
+                team_name,
+                wins: u32::try_from(number(columns.wins)).unwrap_or(0),
+                losses: u32::try_from(number(columns.losses)).unwrap_or(0),
+                map_diff: number(columns.map_diff),
+                round_diff: number(columns.round_diff),
+            })
+        })
+        .collect();
+
+    Ok(GroupStanding { name, rows })
+}
+
+/// Column positions in a group table, found by header label. Without a
+/// header, the usual `team, W, L, map +/-, round +/-` order is assumed.
+struct StandingColumns {
+    wins: Option<usize>,
+    losses: Option<usize>,
+    map_diff: Option<usize>,
+    round_diff: Option<usize>,
+}
+
+impl StandingColumns {
+    fn new(headers: &[String]) -> Self {
+        if headers.len() < 2 {
+            return Self {
+                wins: Some(1),
+                losses: Some(2),
+                map_diff: Some(3),
+                round_diff: Some(4),
+            };
+        }
+        let find = |pred: &dyn Fn(&str) -> bool| {
+            headers
+                .iter()
+                .skip(1)
+                .position(|h| pred(&h.to_lowercase()))
+                .map(|i| i + 1)
+        };
+        Self {
+            wins: find(&|h| h == "w" || h == "wins"),
+            losses: find(&|h| h == "l" || h == "losses"),
+            map_diff: find(&|h| h.contains("map")),
+            round_diff: find(&|h| h.contains("rnd") || h.contains("round")),
+        }
+    }
+}
+
+fn cell_text(cell: &ElementRef) -> String {
+    cell.text()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_standings() {
+        let document = scraper::Html::parse_document(
+            r#"<table class="wf-table mod-simple mod-group">
+                <thead><tr>
+                    <th>Group A</th><th>W</th><th>L</th><th>Map +/-</th><th>RND +/-</th>
+                </tr></thead>
+                <tbody>
+                    <tr>
+                        <td><a href="/team/2/sentinels"><div class="text-of">Sentinels</div></a></td>
+                        <td>3</td><td>0</td><td>+5</td><td>+42</td>
+                    </tr>
+                    <tr>
+                        <td><a href="/team/2593/fnatic"><div class="text-of">FNATIC</div></a></td>
+                        <td>0</td><td>3</td><td>−5</td><td>−42</td>
+                    </tr>
+                </tbody>
+            </table>
+            <table class="wf-table mod-simple mod-group">
+                <thead><tr><th>Group B</th><th>W</th><th>L</th><th>Map +/-</th></tr></thead>
+                <tbody>
+                    <tr><td>TBD</td><td>1</td><td>1</td><td>0</td></tr>
+                </tbody>
+            </table>"#,
+        );
+        let standings = parse_standings(&document).unwrap();
+        assert_eq!(standings.len(), 2);
+
+        let group_a = &standings[0];
+        assert_eq!(group_a.name, "Group A");
+        let sen = &group_a.rows[0];
+        assert_eq!(sen.team_id, Some(2));
+        assert_eq!(sen.team_name, "Sentinels");
+        assert_eq!((sen.wins, sen.losses), (3, 0));
+        assert_eq!((sen.map_diff, sen.round_diff), (5, 42));
+        assert_eq!(group_a.rows[1].round_diff, -42);
+
+        // No round differential column
+        let tbd = &standings[1].rows[0];
+        assert_eq!(tbd.team_id, None);
+        assert_eq!(tbd.team_name, "TBD");
+        assert_eq!((tbd.wins, tbd.losses, tbd.map_diff), (1, 1, 0));
+        assert_eq!(tbd.round_diff, 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_event_standings() {
+        let client = HttpClient::default();
+        let standings = get_event_standings(&client, 2097).await.unwrap();
+        assert!(standings.iter().all(|g| !g.name.is_empty()));
+    }
+}
@@ -1,37 +1,50 @@
 use chrono::NaiveDateTime;
 use itertools::Itertools;
 use scraper::{CaseSensitivity, ElementRef, Selector};
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
-use crate::error::{Result, VlrError};
+use crate::error::{ErrorPolicy, Result, VlrError};
 use crate::model::{
-    HeadToHeadMatch, KillMatrixEntry, Match, MatchEconomy, MatchGame, MatchGamePlayer,
-    MatchGameRound, MatchGameTeam, MatchHeader, MatchHeaderTeam, MatchPerformance, MatchStream,
-    PastMatch, PlayerPerformance, TeamEconomy, TeamPastMatches,
+    GameEconomy, GamePerformance, HeadToHeadMatch, KillMatrixEntry, Match, MatchEconomy,
+    MatchFetchOptions, MatchFormat, MatchGame, MatchGamePlayer, MatchGameRound, MatchGameTeam,
+    MatchHeader, MatchHeaderTeam, MatchPerformance, MatchStatus, MatchStream, MultiKills,
+    PastMatch, PatchVersion, PlayerPerformance, PlayerSideStats, RoundWinType, Side, TeamEconomy,
+    TeamPastMatches, VetoAction, VetoActionKind, SCHEMA_VERSION,
+};
+use crate::vlr_scraper::{
+    self, normalize_img_url, normalize_number, select_text, HttpClient, BASE_URL,
 };
-use crate::vlr_scraper::{self, normalize_img_url, select_text};
 
 #[instrument(skip(client))]
-pub(crate) async fn get_match(client: &reqwest::Client, id: u32) -> Result<Match> {
-    let url = format!("https://www.vlr.gg/{id}");
+pub(crate) async fn get_match(
+    client: &HttpClient,
+    id: u32,
+    options: &MatchFetchOptions,
+) -> Result<Match> {
+    let url = client.url(&format!("/{id}"));
     let mut result = {
-        let document = vlr_scraper::get_document(client, &url).await?;
-        let column_selector = Selector::parse("div.col.mod-3")?;
+        let document = vlr_scraper::get_document_caching(client, &url, is_final_match).await?;
+        let column_selector = selector!("div.col.mod-3");
         let column = document
-            .select(&column_selector)
+            .select(column_selector)
             .next()
             .ok_or(VlrError::ElementNotFound {
                 context: "match page column (div.col.mod-3)",
             })?;
-        parse_match(id, &column)?
+        parse_match(id, &column, options, client.error_policy)?
     };
+    if result.is_placeholder {
+        debug!(id, "match is a TBD placeholder, skipping stat tabs");
+        return Ok(result);
+    }
 
     // Fetch performance and economy tabs concurrently
-    let perf_url = format!("https://www.vlr.gg/{id}/?tab=performance");
-    let econ_url = format!("https://www.vlr.gg/{id}/?tab=economy");
+    let perf_url = client.url(&format!("/{id}/?tab=performance"));
+    let econ_url = client.url(&format!("/{id}/?tab=economy"));
+    let is_final = result.header.status_kind == MatchStatus::Final;
     let (perf_result, econ_result) = futures::join!(
-        fetch_and_parse_performance(client, &perf_url, &result),
-        fetch_and_parse_economy(client, &econ_url),
+        fetch_and_parse_performance(client, &perf_url, &result, is_final),
+        fetch_and_parse_economy(client, &econ_url, &result, is_final),
     );
 
     result.performance = match perf_result {
@@ -54,81 +67,187 @@ pub(crate) async fn get_match(client: &reqwest::Client, id: u32) -> Result<Match
     Ok(result)
 }
 
+/// Fetch only the match overview page and parse its per-map scoreboards,
+/// skipping streams, head-to-head, and the performance/economy tabs.
+#[instrument(skip(client))]
+pub(crate) async fn get_match_scoreboard(client: &HttpClient, id: u32) -> Result<Vec<MatchGame>> {
+    let url = client.url(&format!("/{id}"));
+    let document = vlr_scraper::get_document_caching(client, &url, is_final_match).await?;
+    let column_selector = selector!("div.col.mod-3");
+    let column = document
+        .select(column_selector)
+        .next()
+        .ok_or(VlrError::ElementNotFound {
+            context: "match page column (div.col.mod-3)",
+        })?;
+    let games = parse_scoreboard(&column, client.error_policy)?;
+    debug!(id, games = games.len(), "parsed match scoreboard");
+    Ok(games)
+}
+
+/// Parse the header and the games of a match page column. Placeholder
+/// matches have no games.
+fn parse_scoreboard(document: &ElementRef, policy: ErrorPolicy) -> Result<Vec<MatchGame>> {
+    let header_selector = selector!("div.match-header");
+    let header = document
+        .select(header_selector)
+        .next()
+        .ok_or(VlrError::ElementNotFound {
+            context: "match header (div.match-header)",
+        })?;
+    if is_placeholder_header(&header) {
+        return Ok(Vec::new());
+    }
+    let header = parse_header(&header, false)?;
+
+    let games_selector = selector!(GAMES_SELECTOR);
+    let games = document.select(games_selector).collect_vec();
+    parse_games(&header, &games, policy)
+}
+
 async fn fetch_and_parse_performance(
-    client: &reqwest::Client,
+    client: &HttpClient,
     url: &str,
     match_data: &Match,
+    is_final: bool,
 ) -> Result<Option<MatchPerformance>> {
-    let document = vlr_scraper::get_document(client, url).await?;
-    let col_selector = Selector::parse("div.col.mod-3").unwrap_or_else(|_| unreachable!());
+    let document = vlr_scraper::get_document_caching(client, url, |_| is_final).await?;
+    let col_selector = selector!("div.col.mod-3");
     let result = document
-        .select(&col_selector)
+        .select(col_selector)
         .next()
         .and_then(|col| parse_performance(&col, match_data).ok());
     Ok(result)
 }
 
 async fn fetch_and_parse_economy(
-    client: &reqwest::Client,
+    client: &HttpClient,
     url: &str,
+    match_data: &Match,
+    is_final: bool,
 ) -> Result<Option<MatchEconomy>> {
-    let document = vlr_scraper::get_document(client, url).await?;
-    let col_selector = Selector::parse("div.col.mod-3").unwrap_or_else(|_| unreachable!());
+    let document = vlr_scraper::get_document_caching(client, url, |_| is_final).await?;
+    let col_selector = selector!("div.col.mod-3");
     let result = document
-        .select(&col_selector)
+        .select(col_selector)
         .next()
-        .and_then(|col| parse_economy(&col).ok());
+        .and_then(|col| parse_economy(&col, match_data).ok());
     Ok(result)
 }
 
-fn parse_match(id: u32, document: &ElementRef) -> Result<Match> {
-    let header_selector = Selector::parse("div.match-header")?;
+/// Whether a fetched match page shows a finished match, so it can be cached.
+/// Uses the same reading of the status note as [`MatchHeader::status_kind`].
+fn is_final_match(document: &scraper::Html) -> bool {
+    document
+        .select(selector!("div.match-header-vs-note"))
+        .next()
+        .is_some_and(|note| {
+            parse_match_status(&note.text().collect::<String>(), &[]) == MatchStatus::Final
+        })
+}
+
+/// The per-map sections of the stats container, excluding the "All Maps" tab.
+const GAMES_SELECTOR: &str =
+    "div.vm-stats div.vm-stats-container div.vm-stats-game:not([data-game-id='all'])";
+
+fn parse_match(
+    id: u32,
+    document: &ElementRef,
+    options: &MatchFetchOptions,
+    policy: ErrorPolicy,
+) -> Result<Match> {
+    let header_selector = selector!("div.match-header");
     let header = document
-        .select(&header_selector)
+        .select(header_selector)
         .next()
         .ok_or(VlrError::ElementNotFound {
             context: "match header (div.match-header)",
         })?;
-    let header = parse_header(&header)?;
+    let is_placeholder = is_placeholder_header(&header);
+    let mut header = parse_header(&header, is_placeholder)?;
+
+    // Bracket slots whose teams aren't decided yet have nothing else to parse
+    if is_placeholder {
+        header.teams.clear();
+        return Ok(Match {
+            schema_version: SCHEMA_VERSION,
+            id,
+            header,
+            streams: Vec::new(),
+            vods: Vec::new(),
+            games: Vec::new(),
+            head_to_head: Vec::new(),
+            past_matches: Vec::new(),
+            performance: None,
+            economy: None,
+            map_advantage_team: None,
+            veto: Vec::new(),
+            is_placeholder,
+        });
+    }
 
     let streams_container_selector =
-        Selector::parse("div.match-streams div.match-streams-container div.match-streams-btn")?;
-    let streams_name_selector = Selector::parse("div.match-streams-btn-embed span")?;
-    let streams_link_selector = Selector::parse("a.match-streams-btn-external")?;
+        selector!("div.match-streams div.match-streams-container div.match-streams-btn");
+    let streams_name_selector = selector!("div.match-streams-btn-embed span");
+    let streams_link_selector = selector!("a.match-streams-btn-external");
+    let streams_flag_selector = selector!("i.flag");
     let streams = document
-        .select(&streams_container_selector)
+        .select(streams_container_selector)
         .map(|e| {
-            let name = select_text(&e, &streams_name_selector);
+            let name = select_text(&e, streams_name_selector);
             let link = e
-                .select(&streams_link_selector)
+                .select(streams_link_selector)
                 .next()
                 .and_then(|e| e.value().attr("href"))
                 .unwrap_or_default()
                 .to_string();
-            MatchStream { name, link }
+            let language = e.select(streams_flag_selector).next().and_then(|f| {
+                f.value()
+                    .classes()
+                    .find(|c| c.starts_with("mod-"))
+                    .map(|c| c.strip_prefix("mod-").unwrap_or_default().to_string())
+            });
+            MatchStream {
+                name,
+                link,
+                language,
+            }
         })
         .collect_vec();
 
-    let vods_selector = Selector::parse("div.match-vods div.match-streams-container a")?;
+    let vods_selector = selector!("div.match-vods div.match-streams-container a");
     let vods = document
-        .select(&vods_selector)
+        .select(vods_selector)
         .map(|e| {
             let name = e.text().next().unwrap_or_default().trim().to_string();
             let link = e.value().attr("href").unwrap_or_default().to_string();
-            MatchStream { name, link }
+            MatchStream {
+                name,
+                link,
+                language: None,
+            }
         })
         .collect_vec();
 
-    let games_selector = Selector::parse(
-        "div.vm-stats div.vm-stats-container div.vm-stats-game:not([data-game-id='all'])",
-    )?;
-    let games = document.select(&games_selector).collect_vec();
-    let games = parse_games(&header, &games)?;
+    let games_selector = selector!(GAMES_SELECTOR);
+    let games = document
+        .select(games_selector)
+        .enumerate()
+        .filter(|(i, _)| options.only_games.as_ref().is_none_or(|g| g.contains(i)))
+        .map(|(_, g)| g)
+        .collect_vec();
+    let games = parse_games(&header, &games, policy)?;
 
     let head_to_head = parse_head_to_head(document)?;
     let past_matches = parse_past_matches(&header, document)?;
 
-    Ok(Match {
+    let veto_note_selector = selector!("div.match-header-note");
+    let veto_note = select_text(document, veto_note_selector);
+    let map_advantage_team = parse_map_advantage(&header, &veto_note);
+    let veto = parse_veto(&header, &games, &veto_note);
+
+    let result = Match {
+        schema_version: SCHEMA_VERSION,
         id,
         header,
         streams,
@@ -138,13 +257,49 @@ fn parse_match(id: u32, document: &ElementRef) -> Result<Match> {
         past_matches,
         performance: None,
         economy: None,
-    })
+        map_advantage_team,
+        veto,
+        is_placeholder,
+    };
+
+    // A final match should contain at least as many maps as it takes to win
+    // the series; fewer usually means a forfeit or a map section we failed to parse.
+    // Skipped when only some games were requested. A map advantage counts as
+    // a map won without being played.
+    if result.header.status_kind == MatchStatus::Final && options.only_games.is_none() {
+        if let Some(best_of) = result.header.best_of() {
+            let required =
+                usize::from(best_of / 2 + 1) - usize::from(result.map_advantage_team.is_some());
+            if result.games.len() < required || !result.is_series_complete() {
+                warn!(
+                    id,
+                    best_of,
+                    maps = result.games.len(),
+                    "final match has fewer maps than its format requires"
+                );
+            }
+        }
+    }
+
+    Ok(result)
 }
 
-fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
-    let event_icon_selector = Selector::parse("div.match-header-super a.match-header-event img")?;
+/// Whether the header is a "TBD vs TBD" bracket placeholder.
+fn is_placeholder_header(header: &ElementRef) -> bool {
+    let name_selector = selector!("div.match-header-vs div.wf-title-med");
+    let names = header
+        .select(name_selector)
+        .map(|e| e.text().collect::<String>().trim().to_lowercase())
+        .collect_vec();
+    names.len() >= 2 && names.iter().all(|n| n == "tbd")
+}
+
+/// Parse the match header. A placeholder that isn't scheduled yet may have no
+/// date; its [`MatchHeader::date`] is then the Unix epoch.
+fn parse_header(header: &ElementRef, is_placeholder: bool) -> Result<MatchHeader> {
+    let event_icon_selector = selector!("div.match-header-super a.match-header-event img");
     let event_icon = header
-        .select(&event_icon_selector)
+        .select(event_icon_selector)
         .next()
         .map(|e| {
             e.value()
@@ -157,44 +312,48 @@ fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
         })?;
 
     let event_title_selector =
-        Selector::parse("div.match-header-super a.match-header-event div div:first-child")?;
-    let event_title = select_text(header, &event_title_selector);
+        selector!("div.match-header-super a.match-header-event div div:first-child");
+    let event_title = select_text(header, event_title_selector);
 
-    let event_series_name_selector = Selector::parse(
-        "div.match-header-super a.match-header-event div div.match-header-event-series",
-    )?;
-    let event_series_name = select_text(header, &event_series_name_selector);
+    let event_series_name_selector =
+        selector!("div.match-header-super a.match-header-event div div.match-header-event-series");
+    let event_series_name = select_text(header, event_series_name_selector);
 
     let match_date_selector =
-        Selector::parse("div.match-header-super div.match-header-date div.moment-tz-convert")?;
-    let element = header
-        .select(&match_date_selector)
-        .next()
-        .ok_or(VlrError::ElementNotFound {
+        selector!("div.match-header-super div.match-header-date div.moment-tz-convert");
+    let date_element = header.select(match_date_selector).next();
+    let date_ts = date_element
+        .and_then(|e| e.value().attr("data-utc-ts"))
+        .unwrap_or_default();
+    let date = if is_placeholder && date_ts.trim().is_empty() {
+        NaiveDateTime::default()
+    } else {
+        date_element.ok_or(VlrError::ElementNotFound {
             context: "match date element (moment-tz-convert)",
         })?;
-    let date = element.value().attr("data-utc-ts").unwrap_or_default();
-    let date = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S")?;
+        parse_utc_ts(date_ts)?
+    };
 
     let patch_selector =
-        Selector::parse("div.match-header-super div.match-header-date > div:nth-child(3)")?;
-    let patch_raw = select_text(header, &patch_selector);
+        selector!("div.match-header-super div.match-header-date > div:nth-child(3)");
+    let patch_raw = select_text(header, patch_selector);
     let patch = patch_raw
         .strip_prefix("Patch ")
         .unwrap_or(&patch_raw)
         .to_string();
+    let patch_version = PatchVersion::parse(&patch);
 
-    let vs_note_selector = Selector::parse("div.match-header-vs-note")?;
+    let vs_note_selector = selector!("div.match-header-vs-note");
     let vs_notes: Vec<String> = header
-        .select(&vs_note_selector)
+        .select(vs_note_selector)
         .map(|e| e.text().next().unwrap_or_default().trim().to_string())
         .collect();
     let status = vs_notes.first().cloned().unwrap_or_default();
     let format = vs_notes.get(1).cloned().unwrap_or_default();
 
-    let event_link_selector = Selector::parse("div.match-header-super a.match-header-event")?;
+    let event_link_selector = selector!("div.match-header-super a.match-header-event");
     let event_href = header
-        .select(&event_link_selector)
+        .select(event_link_selector)
         .next()
         .and_then(|e| e.value().attr("href"))
         .unwrap_or_default();
@@ -211,14 +370,16 @@ fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
         let slug = parts.get(1).unwrap_or(&"").to_string();
         (id, slug)
     };
+    let (stage_id, stage_slug) = parse_event_stage(event_href);
+    let event_region = parse_event_region(&format!("{event_title} {event_series_name}"));
 
     let note_selector =
-        Selector::parse("div.match-header-super div.match-header-date *:not(.moment-tz-convert)")?;
-    let note = select_text(header, &note_selector);
+        selector!("div.match-header-super div.match-header-date *:not(.moment-tz-convert)");
+    let note = select_text(header, note_selector);
 
-    let team_links_selector = Selector::parse("div.match-header-vs a.match-header-link")?;
+    let team_links_selector = selector!("div.match-header-vs a.match-header-link");
     let team_links = header
-        .select(&team_links_selector)
+        .select(team_links_selector)
         .filter_map(|e| e.value().attr("href"))
         .collect_vec();
     let team_id_slug: Vec<(u32, String)> = team_links
@@ -235,16 +396,11 @@ fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
         .collect_vec();
     let team_hrefs = team_links
         .iter()
-        .map(|e| {
-            format!(
-                "https://www.vlr.gg/{}",
-                e.strip_prefix('/').unwrap_or_default()
-            )
-        })
+        .map(|e| format!("{BASE_URL}/{}", e.strip_prefix('/').unwrap_or_default()))
         .collect_vec();
-    let team_icons_selector = Selector::parse("div.match-header-vs a.match-header-link img")?;
+    let team_icons_selector = selector!("div.match-header-vs a.match-header-link img");
     let team_icons = header
-        .select(&team_icons_selector)
+        .select(team_icons_selector)
         .map(|e| {
             e.value()
                 .attr("src")
@@ -253,10 +409,9 @@ fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
         })
         .collect_vec();
 
-    let team_names_selector =
-        Selector::parse("div.match-header-vs a.match-header-link div.wf-title-med")?;
+    let team_names_selector = selector!("div.match-header-vs a.match-header-link div.wf-title-med");
     let team_names = header
-        .select(&team_names_selector)
+        .select(team_names_selector)
         .map(|e| e.text().next().unwrap_or_default().trim().to_string())
         .collect_vec();
 
@@ -267,7 +422,7 @@ fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
         .map(|sel| {
             header
                 .select(&sel)
-                .map(|e| e.text().next().unwrap_or_default().trim().to_string())
+                .map(|e| normalize_number(e.text().next().unwrap_or_default()))
                 .map(|s| s.parse().ok())
                 .collect_vec()
         })
@@ -278,6 +433,7 @@ fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
     } else {
         vec![None, None]
     };
+    let status_kind = parse_match_status(&status, &team_scores);
 
     let teams = team_id_slug
         .into_iter()
@@ -303,26 +459,129 @@ fn parse_header(header: &ElementRef) -> Result<MatchHeader> {
         event_series_name,
         event_id,
         event_slug,
+        event_region,
+        stage_id,
+        stage_slug,
         date,
         patch,
+        patch_version,
+        format_kind: MatchFormat::from(format.as_str()),
         format,
         status,
+        status_kind,
         note,
         teams,
     })
 }
 
+/// Region names as they appear in event titles, with the name they map to.
+/// Multi-word and more specific names come first so they win over shorter
+/// ones ("Southeast Asia" before "Asia").
+const EVENT_REGION_HINTS: [(&str, &str); 17] = [
+    ("north america", "North America"),
+    ("latin america", "Latin America"),
+    ("southeast asia", "Southeast Asia"),
+    ("south asia", "South Asia"),
+    ("middle east", "MENA"),
+    ("americas", "Americas"),
+    ("emea", "EMEA"),
+    ("pacific", "Pacific"),
+    ("china", "China"),
+    ("europe", "Europe"),
+    ("brazil", "Brazil"),
+    ("japan", "Japan"),
+    ("korea", "Korea"),
+    ("oceania", "Oceania"),
+    ("latam", "Latin America"),
+    ("mena", "MENA"),
+    ("asia", "Asia"),
+];
+
+/// Infer an event's region from region names in its title, matching whole
+/// words only. Returns `None` for international or unhinted events.
+fn parse_event_region(text: &str) -> Option<String> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let text = format!(" {} ", words.join(" "));
+    EVENT_REGION_HINTS
+        .iter()
+        .find(|(hint, _)| text.contains(&format!(" {hint} ")))
+        .map(|(_, region)| region.to_string())
+}
+
+/// Formats seen in the header's `data-utc-ts` attribute, tried in order.
+const UTC_TS_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+];
+
+/// Parse a `data-utc-ts` timestamp, accepting it with or without seconds.
+/// On failure, returns the error for the primary (with-seconds) format.
+fn parse_utc_ts(ts: &str) -> Result<NaiveDateTime> {
+    let ts = ts.trim();
+    let primary = NaiveDateTime::parse_from_str(ts, UTC_TS_FORMATS[0]);
+    UTC_TS_FORMATS[1..]
+        .iter()
+        .fold(primary, |result, format| {
+            result.or_else(|e| NaiveDateTime::parse_from_str(ts, format).map_err(|_| e))
+        })
+        .map_err(Into::into)
+}
+
+/// Parse the stage a match header's event link points at, e.g.
+/// `/event/2097/valorant-champions-2024/playoffs?series_id=4030`. The id comes
+/// from the `series_id` query parameter and the slug from the path segment
+/// after the event slug; either is `None` when the link doesn't carry it.
+fn parse_event_stage(href: &str) -> (Option<u32>, Option<String>) {
+    let (path, query) = href.split_once('?').unwrap_or((href, ""));
+    let stage_id = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "series_id")
+        .and_then(|(_, value)| value.parse().ok());
+    let stage_slug = path
+        .strip_prefix("/event/")
+        .and_then(|rest| rest.split('/').nth(2))
+        .filter(|s| !s.is_empty())
+        .map(str::to_string);
+    (stage_id, stage_slug)
+}
+
+/// Derive a [`MatchStatus`] from the header's status note, falling back to
+/// whether scores are shown when the note isn't a recognized status (e.g. it
+/// holds a countdown or a stage label).
+fn parse_match_status(status: &str, scores: &[Option<u8>]) -> MatchStatus {
+    let status = status.trim().to_lowercase();
+    if status.contains("live") {
+        MatchStatus::Live
+    } else if status.starts_with("final") || status.starts_with("completed") {
+        MatchStatus::Final
+    } else if status.starts_with("upcoming")
+        || status.starts_with("tbd")
+        || scores.iter().all(Option::is_none)
+    {
+        MatchStatus::Upcoming
+    } else {
+        MatchStatus::Unknown
+    }
+}
+
 fn parse_head_to_head(document: &ElementRef) -> Result<Vec<HeadToHeadMatch>> {
-    let item_selector = Selector::parse("div.match-h2h a.wf-module-item.mod-h2h")?;
-    let event_icon_selector = Selector::parse("div.match-h2h-matches-event img")?;
-    let event_name_selector = Selector::parse("div.match-h2h-matches-event-name")?;
-    let event_series_selector = Selector::parse("div.match-h2h-matches-event-series")?;
-    let score_rf_selector = Selector::parse("span.rf")?;
-    let score_ra_selector = Selector::parse("span.ra")?;
-    let date_selector = Selector::parse("div.match-h2h-matches-date")?;
+    let item_selector = selector!("div.match-h2h a.wf-module-item.mod-h2h");
+    let event_icon_selector = selector!("div.match-h2h-matches-event img");
+    let event_name_selector = selector!("div.match-h2h-matches-event-name");
+    let event_series_selector = selector!("div.match-h2h-matches-event-series");
+    let score_rf_selector = selector!("span.rf");
+    let score_ra_selector = selector!("span.ra");
+    let date_selector = selector!("div.match-h2h-matches-date");
 
     let matches = document
-        .select(&item_selector)
+        .select(item_selector)
         .filter_map(|e| {
             let href = e.value().attr("href").unwrap_or_default();
             let href_trimmed = href.strip_prefix('/').unwrap_or(href);
@@ -331,28 +590,20 @@ fn parse_head_to_head(document: &ElementRef) -> Result<Vec<HeadToHeadMatch>> {
             let match_slug = match_slug.to_string();
 
             let event_icon = e
-                .select(&event_icon_selector)
+                .select(event_icon_selector)
                 .next()
                 .and_then(|img| img.value().attr("src"))
                 .map(normalize_img_url)
                 .unwrap_or_default();
-            let event_name = select_text(&e, &event_name_selector);
-            let event_series = select_text(&e, &event_series_selector);
+            let event_name = select_text(&e, event_name_selector);
+            let event_series = select_text(&e, event_series_selector);
 
-            let rf_el = e.select(&score_rf_selector).next()?;
-            let ra_el = e.select(&score_ra_selector).next()?;
-            let team1_score: u8 = rf_el
-                .text()
-                .next()
-                .unwrap_or_default()
-                .trim()
+            let rf_el = e.select(score_rf_selector).next()?;
+            let ra_el = e.select(score_ra_selector).next()?;
+            let team1_score: u8 = normalize_number(rf_el.text().next().unwrap_or_default())
                 .parse()
                 .ok()?;
-            let team2_score: u8 = ra_el
-                .text()
-                .next()
-                .unwrap_or_default()
-                .trim()
+            let team2_score: u8 = normalize_number(ra_el.text().next().unwrap_or_default())
                 .parse()
                 .ok()?;
 
@@ -365,7 +616,7 @@ fn parse_head_to_head(document: &ElementRef) -> Result<Vec<HeadToHeadMatch>> {
                 1u8
             };
 
-            let date = select_text(&e, &date_selector);
+            let date = select_text(&e, date_selector);
 
             Some(HeadToHeadMatch {
                 match_id,
@@ -385,21 +636,21 @@ fn parse_head_to_head(document: &ElementRef) -> Result<Vec<HeadToHeadMatch>> {
 }
 
 fn parse_past_matches(header: &MatchHeader, document: &ElementRef) -> Result<Vec<TeamPastMatches>> {
-    let card_selector = Selector::parse("div.match-histories")?;
-    let item_selector = Selector::parse("a.match-histories-item")?;
-    let score_rf_selector = Selector::parse("span.rf")?;
-    let score_ra_selector = Selector::parse("span.ra")?;
-    let opponent_name_selector = Selector::parse("span.match-histories-item-opponent-name")?;
-    let opponent_logo_selector = Selector::parse("img.match-histories-item-opponent-logo")?;
-    let date_selector = Selector::parse("div.match-histories-item-date")?;
+    let card_selector = selector!("div.match-histories");
+    let item_selector = selector!("a.match-histories-item");
+    let score_rf_selector = selector!("span.rf");
+    let score_ra_selector = selector!("span.ra");
+    let opponent_name_selector = selector!("span.match-histories-item-opponent-name");
+    let opponent_logo_selector = selector!("img.match-histories-item-opponent-logo");
+    let date_selector = selector!("div.match-histories-item-date");
 
     let past_matches = document
-        .select(&card_selector)
+        .select(card_selector)
         .enumerate()
         .map(|(i, card)| {
             let team_id = header.teams.get(i).map(|t| t.id).unwrap_or_default();
             let matches = card
-                .select(&item_selector)
+                .select(item_selector)
                 .filter_map(|e| {
                     let href = e.value().attr("href").unwrap_or_default();
                     let href_trimmed = href.strip_prefix('/').unwrap_or(href);
@@ -408,38 +659,38 @@ fn parse_past_matches(header: &MatchHeader, document: &ElementRef) -> Result<Vec
                     let match_id = match_id_str.parse::<u32>().ok()?;
                     let match_slug = match_slug.to_string();
 
-                    let score_for: u8 = e
-                        .select(&score_rf_selector)
-                        .next()?
-                        .text()
-                        .next()
-                        .unwrap_or_default()
-                        .trim()
-                        .parse()
-                        .ok()?;
-                    let score_against: u8 = e
-                        .select(&score_ra_selector)
-                        .next()?
-                        .text()
-                        .next()
-                        .unwrap_or_default()
-                        .trim()
-                        .parse()
-                        .ok()?;
+                    let score_for: u8 = normalize_number(
+                        e.select(score_rf_selector)
+                            .next()?
+                            .text()
+                            .next()
+                            .unwrap_or_default(),
+                    )
+                    .parse()
+                    .ok()?;
+                    let score_against: u8 = normalize_number(
+                        e.select(score_ra_selector)
+                            .next()?
+                            .text()
+                            .next()
+                            .unwrap_or_default(),
+                    )
+                    .parse()
+                    .ok()?;
 
                     let is_win = e
                         .value()
                         .has_class("mod-win", CaseSensitivity::CaseSensitive);
 
-                    let opponent_name = select_text(&e, &opponent_name_selector);
+                    let opponent_name = select_text(&e, opponent_name_selector);
                     let opponent_logo = e
-                        .select(&opponent_logo_selector)
+                        .select(opponent_logo_selector)
                         .next()
                         .and_then(|img| img.value().attr("src"))
                         .map(normalize_img_url)
                         .unwrap_or_default();
 
-                    let date = select_text(&e, &date_selector);
+                    let date = select_text(&e, date_selector);
 
                     Some(PastMatch {
                         match_id,
@@ -463,56 +714,92 @@ fn parse_past_matches(header: &MatchHeader, document: &ElementRef) -> Result<Vec
 
 /// Build a name→id lookup from all players in the match games.
 fn build_player_name_map(m: &Match) -> std::collections::HashMap<String, u32> {
-    let mut map = std::collections::HashMap::new();
-    for game in &m.games {
-        for team in &game.teams {
-            for player in &team.players {
-                if player.id != 0 && !player.name.is_empty() {
-                    map.insert(player.name.clone(), player.id);
-                }
-            }
-        }
-    }
-    map
+    m.players()
+        .filter(|p| p.id != 0 && !p.name.is_empty())
+        .map(|p| (p.name.clone(), p.id))
+        .collect()
 }
 
 fn parse_performance(document: &ElementRef, m: &Match) -> Result<MatchPerformance> {
     let name_map = build_player_name_map(m);
 
     // The "all" game section contains the aggregated performance tables
-    let all_game_selector = Selector::parse("div.vm-stats div.vm-stats-game[data-game-id='all']")?;
+    let all_game_selector = selector!("div.vm-stats div.vm-stats-game[data-game-id='all']");
     let all_game = document
-        .select(&all_game_selector)
+        .select(all_game_selector)
         .next()
         .ok_or(VlrError::ElementNotFound {
             context: "performance all-game section",
         })?;
+    let (kill_matrix, player_performances) = parse_performance_section(&all_game, &name_map)?;
+
+    // Each map has its own section keyed by the same game id as the overview
+    let game_selector =
+        selector!("div.vm-stats div.vm-stats-game[data-game-id]:not([data-game-id='all'])");
+    let per_game = document
+        .select(game_selector)
+        .filter_map(|section| {
+            let game_id: u32 = section.value().attr("data-game-id")?.parse().ok()?;
+            let (kill_matrix, player_performances) =
+                match parse_performance_section(&section, &name_map) {
+                    Ok(tables) => tables,
+                    Err(e) => {
+                        debug!(game_id, error = %e, "skipping map without performance data");
+                        return None;
+                    }
+                };
+            let map = m
+                .games
+                .iter()
+                .find(|g| g.game_id == Some(game_id))
+                .map(|g| g.map.clone())
+                .unwrap_or_default();
+            Some(GamePerformance {
+                game_id,
+                map,
+                kill_matrix,
+                player_performances,
+            })
+        })
+        .collect();
 
+    Ok(MatchPerformance {
+        kill_matrix,
+        player_performances,
+        per_game,
+    })
+}
+
+/// Parse the kill matrix and advanced stats tables of one performance
+/// section (all maps or a single map).
+fn parse_performance_section(
+    section: &ElementRef,
+    name_map: &std::collections::HashMap<String, u32>,
+) -> Result<(Vec<KillMatrixEntry>, Vec<PlayerPerformance>)> {
     // --- Kill Matrix (table.mod-normal) ---
-    let matrix_selector = Selector::parse("table.mod-normal")?;
-    let matrix_table =
-        all_game
-            .select(&matrix_selector)
-            .next()
-            .ok_or(VlrError::ElementNotFound {
-                context: "kill matrix table (table.mod-normal)",
-            })?;
+    let matrix_selector = selector!("table.mod-normal");
+    let matrix_table = section
+        .select(matrix_selector)
+        .next()
+        .ok_or(VlrError::ElementNotFound {
+            context: "kill matrix table (table.mod-normal)",
+        })?;
 
-    let row_selector = Selector::parse("tbody tr")?;
-    let cell_selector = Selector::parse("td")?;
-    let stats_sq_selector = Selector::parse("div.stats-sq")?;
-    let team_div_selector = Selector::parse("div.team > div")?;
+    let row_selector = selector!("tbody tr");
+    let cell_selector = selector!("td");
+    let stats_sq_selector = selector!("div.stats-sq");
+    let team_div_selector = selector!("div.team > div");
 
-    let rows: Vec<ElementRef> = matrix_table.select(&row_selector).collect();
+    let rows: Vec<ElementRef> = matrix_table.select(row_selector).collect();
 
     // First row = column headers (victim players)
     let victim_names: Vec<String> = rows
         .first()
         .map(|row| {
-            row.select(&cell_selector)
+            row.select(cell_selector)
                 .skip(1) // skip empty corner cell
                 .map(|cell| {
-                    select_text(&cell, &team_div_selector)
+                    select_text(&cell, team_div_selector)
                 })
                 .collect()
         })
@@ -526,18 +813,18 @@ fn parse_performance(document: &ElementRef, m: &Match) -> Result<MatchPerformanc
     // Data rows (row 1+) = killer players
     let mut kill_matrix = Vec::new();
     for row in rows.iter().skip(1) {
-        let cells: Vec<ElementRef> = row.select(&cell_selector).collect();
+        let cells: Vec<ElementRef> = row.select(cell_selector).collect();
         if cells.is_empty() {
             continue;
         }
 
-        let killer_name = select_text(&cells[0], &team_div_selector);
+        let killer_name = select_text(&cells[0], team_div_selector);
         let killer_id = name_map.get(&killer_name).copied().unwrap_or(0);
 
         for (ci, cell) in cells.iter().skip(1).enumerate() {
             let stat_squares: Vec<String> = cell
-                .select(&stats_sq_selector)
-                .map(|s| s.text().next().unwrap_or_default().trim().to_string())
+                .select(stats_sq_selector)
+                .map(|s| normalize_number(s.text().next().unwrap_or_default()))
                 .collect();
 
             // Each cell has [kills, deaths, diff] - we only need kills and deaths
@@ -562,23 +849,23 @@ fn parse_performance(document: &ElementRef, m: &Match) -> Result<MatchPerformanc
     }
 
     // --- Advanced Stats (table.mod-adv-stats) ---
-    let adv_selector = Selector::parse("table.mod-adv-stats")?;
-    let adv_table = all_game
-        .select(&adv_selector)
+    let adv_selector = selector!("table.mod-adv-stats");
+    let adv_table = section
+        .select(adv_selector)
         .next()
         .ok_or(VlrError::ElementNotFound {
             context: "advanced stats table (table.mod-adv-stats)",
         })?;
 
     let mut player_performances = Vec::new();
-    for row in adv_table.select(&row_selector) {
-        let cells: Vec<ElementRef> = row.select(&cell_selector).collect();
+    for row in adv_table.select(row_selector) {
+        let cells: Vec<ElementRef> = row.select(cell_selector).collect();
         // Player rows have 14 cells: [name, agent, 2K, 3K, 4K, 5K, 1v1, 1v2, 1v3, 1v4, 1v5, ECON, PL, DE]
         if cells.len() < 14 {
             continue;
         }
 
-        let player_name = select_text(&cells[0], &team_div_selector);
+        let player_name = select_text(&cells[0], team_div_selector);
         if player_name.is_empty() {
             continue;
         }
@@ -588,16 +875,14 @@ fn parse_performance(document: &ElementRef, m: &Match) -> Result<MatchPerformanc
             cells
                 .get(idx)
                 .and_then(|c| c.text().next())
-                .map(|t| t.trim())
-                .and_then(|s| s.parse().ok())
+                .and_then(|s| normalize_number(s).parse().ok())
                 .unwrap_or(0)
         };
         let parse_u16 = |idx: usize| -> u16 {
             cells
                 .get(idx)
                 .and_then(|c| c.text().next())
-                .map(|t| t.trim())
-                .and_then(|s| s.parse().ok())
+                .and_then(|s| normalize_number(s).parse().ok())
                 .unwrap_or(0)
         };
 
@@ -619,37 +904,68 @@ fn parse_performance(document: &ElementRef, m: &Match) -> Result<MatchPerformanc
         });
     }
 
-    Ok(MatchPerformance {
-        kill_matrix,
-        player_performances,
-    })
+    Ok((kill_matrix, player_performances))
 }
 
-fn parse_economy(document: &ElementRef) -> Result<MatchEconomy> {
-    let all_game_selector = Selector::parse("div.vm-stats div.vm-stats-game[data-game-id='all']")?;
+fn parse_economy(document: &ElementRef, m: &Match) -> Result<MatchEconomy> {
+    let all_game_selector = selector!("div.vm-stats div.vm-stats-game[data-game-id='all']");
     let all_game = document
-        .select(&all_game_selector)
+        .select(all_game_selector)
         .next()
         .ok_or(VlrError::ElementNotFound {
             context: "economy all-game section",
         })?;
+    let teams = parse_economy_section(&all_game)?;
+
+    // Each map has its own section keyed by the same game id as the overview
+    let game_selector =
+        selector!("div.vm-stats div.vm-stats-game[data-game-id]:not([data-game-id='all'])");
+    let per_game = document
+        .select(game_selector)
+        .filter_map(|section| {
+            let game_id: u32 = section.value().attr("data-game-id")?.parse().ok()?;
+            let teams = match parse_economy_section(&section) {
+                Ok(teams) => teams,
+                Err(e) => {
+                    debug!(game_id, error = %e, "skipping map without economy data");
+                    return None;
+                }
+            };
+            let map = m
+                .games
+                .iter()
+                .find(|g| g.game_id == Some(game_id))
+                .map(|g| g.map.clone())
+                .unwrap_or_default();
+            Some(GameEconomy {
+                game_id,
+                map,
+                teams,
+            })
+        })
+        .collect();
 
-    let table_selector = Selector::parse("table.mod-econ")?;
-    let table = all_game
-        .select(&table_selector)
+    Ok(MatchEconomy { teams, per_game })
+}
+
+/// Parse the economy table of one section (all maps or a single map).
+fn parse_economy_section(section: &ElementRef) -> Result<Vec<TeamEconomy>> {
+    let table_selector = selector!("table.mod-econ");
+    let table = section
+        .select(table_selector)
         .next()
         .ok_or(VlrError::ElementNotFound {
             context: "economy table (table.mod-econ)",
         })?;
 
-    let row_selector = Selector::parse("tbody tr")?;
-    let cell_selector = Selector::parse("td")?;
-    let stats_sq_selector = Selector::parse("div.stats-sq")?;
+    let row_selector = selector!("tbody tr");
+    let cell_selector = selector!("td");
+    let stats_sq_selector = selector!("div.stats-sq");
 
     let teams = table
-        .select(&row_selector)
+        .select(row_selector)
         .filter_map(|row| {
-            let cells: Vec<ElementRef> = row.select(&cell_selector).collect();
+            let cells: Vec<ElementRef> = row.select(cell_selector).collect();
             // Team rows have 6 td cells: [name, pistol_won, eco(won), $(won), $$(won), $$$(won)]
             if cells.len() < 6 {
                 return None;
@@ -661,7 +977,7 @@ fn parse_economy(document: &ElementRef) -> Result<MatchEconomy> {
             }
 
             let sq_text = |cell: &ElementRef| -> String {
-                cell.select(&stats_sq_selector)
+                cell.select(stats_sq_selector)
                     .next()
                     .map(|s| s.text().collect::<String>().trim().to_string())
                     .unwrap_or_default()
@@ -671,15 +987,17 @@ fn parse_economy(document: &ElementRef) -> Result<MatchEconomy> {
             let parse_rounds_won = |text: &str| -> (u8, u8) {
                 // Split on '(' to get "9 " and "3)"
                 if let Some((total_str, won_part)) = text.split_once('(') {
-                    let rounds: u8 = total_str.trim().parse().unwrap_or(0);
-                    let won: u8 = won_part.trim_end_matches(')').trim().parse().unwrap_or(0);
+                    let rounds: u8 = normalize_number(total_str).parse().unwrap_or(0);
+                    let won: u8 = normalize_number(won_part.trim_end_matches(')'))
+                        .parse()
+                        .unwrap_or(0);
                     (rounds, won)
                 } else {
                     (0, 0)
                 }
             };
 
-            let pistol_won: u8 = sq_text(&cells[1]).parse().unwrap_or(0);
+            let pistol_won: u8 = normalize_number(&sq_text(&cells[1])).parse().unwrap_or(0);
 
             let (eco_rounds, eco_won) = parse_rounds_won(&sq_text(&cells[2]));
             let (semi_eco_rounds, semi_eco_won) = parse_rounds_won(&sq_text(&cells[3]));
@@ -701,20 +1019,28 @@ fn parse_economy(document: &ElementRef) -> Result<MatchEconomy> {
         })
         .collect_vec();
 
-    Ok(MatchEconomy { teams })
+    Ok(teams)
 }
 
-fn parse_games(header: &MatchHeader, games: &[ElementRef]) -> Result<Vec<MatchGame>> {
-    games.iter().map(|g| parse_game(header, g)).collect()
+fn parse_games(
+    header: &MatchHeader,
+    games: &[ElementRef],
+    policy: ErrorPolicy,
+) -> Result<Vec<MatchGame>> {
+    policy.collect(games.iter().map(|g| parse_game(header, g)), "game")
 }
 
 fn parse_game(header: &MatchHeader, game: &ElementRef) -> Result<MatchGame> {
-    let map_name_selector =
-        Selector::parse("div.vm-stats-game-header div.map div:first-child span")?;
-    let map = select_text(game, &map_name_selector);
+    let game_id = game
+        .value()
+        .attr("data-game-id")
+        .and_then(|id| id.parse().ok());
+
+    let map_name_selector = selector!("div.vm-stats-game-header div.map div:first-child span");
+    let map = select_text(game, map_name_selector);
 
-    let picked_by_selector = Selector::parse("div.vm-stats-game-header div.map span.picked")?;
-    let picked_by = game.select(&picked_by_selector).next().and_then(|e| {
+    let picked_by_selector = selector!("div.vm-stats-game-header div.map span.picked");
+    let picked_by = game.select(picked_by_selector).next().and_then(|e| {
         if e.value().has_class("mod-1", CaseSensitivity::CaseSensitive) {
             header.teams.first().map(|t| t.id)
         } else if e.value().has_class("mod-2", CaseSensitivity::CaseSensitive) {
@@ -724,9 +1050,9 @@ fn parse_game(header: &MatchHeader, game: &ElementRef) -> Result<MatchGame> {
         }
     });
 
-    let duration_selector = Selector::parse("div.vm-stats-game-header div.map-duration")?;
+    let duration_selector = selector!("div.vm-stats-game-header div.map-duration");
     let duration = {
-        let text = select_text(game, &duration_selector);
+        let text = select_text(game, duration_selector);
         if text.is_empty() {
             None
         } else {
@@ -734,57 +1060,313 @@ fn parse_game(header: &MatchHeader, game: &ElementRef) -> Result<MatchGame> {
         }
     };
 
+    let duration_secs = duration.as_deref().and_then(parse_duration_secs);
+
+    let note_selector = selector!("div.vm-stats-game-header div.map-note, div.vm-stats-game-note");
+    let note = game
+        .select(note_selector)
+        .next()
+        .map(|e| e.text().map(str::trim).filter(|t| !t.is_empty()).join(" "))
+        .filter(|t| !t.is_empty());
+
     let rounds_selector =
-        Selector::parse("div.vlr-rounds div.vlr-rounds-row-col:not(:first-child,.mod-spacing)")?;
-    let rounds = game.select(&rounds_selector).collect_vec();
+        selector!("div.vlr-rounds div.vlr-rounds-row-col:not(:first-child,.mod-spacing)");
+    let rounds = game.select(rounds_selector).collect_vec();
     let rounds = parse_rounds(header, rounds)?;
 
-    let overview_table_selector = Selector::parse("table.wf-table-inset.mod-overview")?;
-    let player_row_selector = Selector::parse("tbody tr:has(td.mod-player)")?;
-    let team_player_lists: Vec<Vec<MatchGamePlayer>> = game
-        .select(&overview_table_selector)
+    let overview_table_selector = selector!("table.wf-table-inset.mod-overview");
+    let player_row_selector = selector!("tbody tr:has(td.mod-player)");
+    let team_player_tables: Vec<Vec<(MatchGamePlayer, Option<String>)>> = game
+        .select(overview_table_selector)
         .map(|t| {
-            t.select(&player_row_selector)
-                .map(parse_player)
+            let columns = OverviewColumns::parse(&t);
+            t.select(player_row_selector)
+                .map(|row| {
+                    let player = parse_player(row, &columns)?;
+                    let tag = player.team_tag.clone();
+                    Ok((player, tag))
+                })
                 .collect::<Result<_>>()
         })
         .collect::<Result<_>>()?;
-
-    let team_name_selectors = Selector::parse("div.vm-stats-game-header div.team")?;
-    let teams: Vec<MatchGameTeam> = game
-        .select(&team_name_selectors)
+    let team_ids = header.teams.iter().map(|t| t.id).collect_vec();
+    let team_player_lists = assign_player_teams(&team_ids, team_player_tables);
+
+    let team_name_selectors = selector!("div.vm-stats-game-header div.team");
+    let team_headers = game.select(team_name_selectors).collect_vec();
+    if team_headers.len() != team_player_lists.len() {
+        warn!(
+            map = %map,
+            team_headers = team_headers.len(),
+            scoreboards = team_player_lists.len(),
+            "game team headers and scoreboard tables don't line up"
+        );
+    }
+    let mut teams: Vec<MatchGameTeam> = team_headers
+        .into_iter()
         .zip(team_player_lists)
         .map(|(t, p)| parse_game_team(t, p))
         .collect();
+
+    // Normalize totals per round, preferring the final map score over the
+    // round timeline since rounds without a recorded winner are skipped there
+    let round_count = teams
+        .iter()
+        .map(|t| t.score.map(u16::from))
+        .sum::<Option<u16>>()
+        .filter(|&n| n > 0)
+        .or_else(|| u16::try_from(rounds.len()).ok().filter(|&n| n > 0));
+    if let Some(round_count) = round_count {
+        let per_round = |total: Option<u16>| total.map(|v| f32::from(v) / f32::from(round_count));
+        for player in teams.iter_mut().flat_map(|t| t.players.iter_mut()) {
+            player.kpr = per_round(player.kills);
+            player.dpr = per_round(player.deaths);
+            player.apr = per_round(player.assists);
+        }
+    }
     Ok(MatchGame {
+        game_id,
+        map_kind: map.parse().ok(),
         map,
         picked_by,
         duration,
+        duration_secs,
+        note,
         teams,
         rounds,
     })
 }
 
-fn parse_player(player: ElementRef) -> Result<MatchGamePlayer> {
-    let name_column_selector = Selector::parse("td.mod-player")?;
+/// Detect a grand final where one team started with a map advantage.
+///
+/// Only applies when the series or stage is a grand final, and only when the
+/// veto note credits a team with the advantage by name. The series score is
+/// not compared to the maps won, since maps that failed to parse would look
+/// like an advantage.
+fn parse_map_advantage(header: &MatchHeader, veto_note: &str) -> Option<u32> {
+    let context = format!(
+        "{} {} {}",
+        header.event_series_name,
+        header.stage_slug.as_deref().unwrap_or_default(),
+        header.note
+    )
+    .to_lowercase()
+    .replace('-', " ");
+    if !context.contains("grand final") {
+        return None;
+    }
+
+    let note = veto_note.to_lowercase();
+    if !note.contains("advantage") {
+        return None;
+    }
+    header
+        .teams
+        .iter()
+        .find(|t| !t.name.is_empty() && note.contains(&t.name.to_lowercase()))
+        .map(|t| t.id)
+}
+
+/// Parse the veto sequence from the header note, e.g.
+/// `"SEN ban Bind; FNC ban Split; SEN pick Haven; ...; Ascent remains"`.
+///
+/// Teams are named by tag, so they're resolved through the header's team
+/// names and slugs and the scoreboard's team tags. Notes without veto steps
+/// (older matches, map advantage notes) yield an empty list.
+fn parse_veto(header: &MatchHeader, games: &[MatchGame], note: &str) -> Vec<VetoAction> {
+    let mut aliases = header
+        .teams
+        .iter()
+        .flat_map(|t| [(t.name.to_lowercase(), t.id), (t.slug.to_lowercase(), t.id)])
+        .collect_vec();
+    aliases.extend(
+        games
+            .iter()
+            .flat_map(|g| &g.teams)
+            .flat_map(|t| &t.players)
+            .filter_map(|p| Some((p.team_tag.as_ref()?.to_lowercase(), p.team_id?))),
+    );
+    let team_id = |who: &str| {
+        let who = who.trim().to_lowercase();
+        aliases
+            .iter()
+            .find(|(alias, _)| !alias.is_empty() && *alias == who)
+            .map(|&(_, id)| id)
+    };
+
+    note.split(';')
+        .filter_map(|step| {
+            let words = step.split_whitespace().collect_vec();
+            match words[..] {
+                [ref map @ .., last] if last.eq_ignore_ascii_case("remains") && !map.is_empty() => {
+                    Some(VetoAction {
+                        team_id: None,
+                        action: VetoActionKind::Remains,
+                        map: map.join(" "),
+                    })
+                }
+                _ => {
+                    let (pos, action) = words.iter().enumerate().find_map(|(i, w)| {
+                        let action = match w.to_lowercase().as_str() {
+                            "ban" | "bans" => VetoActionKind::Ban,
+                            "pick" | "picks" => VetoActionKind::Pick,
+                            _ => return None,
+                        };
+                        Some((i, action))
+                    })?;
+                    let (who, map) = (&words[..pos], &words[pos + 1..]);
+                    if who.is_empty() || map.is_empty() {
+                        return None;
+                    }
+                    Some(VetoAction {
+                        team_id: team_id(&who.join(" ")),
+                        action,
+                        map: map.join(" "),
+                    })
+                }
+            }
+        })
+        .collect()
+}
+
+/// Parse a map duration like `"45:12"` or `"1:02:33"` into seconds.
+fn parse_duration_secs(text: &str) -> Option<u32> {
+    let parts: Vec<u32> = normalize_number(text)
+        .split(':')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    match parts[..] {
+        [m, s] if s < 60 => Some(m * 60 + s),
+        [h, m, s] if m < 60 && s < 60 => Some(h * 3600 + m * 60 + s),
+        _ => None,
+    }
+}
+
+/// Drop a leading team tag from a scoreboard name, so `"SEN TenZ"` with tag
+/// `SEN` becomes `"TenZ"`.
+fn strip_team_tag(name: &str, tag: Option<&str>) -> String {
+    tag.and_then(|tag| {
+        let (prefix, rest) = name.split_once(' ')?;
+        prefix.eq_ignore_ascii_case(tag).then(|| rest.trim())
+    })
+    .filter(|rest| !rest.is_empty())
+    .unwrap_or(name)
+    .to_string()
+}
+
+/// Parse a rate stat into a fraction (0.73 = 73%). Cells usually show a
+/// percentage (`"73%"`), but some rows omit the `%` or already hold a
+/// fraction, so values without `%` are only scaled down when above 1.
+fn parse_rate(text: &str) -> Option<f32> {
+    match text.strip_suffix('%') {
+        Some(pct) => pct.parse::<f32>().ok().map(|v| v / 100.0),
+        None => text
+            .parse::<f32>()
+            .ok()
+            .map(|v| if v > 1.0 { v / 100.0 } else { v }),
+    }
+}
+
+/// Attach a team id to each scoreboard row and group the rows by team.
+///
+/// Each overview table is assumed to belong to the header team at the same
+/// position. That assumption is checked against the team tag shown under the
+/// player's name: a row whose tag is the dominant tag of another table is
+/// moved to that table's team instead.
+fn assign_player_teams(
+    team_ids: &[u32],
+    tables: Vec<Vec<(MatchGamePlayer, Option<String>)>>,
+) -> Vec<Vec<MatchGamePlayer>> {
+    let table_tags: Vec<Option<String>> = tables
+        .iter()
+        .map(|rows| {
+            rows.iter()
+                .filter_map(|(_, tag)| tag.as_deref())
+                .counts()
+                .into_iter()
+                .max_by_key(|&(tag, count)| (count, std::cmp::Reverse(tag)))
+                .map(|(tag, _)| tag.to_string())
+        })
+        .collect();
+
+    let mut teams: Vec<Vec<MatchGamePlayer>> = vec![Vec::new(); tables.len()];
+    for (i, rows) in tables.into_iter().enumerate() {
+        for (mut player, tag) in rows {
+            let index = match tag {
+                Some(tag) if table_tags[i].as_ref() != Some(&tag) => table_tags
+                    .iter()
+                    .position(|t| t.as_ref() == Some(&tag))
+                    .unwrap_or(i),
+                _ => i,
+            };
+            if index != i {
+                warn!(
+                    player = %player.name,
+                    "scoreboard row is in the other team's table; reassigning"
+                );
+            }
+            player.team_id = team_ids.get(index).copied();
+            teams[index].push(player);
+        }
+    }
+    teams
+}
+
+/// Header labels of an overview table, indexed like the `td` cells of its
+/// player rows. Used for columns that only some layouts include.
+struct OverviewColumns(Vec<String>);
+
+impl OverviewColumns {
+    fn parse(table: &ElementRef) -> Self {
+        let header_selector = selector!("thead th");
+        let labels = table
+            .select(header_selector)
+            .map(|th| {
+                th.value()
+                    .attr("title")
+                    .map(str::to_string)
+                    .unwrap_or_else(|| th.text().collect())
+                    .trim()
+                    .to_lowercase()
+            })
+            .collect();
+        Self(labels)
+    }
+
+    fn total_damage(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|l| l == "dmg" || l == "damage" || l.contains("total damage"))
+    }
+
+    /// Columns of the `2K` to `5K` multikill counts, in that order.
+    fn multikills(&self) -> [Option<usize>; 4] {
+        ["2k", "3k", "4k", "5k"].map(|label| self.0.iter().position(|l| l == label))
+    }
+}
+
+fn parse_player(player: ElementRef, columns: &OverviewColumns) -> Result<MatchGamePlayer> {
+    let name_column_selector = selector!("td.mod-player");
     let name_column =
         player
-            .select(&name_column_selector)
+            .select(name_column_selector)
             .next()
             .ok_or(VlrError::ElementNotFound {
                 context: "player name column (td.mod-player)",
             })?;
-    let nation_selector = Selector::parse("i.flag")?;
+    let is_mvp = is_mvp_row(&player);
+
+    let nation_selector = selector!("i.flag");
     let nation = name_column
-        .select(&nation_selector)
+        .select(nation_selector)
         .next()
         .and_then(|e| e.value().attr("title"))
         .unwrap_or_default()
         .trim()
         .to_string();
 
-    let a_tag_selector = Selector::parse("a")?;
-    let a_tag = name_column.select(&a_tag_selector).next();
+    let a_tag_selector = selector!("a");
+    let a_tag = name_column.select(a_tag_selector).next();
     let href = a_tag
         .and_then(|e| e.value().attr("href"))
         .unwrap_or_default()
@@ -796,26 +1378,31 @@ fn parse_player(player: ElementRef) -> Result<MatchGamePlayer> {
         .map(|s| s.to_string())
         .collect_tuple()
         .unwrap_or_default();
-    let name_selector = Selector::parse("a div:first-child")?;
-    let name = select_text(&name_column, &name_selector);
+    let name_selector = selector!("a div:first-child");
+    let team_tag_selector = selector!("div.ge-text-light");
+    let team_tag = Some(select_text(&name_column, team_tag_selector)).filter(|t| !t.is_empty());
+    let name = strip_team_tag(
+        &select_text(&name_column, name_selector),
+        team_tag.as_deref(),
+    );
 
-    let agent_selector = Selector::parse("td.mod-agents div span img")?;
-    let agent = player
-        .select(&agent_selector)
+    let agent_selector = selector!("td.mod-agents div span img");
+    let agents = player
+        .select(agent_selector)
         .filter_map(|e| e.value().attr("title"))
-        .map(|s| s.to_string())
-        .next()
-        .unwrap_or_default();
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect_vec();
+    let agent = agents.first().cloned().unwrap_or_default();
 
-    let stat_cells: Vec<ElementRef> = player.select(&Selector::parse("td.mod-stat")?).collect();
+    let stat_cells: Vec<ElementRef> = player.select(selector!("td.mod-stat")).collect();
 
     let stat_both = |cell: Option<&ElementRef>| -> Option<String> {
         cell.and_then(|e| {
-            let sel = Selector::parse("span.side.mod-both").unwrap();
-            e.select(&sel)
+            e.select(selector!("span.side.mod-both"))
                 .next()
                 .and_then(|s| s.text().next())
-                .map(|t| t.trim().to_string())
+                .map(normalize_number)
         })
     };
 
@@ -825,24 +1412,49 @@ fn parse_player(player: ElementRef) -> Result<MatchGamePlayer> {
     let deaths = stat_both(stat_cells.get(3)).and_then(|s| s.parse::<u16>().ok());
     let assists = stat_both(stat_cells.get(4)).and_then(|s| s.parse::<u16>().ok());
     let kd_diff = stat_both(stat_cells.get(5)).and_then(|s| s.replace('+', "").parse::<i16>().ok());
-    let kast = stat_both(stat_cells.get(6))
-        .and_then(|s| s.strip_suffix('%').unwrap_or(&s).parse::<f32>().ok())
-        .map(|v| v / 100.0);
+    let kast = stat_both(stat_cells.get(6)).and_then(|s| parse_rate(&s));
     let adr = stat_both(stat_cells.get(7)).and_then(|s| s.parse::<f32>().ok());
-    let hs_pct = stat_both(stat_cells.get(8))
-        .and_then(|s| s.strip_suffix('%').unwrap_or(&s).parse::<f32>().ok())
-        .map(|v| v / 100.0);
+    let hs_pct = stat_both(stat_cells.get(8)).and_then(|s| parse_rate(&s));
     let first_kills = stat_both(stat_cells.get(9)).and_then(|s| s.parse::<u16>().ok());
     let first_deaths = stat_both(stat_cells.get(10)).and_then(|s| s.parse::<u16>().ok());
     let fk_diff =
         stat_both(stat_cells.get(11)).and_then(|s| s.replace('+', "").parse::<i16>().ok());
+    let t_side = parse_side_stats(&stat_cells, selector!("span.side.mod-t"));
+    let ct_side = parse_side_stats(&stat_cells, selector!("span.side.mod-ct"));
+
+    let cells: Vec<ElementRef> = player.select(selector!("td")).collect();
+    let total_damage = columns
+        .total_damage()
+        .and_then(|i| stat_both(cells.get(i)))
+        .and_then(|s| s.parse::<u32>().ok());
+
+    let multikill_columns = columns.multikills();
+    let multikills = multikill_columns.iter().any(Option::is_some).then(|| {
+        let count = |column: Option<usize>| {
+            column
+                .and_then(|i| stat_both(cells.get(i)))
+                .and_then(|s| s.parse::<u8>().ok())
+                .unwrap_or_default()
+        };
+        let [two_k, three_k, four_k, five_k] = multikill_columns.map(count);
+        MultiKills {
+            two_k,
+            three_k,
+            four_k,
+            five_k,
+        }
+    });
 
     Ok(MatchGamePlayer {
         nation,
         id: id.parse().unwrap_or_default(),
+        team_id: None,
         slug,
         name,
+        team_tag,
+        agent_kind: agent.parse().ok(),
         agent,
+        agents,
         rating,
         acs,
         kills,
@@ -851,32 +1463,92 @@ fn parse_player(player: ElementRef) -> Result<MatchGamePlayer> {
         kd_diff,
         kast,
         adr,
+        total_damage,
         hs_pct,
         first_kills,
         first_deaths,
         fk_diff,
+        kpr: None,
+        dpr: None,
+        apr: None,
+        is_mvp,
+        multikills,
+        t_side,
+        ct_side,
     })
 }
 
+/// Read one side's values, selected from each stat cell by `side_selector`
+/// (`span.side.mod-t` or `span.side.mod-ct`). `None` when no cell has a
+/// value for that side.
+fn parse_side_stats(
+    stat_cells: &[ElementRef],
+    side_selector: &Selector,
+) -> Option<PlayerSideStats> {
+    let stat = |i: usize| -> Option<String> {
+        stat_cells
+            .get(i)?
+            .select(side_selector)
+            .next()?
+            .text()
+            .next()
+            .map(normalize_number)
+            .filter(|s| !s.is_empty())
+    };
+    let stats = PlayerSideStats {
+        rating: stat(0).and_then(|s| s.parse().ok()),
+        acs: stat(1).and_then(|s| s.parse().ok()),
+        kills: stat(2).and_then(|s| s.parse().ok()),
+        deaths: stat(3).and_then(|s| s.parse().ok()),
+        assists: stat(4).and_then(|s| s.parse().ok()),
+        kd_diff: stat(5).and_then(|s| s.replace('+', "").parse().ok()),
+        kast: stat(6).and_then(|s| parse_rate(&s)),
+        adr: stat(7).and_then(|s| s.parse().ok()),
+        hs_pct: stat(8).and_then(|s| parse_rate(&s)),
+        first_kills: stat(9).and_then(|s| s.parse().ok()),
+        first_deaths: stat(10).and_then(|s| s.parse().ok()),
+        fk_diff: stat(11).and_then(|s| s.replace('+', "").parse().ok()),
+    };
+    (stats != PlayerSideStats::default()).then_some(stats)
+}
+
+/// Whether a scoreboard row carries vlr.gg's MVP highlight, either as a
+/// `mod-mvp` class on the row or as an MVP marker inside it.
+fn is_mvp_row(row: &ElementRef) -> bool {
+    let marker_selector = selector!(".mod-mvp, .mvp");
+    row.value()
+        .has_class("mod-mvp", CaseSensitivity::AsciiCaseInsensitive)
+        || row.select(marker_selector).next().is_some()
+}
+
 fn parse_rounds(header: &MatchHeader, rounds: Vec<ElementRef>) -> Result<Vec<MatchGameRound>> {
-    let round_number_selector = Selector::parse("div.rnd-num")?;
-    let round_result_selector = Selector::parse("div.rnd-sq")?;
+    let round_number_selector = selector!("div.rnd-num");
+    let round_result_selector = selector!("div.rnd-sq");
     let rounds: Vec<MatchGameRound> = rounds
         .iter()
         .filter_map(|r| {
-            let round = select_text(r, &round_number_selector)
+            let round = select_text(r, round_number_selector)
                 .parse()
                 .unwrap_or_default();
-            let winning_team = r
-                .select(&round_result_selector)
-                .map(|e| {
-                    e.value()
-                        .classes()
-                        .map(|c| c.trim().to_string())
-                        .collect_vec()
-                })
-                .find_position(|c| c.contains(&"mod-win".to_string()));
-            if let Some((winning_team_index, winning_team)) = winning_team {
+            let winning_team = r.select(round_result_selector).find_position(|e| {
+                e.value()
+                    .has_class("mod-win", CaseSensitivity::CaseSensitive)
+            });
+            if let Some((winning_team_index, winning_square)) = winning_team {
+                let winning_site = if winning_square
+                    .value()
+                    .has_class("mod-t", CaseSensitivity::CaseSensitive)
+                {
+                    Side::Attack
+                } else {
+                    Side::Defense
+                };
+                let win_type = parse_round_win_type(&winning_square);
+                let (team1_side, team2_side) = if winning_team_index == 0 {
+                    (winning_site, winning_site.opposite())
+                } else {
+                    (winning_site.opposite(), winning_site)
+                };
                 header
                     .teams
                     .get(winning_team_index)
@@ -884,11 +1556,10 @@ fn parse_rounds(header: &MatchHeader, rounds: Vec<ElementRef>) -> Result<Vec<Mat
                     .map(|team_id| MatchGameRound {
                         round,
                         winning_team: team_id,
-                        winning_site: if winning_team.contains(&"mod-t".to_string()) {
-                            "t".to_string()
-                        } else {
-                            "ct".to_string()
-                        },
+                        winning_site,
+                        team1_side,
+                        team2_side,
+                        win_type,
                     })
             } else {
                 None
@@ -898,18 +1569,52 @@ fn parse_rounds(header: &MatchHeader, rounds: Vec<ElementRef>) -> Result<Vec<Mat
     Ok(rounds)
 }
 
+/// Read how a round was won from the icon in its winning square: the file
+/// name of the `<img>` (`elim.webp`, `defuse.webp`, `boom.webp`,
+/// `time.webp`), or a `mod-*` class naming the same condition.
+fn parse_round_win_type(square: &ElementRef) -> RoundWinType {
+    let icon = square
+        .select(selector!("img"))
+        .next()
+        .and_then(|img| img.value().attr("src"))
+        .and_then(|src| src.rsplit('/').next())
+        .and_then(|file| file.split('.').next())
+        .map(str::to_lowercase);
+    let classes = square
+        .descendent_elements()
+        .flat_map(|e| e.value().classes())
+        .filter_map(|c| c.strip_prefix("mod-"))
+        .map(str::to_lowercase);
+    icon.into_iter()
+        .chain(classes)
+        .find_map(|name| match name.as_str() {
+            "elim" | "elimination" => Some(RoundWinType::Elimination),
+            "defuse" => Some(RoundWinType::Defuse),
+            "boom" | "detonate" | "detonation" => Some(RoundWinType::Detonate),
+            "time" => Some(RoundWinType::Time),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
 fn parse_game_team(team: ElementRef, players: Vec<MatchGamePlayer>) -> MatchGameTeam {
     let name_selector = Selector::parse("div.team-name").unwrap();
     let name = select_text(&team, &name_selector);
 
     let score_selector = Selector::parse("div.score").unwrap();
-    let score = select_text(&team, &score_selector).parse().ok();
+    let score = normalize_number(&select_text(&team, &score_selector))
+        .parse()
+        .ok();
 
     let score_t_selector = Selector::parse("span.mod-t").unwrap();
-    let score_t = select_text(&team, &score_t_selector).parse().ok();
+    let score_t = normalize_number(&select_text(&team, &score_t_selector))
+        .parse()
+        .ok();
 
     let score_ct_selector = Selector::parse("span.mod-ct").unwrap();
-    let score_ct = select_text(&team, &score_ct_selector).parse().ok();
+    let score_ct = normalize_number(&select_text(&team, &score_ct_selector))
+        .parse()
+        .ok();
 
     let is_winner = team
         .select(&score_selector)
@@ -920,6 +1625,13 @@ fn parse_game_team(team: ElementRef, players: Vec<MatchGamePlayer>) -> MatchGame
         })
         .unwrap_or_default();
 
+    let composition = players
+        .iter()
+        .map(|p| p.agent.clone())
+        .filter(|a| !a.is_empty())
+        .sorted()
+        .collect();
+
     MatchGameTeam {
         name,
         score,
@@ -927,17 +1639,20 @@ fn parse_game_team(team: ElementRef, players: Vec<MatchGamePlayer>) -> MatchGame
         score_ct,
         is_winner,
         players,
+        composition,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "events")]
     use crate::model::{EventType, Region};
 
+    #[cfg(feature = "events")]
     #[tokio::test]
     async fn test_get_match() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
 
         let events = crate::vlr_scraper::events::list::get_events(
             &client,
@@ -949,19 +1664,22 @@ mod tests {
         .unwrap();
         let event_id = events.events[0].id;
 
-        let matches = crate::vlr_scraper::events::matchlist::get_event_matchlist(&client, event_id)
-            .await
-            .unwrap();
+        let matches =
+            crate::vlr_scraper::events::matchlist::get_event_matchlist(&client, event_id, None)
+                .await
+                .unwrap();
         let match_id = matches[0].id;
 
-        let vlr_match = get_match(&client, match_id).await;
+        let vlr_match = get_match(&client, match_id, &MatchFetchOptions::default()).await;
         assert!(vlr_match.is_ok());
     }
 
     #[tokio::test]
     async fn test_get_match_enhanced_fields() {
-        let client = reqwest::Client::new();
-        let vlr_match = get_match(&client, 595657).await.unwrap();
+        let client = HttpClient::default();
+        let vlr_match = get_match(&client, 595657, &MatchFetchOptions::default())
+            .await
+            .unwrap();
 
         // Header metadata assertions
         assert!(
@@ -973,6 +1691,15 @@ mod tests {
             "format should be non-empty"
         );
         assert_eq!(vlr_match.header.status, "final");
+        assert_eq!(vlr_match.header.status_kind, MatchStatus::Final);
+        assert!(
+            vlr_match.header.best_of().is_some(),
+            "best_of should be parsed from the format"
+        );
+        assert!(
+            vlr_match.is_series_complete(),
+            "a final match should have a complete series"
+        );
 
         // Player stats: at least one player should have kills populated
         let has_player_stats = vlr_match.games.iter().any(|game| {
@@ -984,6 +1711,24 @@ mod tests {
             has_player_stats,
             "at least one player should have kills populated"
         );
+        let has_per_round_stats = vlr_match.games.iter().any(|game| {
+            game.teams
+                .iter()
+                .any(|team| team.players.iter().any(|p| p.kpr.is_some()))
+        });
+        assert!(
+            has_per_round_stats,
+            "at least one player should have kpr populated"
+        );
+
+        assert!(
+            vlr_match
+                .games
+                .iter()
+                .flat_map(|g| g.teams.iter().flat_map(|t| t.players.iter()))
+                .all(|p| p.team_id.is_some()),
+            "every scoreboard player should have a team_id"
+        );
 
         // Head-to-head entries
         assert!(
@@ -1003,18 +1748,981 @@ mod tests {
             "economy data should be present"
         );
 
+        // Series duration sums the per-map durations
+        let map_secs: u64 = vlr_match
+            .games
+            .iter()
+            .filter_map(|g| g.duration_secs)
+            .map(u64::from)
+            .sum();
+        assert!(map_secs > 0, "at least one map should have a duration");
+        assert_eq!(
+            vlr_match.total_duration().map(|d| d.as_secs()),
+            Some(map_secs)
+        );
+
         // Map picks: at least one game should have a pick
         let has_map_pick = vlr_match.games.iter().any(|g| g.picked_by.is_some());
         assert!(has_map_pick, "at least one game should have picked_by set");
     }
 
+    fn fixture_header() -> MatchHeader {
+        let team = |id: u32, name: &str| MatchHeaderTeam {
+            id,
+            slug: name.to_lowercase(),
+            href: format!("/team/{id}/{}", name.to_lowercase()),
+            name: name.to_string(),
+            score: None,
+            icon: String::new(),
+        };
+        MatchHeader {
+            event_icon: String::new(),
+            event_title: String::new(),
+            event_series_name: String::new(),
+            event_id: 0,
+            event_slug: String::new(),
+            event_region: None,
+            stage_id: None,
+            stage_slug: None,
+            date: NaiveDateTime::default(),
+            patch: String::new(),
+            patch_version: None,
+            format: String::new(),
+            format_kind: MatchFormat::Unknown,
+            status: String::new(),
+            status_kind: MatchStatus::Unknown,
+            note: String::new(),
+            teams: vec![team(2, "Sentinels"), team(188, "Cloud9")],
+        }
+    }
+
+    fn scoreboard_row(id: u32, name: &str, tag: &str, agent: &str) -> String {
+        format!(
+            r#"<tr><td class="mod-player"><a href="/player/{id}/{slug}">
+                <div>{name}</div><div class="ge-text-light">{tag}</div>
+            </a></td><td class="mod-agents"><div><span><img title="{agent}"></span></div></td></tr>"#,
+            slug = name.to_lowercase()
+        )
+    }
+
+    #[test]
+    fn test_parse_game_assigns_player_teams() {
+        let table = |rows: &[String]| {
+            format!(
+                r#"<div><table class="wf-table-inset mod-overview">
+                    <thead><tr><th></th><th></th></tr></thead>
+                    <tbody>{}</tbody>
+                </table></div>"#,
+                rows.concat()
+            )
+        };
+        // The second Sentinels table row belongs to Cloud9 by its tag
+        let html = scraper::Html::parse_fragment(&format!(
+            r#"<div class="vm-stats-game">
+                <div class="vm-stats-game-header">
+                    <div class="team"><div class="team-name">Sentinels</div><div class="score mod-win">13</div></div>
+                    <div class="map"><div><span>Ascent</span></div></div>
+                    <div class="team mod-right"><div class="score">7</div><div class="team-name">Cloud9</div></div>
+                </div>
+                {}{}
+            </div>"#,
+            table(&[
+                scoreboard_row(9, "TenZ", "SEN", "Jett"),
+                scoreboard_row(729, "Zellsis", "SEN", "Breach"),
+                scoreboard_row(3520, "OXY", "C9", "Raze"),
+            ]),
+            table(&[
+                scoreboard_row(881, "Xeppaa", "C9", "Sova"),
+                scoreboard_row(4004, "vanity", "C9", "Omen"),
+            ]),
+        ));
+        let game = html
+            .select(&Selector::parse("div.vm-stats-game").unwrap())
+            .next()
+            .unwrap();
+
+        let game = parse_game(&fixture_header(), &game).unwrap();
+        assert_eq!(game.teams.len(), 2);
+        let names =
+            |team: &MatchGameTeam| team.players.iter().map(|p| p.name.clone()).collect_vec();
+        assert_eq!(names(&game.teams[0]), vec!["TenZ", "Zellsis"]);
+        assert_eq!(names(&game.teams[1]), vec!["OXY", "Xeppaa", "vanity"]);
+        assert!(game.teams[0].players.iter().all(|p| p.team_id == Some(2)));
+        assert!(game.teams[1].players.iter().all(|p| p.team_id == Some(188)));
+        assert_eq!(game.teams[0].composition, vec!["Breach", "Jett"]);
+        assert_eq!(game.teams[1].composition, vec!["Omen", "Raze", "Sova"]);
+    }
+
+    #[test]
+    fn test_parse_rounds_sides_switch_at_half() {
+        let round = |n: u8, winner: usize, side: &str| {
+            let sq = |i: usize| {
+                if i == winner {
+                    format!(r#"<div class="rnd-sq mod-win mod-{side}"></div>"#)
+                } else {
+                    r#"<div class="rnd-sq"></div>"#.to_string()
+                }
+            };
+            format!(
+                r#"<div class="vlr-rounds-row-col"><div class="rnd-num">{n}</div>{}{}</div>"#,
+                sq(0),
+                sq(1)
+            )
+        };
+        let html = scraper::Html::parse_fragment(&format!(
+            "<div>{}{}{}</div>",
+            round(12, 0, "t"),
+            round(13, 0, "ct"),
+            round(14, 1, "t"),
+        ));
+        let cols = html
+            .select(&Selector::parse("div.vlr-rounds-row-col").unwrap())
+            .collect_vec();
+
+        let rounds = parse_rounds(&fixture_header(), cols).unwrap();
+        let sides = rounds
+            .iter()
+            .map(|r| (r.round, r.winning_site, r.team1_side, r.team2_side))
+            .collect_vec();
+        assert_eq!(
+            sides,
+            vec![
+                (12, Side::Attack, Side::Attack, Side::Defense),
+                (13, Side::Defense, Side::Defense, Side::Attack),
+                (14, Side::Attack, Side::Defense, Side::Attack),
+            ]
+        );
+        assert_eq!(rounds[2].winning_team, 188);
+        assert_eq!(
+            serde_json::to_value(rounds[0].winning_site).unwrap(),
+            serde_json::json!("t")
+        );
+    }
+
+    #[test]
+    fn test_parse_round_win_type() {
+        let win_type = |square: &str| {
+            let html = scraper::Html::parse_fragment(square);
+            let square = html
+                .select(&Selector::parse("div.rnd-sq").unwrap())
+                .next()
+                .unwrap();
+            parse_round_win_type(&square)
+        };
+
+        assert_eq!(
+            win_type(
+                r#"<div class="rnd-sq mod-win mod-t"><img src="/img/vlr/game/round/elim.webp"></div>"#
+            ),
+            RoundWinType::Elimination
+        );
+        assert_eq!(
+            win_type(
+                r#"<div class="rnd-sq mod-win mod-ct"><img src="https://www.vlr.gg/img/vlr/game/round/defuse.webp"></div>"#
+            ),
+            RoundWinType::Defuse
+        );
+        assert_eq!(
+            win_type(
+                r#"<div class="rnd-sq mod-win mod-t"><img src="/img/vlr/game/round/boom.webp"></div>"#
+            ),
+            RoundWinType::Detonate
+        );
+        assert_eq!(
+            win_type(r#"<div class="rnd-sq mod-win mod-ct mod-time"></div>"#),
+            RoundWinType::Time
+        );
+        assert_eq!(
+            win_type(r#"<div class="rnd-sq mod-win mod-ct"></div>"#),
+            RoundWinType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_parse_game_note() {
+        let game = |extra: &str| {
+            let html = scraper::Html::parse_fragment(&format!(
+                r#"<div class="vm-stats-game">
+                    <div class="vm-stats-game-header">
+                        <div class="map"><div><span>Bind</span></div></div>
+                        {extra}
+                    </div>
+                </div>"#
+            ));
+            let game = html
+                .select(&Selector::parse("div.vm-stats-game").unwrap())
+                .next()
+                .unwrap();
+            parse_game(&fixture_header(), &game).unwrap()
+        };
+
+        let noted = game(
+            r#"<div class="map-note">Remade from round 7 <span>(technical issue)</span></div>"#,
+        );
+        assert_eq!(
+            noted.note.as_deref(),
+            Some("Remade from round 7 (technical issue)")
+        );
+        assert_eq!(game("").note, None);
+        assert_eq!(noted.map_kind, Some(crate::model::Map::Bind));
+    }
+
+    #[test]
+    fn test_parse_player_total_damage() {
+        let html = scraper::Html::parse_fragment(
+            r#"<table class="wf-table-inset mod-overview">
+                <thead><tr>
+                    <th></th><th></th><th title="Rating 2.0">R</th>
+                    <th title="Average Damage per Round">ADR</th>
+                    <th title="Total Damage">DMG</th>
+                </tr></thead>
+                <tbody><tr>
+                    <td class="mod-player"><a href="/player/9/tenz"><div>TenZ</div></a></td>
+                    <td class="mod-agents"></td>
+                    <td class="mod-stat"><span class="side mod-both">1.10</span></td>
+                    <td class="mod-stat"><span class="side mod-both">150</span></td>
+                    <td class="mod-stat"><span class="side mod-both">3,450</span></td>
+                </tr></tbody>
+            </table>"#,
+        );
+        let table = html
+            .select(&Selector::parse("table").unwrap())
+            .next()
+            .unwrap();
+        let row = table
+            .select(&Selector::parse("tbody tr").unwrap())
+            .next()
+            .unwrap();
+
+        let columns = OverviewColumns::parse(&table);
+        let player = parse_player(row, &columns).unwrap();
+        assert_eq!(player.total_damage, Some(3450));
+
+        let without_damage = OverviewColumns(vec![String::new(); 4]);
+        assert_eq!(
+            parse_player(row, &without_damage).unwrap().total_damage,
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_player_agents() {
+        let agents = |cell: &str| {
+            let html = scraper::Html::parse_fragment(&format!(
+                r#"<table><tbody><tr>
+                    <td class="mod-player"><a href="/player/9/tenz"><div>TenZ</div></a></td>
+                    <td class="mod-agents"><div>{cell}</div></td>
+                </tr></tbody></table>"#
+            ));
+            let row = html
+                .select(&Selector::parse("tbody tr").unwrap())
+                .next()
+                .unwrap();
+            let player = parse_player(row, &OverviewColumns(vec![])).unwrap();
+            assert_eq!(player.agent_kind, player.agent.parse().ok());
+            (player.agent, player.agents)
+        };
+
+        assert_eq!(
+            agents(r#"<span><img title="Jett"></span><span><img title="Raze"></span>"#),
+            (
+                "Jett".to_string(),
+                vec!["Jett".to_string(), "Raze".to_string()]
+            )
+        );
+        assert_eq!(agents(""), (String::new(), vec![]));
+    }
+
+    #[test]
+    fn test_parse_player_side_stats() {
+        let cell = |both: &str, t: &str, ct: &str| {
+            format!(
+                r#"<td class="mod-stat"><span class="side mod-both">{both}</span><span class="side mod-t">{t}</span><span class="side mod-ct">{ct}</span></td>"#
+            )
+        };
+        let html = scraper::Html::parse_fragment(&format!(
+            r#"<table><tbody><tr>
+                <td class="mod-player"><a href="/player/9/tenz"><div>TenZ</div></a></td>
+                <td class="mod-agents"></td>
+                {}{}{}{}
+            </tr></tbody></table>"#,
+            cell("1.10", "1.32", "0.90"),
+            cell("250", "281", "219"),
+            cell("20", "12", "8"),
+            cell("15", "6", "9"),
+        ));
+        let row = html
+            .select(&Selector::parse("tbody tr").unwrap())
+            .next()
+            .unwrap();
+
+        let player = parse_player(row, &OverviewColumns(vec![])).unwrap();
+        assert_eq!(player.kills, Some(20));
+        let t_side = player.t_side.unwrap();
+        assert_eq!(
+            (t_side.rating, t_side.acs, t_side.kills, t_side.deaths),
+            (Some(1.32), Some(281), Some(12), Some(6))
+        );
+        assert_eq!(t_side.kast, None);
+        assert_eq!(player.ct_side.unwrap().kills, Some(8));
+
+        let html = scraper::Html::parse_fragment(&format!(
+            "<table><tbody>{}</tbody></table>",
+            scoreboard_row(9, "TenZ", "SEN", "Jett")
+        ));
+        let row = html
+            .select(&Selector::parse("tbody tr").unwrap())
+            .next()
+            .unwrap();
+        let combined_only = parse_player(row, &OverviewColumns(vec![])).unwrap();
+        assert!(combined_only.t_side.is_none());
+        assert!(combined_only.ct_side.is_none());
+    }
+
+    #[test]
+    fn test_parse_player_multikills() {
+        let html = scraper::Html::parse_fragment(
+            r#"<table class="wf-table-inset mod-overview">
+                <thead><tr>
+                    <th></th><th></th><th title="Rating 2.0">R</th>
+                    <th>2K</th><th>3K</th><th>4K</th><th>5K</th>
+                </tr></thead>
+                <tbody><tr>
+                    <td class="mod-player"><a href="/player/9/tenz"><div>TenZ</div></a></td>
+                    <td class="mod-agents"></td>
+                    <td class="mod-stat"><span class="side mod-both">1.10</span></td>
+                    <td class="mod-stat"><span class="side mod-both">4</span></td>
+                    <td class="mod-stat"><span class="side mod-both">2</span></td>
+                    <td class="mod-stat"><span class="side mod-both">1</span></td>
+                    <td class="mod-stat"><span class="side mod-both">0</span></td>
+                </tr></tbody>
+            </table>"#,
+        );
+        let table = html
+            .select(&Selector::parse("table").unwrap())
+            .next()
+            .unwrap();
+        let row = table
+            .select(&Selector::parse("tbody tr").unwrap())
+            .next()
+            .unwrap();
+
+        let columns = OverviewColumns::parse(&table);
+        assert_eq!(
+            parse_player(row, &columns).unwrap().multikills,
+            Some(MultiKills {
+                two_k: 4,
+                three_k: 2,
+                four_k: 1,
+                five_k: 0,
+            })
+        );
+        assert_eq!(
+            parse_player(row, &OverviewColumns(vec![]))
+                .unwrap()
+                .multikills,
+            None
+        );
+    }
+
+    fn fixture_game(first_team_won: bool) -> MatchGame {
+        let team = |is_winner: bool| MatchGameTeam {
+            name: String::new(),
+            score: None,
+            score_t: None,
+            score_ct: None,
+            is_winner,
+            players: Vec::new(),
+            composition: Vec::new(),
+        };
+        MatchGame {
+            game_id: None,
+            map: String::new(),
+            map_kind: None,
+            picked_by: None,
+            duration: None,
+            duration_secs: None,
+            note: None,
+            teams: vec![team(first_team_won), team(!first_team_won)],
+            rounds: Vec::new(),
+        }
+    }
+
+    fn fixture_match(games: Vec<MatchGame>) -> Match {
+        Match {
+            schema_version: SCHEMA_VERSION,
+            id: 1,
+            header: fixture_header(),
+            streams: Vec::new(),
+            vods: Vec::new(),
+            games,
+            head_to_head: Vec::new(),
+            past_matches: Vec::new(),
+            performance: None,
+            economy: None,
+            map_advantage_team: None,
+            veto: Vec::new(),
+            is_placeholder: false,
+        }
+    }
+
+    #[test]
+    fn test_is_final_match() {
+        let page = |note: &str| {
+            scraper::Html::parse_document(&format!(
+                r#"<div class="match-header-vs-score"><div class="match-header-vs-note">{note}</div></div>"#
+            ))
+        };
+        assert!(is_final_match(&page("final")));
+        assert!(is_final_match(&page(" Completed ")));
+        assert!(!is_final_match(&page("live")));
+        assert!(!is_final_match(&page("upcoming")));
+        assert!(!is_final_match(&scraper::Html::parse_document(
+            "<div></div>"
+        )));
+    }
+
+    #[test]
+    fn test_match_json_round_trip() {
+        let m = fixture_match(vec![fixture_game(true)]);
+        let json = serde_json::to_string(&m).unwrap();
+        let parsed: Match = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+
+        // JSON written before the veto and placeholder fields existed
+        let mut value = serde_json::to_value(&m).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("veto");
+        object.remove("is_placeholder");
+        object.remove("schema_version");
+        object["header"]
+            .as_object_mut()
+            .unwrap()
+            .remove("format_kind");
+        let parsed: Match = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.schema_version, 0);
+        assert!(parsed.veto.is_empty());
+        assert!(!parsed.is_placeholder);
+        assert_eq!(parsed.header.format_kind, MatchFormat::Unknown);
+    }
+
+    #[test]
+    fn test_parse_match_placeholder() {
+        let html = scraper::Html::parse_fragment(
+            r#"<div class="col mod-3"><div class="match-header">
+                <div class="match-header-super">
+                    <a class="match-header-event" href="/event/2097/valorant-champions-2024">
+                        <img src="//owcdn.net/img/champions.png">
+                        <div><div>Valorant Champions 2024</div>
+                        <div class="match-header-event-series">Playoffs: Grand Final</div></div>
+                    </a>
+                    <div class="match-header-date">
+                        <div class="moment-tz-convert" data-utc-ts="2024-08-25 18:00:00"></div>
+                    </div>
+                </div>
+                <div class="match-header-vs">
+                    <div class="match-header-link"><div class="wf-title-med">TBD</div></div>
+                    <div class="match-header-vs-score">
+                        <div class="match-header-vs-note">upcoming</div>
+                    </div>
+                    <div class="match-header-link"><div class="wf-title-med">TBD</div></div>
+                </div>
+            </div></div>"#,
+        );
+        let col = html
+            .select(&Selector::parse("div.col").unwrap())
+            .next()
+            .unwrap();
+
+        let m = parse_match(
+            427000,
+            &col,
+            &MatchFetchOptions::default(),
+            ErrorPolicy::Strict,
+        )
+        .unwrap();
+        assert!(m.is_placeholder);
+        assert_eq!(
+            serde_json::to_value(&m).unwrap()["schema_version"],
+            SCHEMA_VERSION
+        );
+        assert!(m.header.teams.is_empty());
+        assert!(m.games.is_empty());
+        assert!(parse_scoreboard(&col, ErrorPolicy::Strict)
+            .unwrap()
+            .is_empty());
+        assert_eq!(m.header.status_kind, MatchStatus::Upcoming);
+        assert_eq!(m.header.date.to_string(), "2024-08-25 18:00:00");
+        assert_eq!(
+            m.header.date_utc().to_rfc3339(),
+            "2024-08-25T18:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_parse_match_unscheduled_placeholder() {
+        let page = |date: &str| {
+            scraper::Html::parse_fragment(&format!(
+                r#"<div class="col mod-3"><div class="match-header">
+                    <div class="match-header-super">
+                        <a class="match-header-event" href="/event/2097/valorant-champions-2024">
+                            <img src="//owcdn.net/img/champions.png">
+                            <div><div>Valorant Champions 2024</div></div>
+                        </a>
+                        <div class="match-header-date">{date}</div>
+                    </div>
+                    <div class="match-header-vs">
+                        <div class="match-header-link"><div class="wf-title-med">TBD</div></div>
+                        <div class="match-header-vs-score">
+                            <div class="match-header-vs-note">TBD</div>
+                        </div>
+                        <div class="match-header-link"><div class="wf-title-med">TBD</div></div>
+                    </div>
+                </div></div>"#
+            ))
+        };
+        for date in [
+            "",
+            r#"<div class="moment-tz-convert" data-utc-ts=""></div>"#,
+        ] {
+            let html = page(date);
+            let col = html.select(selector!("div.col")).next().unwrap();
+            let m = parse_match(
+                427001,
+                &col,
+                &MatchFetchOptions::default(),
+                ErrorPolicy::Strict,
+            )
+            .unwrap();
+            assert!(m.is_placeholder);
+            assert_eq!(m.header.date, NaiveDateTime::default());
+            assert_eq!(m.header.event_title, "Valorant Champions 2024");
+        }
+    }
+
+    #[test]
+    fn test_parse_performance_per_map() {
+        let section = |game_id: &str, kills: u16| {
+            format!(
+                r#"<div class="vm-stats-game" data-game-id="{game_id}">
+                    <table class="mod-normal"><tbody>
+                        <tr><td></td><td><div class="team"><div>TenZ</div></div></td></tr>
+                        <tr>
+                            <td><div class="team"><div>Boaster</div></div></td>
+                            <td><div class="stats-sq">{kills}</div><div class="stats-sq">3</div></td>
+                        </tr>
+                    </tbody></table>
+                    <table class="mod-adv-stats"><tbody></tbody></table>
+                </div>"#
+            )
+        };
+        let html = scraper::Html::parse_fragment(&format!(
+            r#"<div class="col mod-3"><div class="vm-stats">{}{}{}</div></div>"#,
+            section("all", 9),
+            section("164411", 5),
+            // A map section without tables is left out
+            r#"<div class="vm-stats-game" data-game-id="164412"></div>"#,
+        ));
+        let col = html
+            .select(&Selector::parse("div.col").unwrap())
+            .next()
+            .unwrap();
+
+        let mut ascent = fixture_game(true);
+        ascent.map = "Ascent".to_string();
+        ascent.game_id = Some(164411);
+        let mut bind = fixture_game(false);
+        bind.map = "Bind".to_string();
+        bind.game_id = Some(164412);
+        let mut m = fixture_match(vec![ascent, bind]);
+        m.performance = Some(parse_performance(&col, &m).unwrap());
+
+        let perf = m.performance.as_ref().unwrap();
+        assert_eq!(perf.kill_matrix[0].kills, 9);
+        assert_eq!(perf.per_game.len(), 1);
+        assert_eq!(perf.per_game[0].map, "Ascent");
+
+        assert_eq!(m.performance_for(164411).unwrap().kill_matrix[0].kills, 5);
+        assert_eq!(m.kill_matrix("ascent").map(|k| k[0].deaths), Some(3));
+        assert!(m.kill_matrix("Bind").is_none());
+        assert!(m.kill_matrix("Haven").is_none());
+    }
+
+    #[test]
+    fn test_match_unique_players() {
+        let html = scraper::Html::parse_fragment(&format!(
+            "<table><tbody>{}{}{}</tbody></table>",
+            scoreboard_row(9, "TenZ", "SEN", "Jett"),
+            scoreboard_row(729, "Zellsis", "SEN", "Breach"),
+            scoreboard_row(9, "TenZ", "SEN", "Raze"),
+        ));
+        let row_selector = Selector::parse("tr").unwrap();
+        let mut players = html
+            .select(&row_selector)
+            .map(|row| parse_player(row, &OverviewColumns(vec![])).unwrap());
+
+        let mut first = fixture_game(true);
+        first.teams[0].players = players.by_ref().take(2).collect();
+        let mut second = fixture_game(false);
+        second.teams[0].players = players.collect();
+        let m = fixture_match(vec![first, second]);
+
+        assert_eq!(m.players().count(), 3);
+        let unique = m.unique_players();
+        assert_eq!(
+            unique.iter().map(|p| p.name.as_str()).collect_vec(),
+            vec!["TenZ", "Zellsis"]
+        );
+        assert_eq!(unique[0].id, 9);
+    }
+
+    #[test]
+    fn test_patch_version() {
+        let v = |major, minor| Some(PatchVersion { major, minor });
+        assert_eq!(PatchVersion::parse("8.11"), v(8, 11));
+        assert_eq!(PatchVersion::parse("Patch 9.02"), v(9, 2));
+        assert_eq!(PatchVersion::parse("10"), v(10, 0));
+        assert_eq!(PatchVersion::parse(""), None);
+        assert_eq!(PatchVersion::parse("TBD"), None);
+        assert_eq!(PatchVersion::parse("8.x"), None);
+        assert!(PatchVersion::parse("8.2") < PatchVersion::parse("8.11"));
+        assert_eq!(v(8, 11).unwrap().to_string(), "8.11");
+    }
+
+    #[test]
+    fn test_match_format() {
+        assert_eq!(MatchFormat::from("Bo1"), MatchFormat::BestOf(1));
+        assert_eq!(MatchFormat::from("BO5"), MatchFormat::BestOf(5));
+        assert_eq!(MatchFormat::from(" Best of 3 "), MatchFormat::BestOf(3));
+        assert_eq!(MatchFormat::from("Showmatch"), MatchFormat::Showmatch);
+        assert_eq!(MatchFormat::from(""), MatchFormat::Unknown);
+        assert_eq!(MatchFormat::from("Bo0"), MatchFormat::Unknown);
+        assert_eq!(MatchFormat::BestOf(3).best_of(), Some(3));
+        assert_eq!(MatchFormat::Showmatch.best_of(), None);
+    }
+
+    #[test]
+    fn test_parse_economy_per_game() {
+        let section = |game_id: &str, table: bool| {
+            let table = if table {
+                r#"<table class="wf-table-inset mod-econ"><tbody>
+                    <tr><td>Sentinels</td><td><div class="stats-sq">1</div></td>
+                        <td><div class="stats-sq">3 (1)</div></td><td><div class="stats-sq">2 (0)</div></td>
+                        <td><div class="stats-sq">4 (2)</div></td><td><div class="stats-sq">12 (8)</div></td></tr>
+                </tbody></table>"#
+            } else {
+                ""
+            };
+            format!(r#"<div class="vm-stats-game" data-game-id="{game_id}">{table}</div>"#)
+        };
+        let html = scraper::Html::parse_fragment(&format!(
+            r#"<div class="col mod-3"><div class="vm-stats">{}{}{}</div></div>"#,
+            section("all", true),
+            section("164412", true),
+            section("164413", false),
+        ));
+        let col = html
+            .select(&Selector::parse("div.col.mod-3").unwrap())
+            .next()
+            .unwrap();
+        let mut game = fixture_game(true);
+        game.game_id = Some(164412);
+        game.map = "Bind".to_string();
+
+        let economy = parse_economy(&col, &fixture_match(vec![game])).unwrap();
+        assert_eq!(economy.teams.len(), 1);
+        assert_eq!(economy.per_game.len(), 1);
+        let bind = &economy.per_game[0];
+        assert_eq!((bind.game_id, bind.map.as_str()), (164412, "Bind"));
+        assert_eq!(bind.teams[0].team_name, "Sentinels");
+        assert_eq!(
+            (bind.teams[0].full_buy_rounds, bind.teams[0].full_buy_won),
+            (12, 8)
+        );
+    }
+
+    #[test]
+    fn test_match_label() {
+        let mut vlr_match = fixture_match(vec![]);
+        vlr_match.header.event_title = "Valorant Champions 2024".to_string();
+        vlr_match.header.event_series_name = "Playoffs: Grand Final".to_string();
+        vlr_match.header.format = "Bo5".to_string();
+        assert_eq!(vlr_match.label(), "Grand Final (Bo5)");
+
+        vlr_match.header.event_series_name = "Showmatch".to_string();
+        vlr_match.header.format = String::new();
+        assert_eq!(vlr_match.label(), "Showmatch");
+
+        vlr_match.header.event_series_name = String::new();
+        vlr_match.header.format = "Best of 3".to_string();
+        assert_eq!(vlr_match.label(), "Bo3");
+
+        vlr_match.header.format = String::new();
+        assert_eq!(vlr_match.label(), "Valorant Champions 2024");
+    }
+
+    #[test]
+    fn test_parse_player_mvp_highlight() {
+        let html = scraper::Html::parse_fragment(&format!(
+            "<table><tbody>{}{}{}</tbody></table>",
+            scoreboard_row(9, "TenZ", "SEN", "Jett").replace("<tr>", r#"<tr class="mod-mvp">"#),
+            scoreboard_row(729, "Zellsis", "SEN", "Breach"),
+            scoreboard_row(1265, "OXY", "C9", "Raze").replace(
+                r#"<td class="mod-player">"#,
+                r#"<td class="mod-player"><span class="mvp">MVP</span>"#,
+            ),
+        ));
+        let row_selector = Selector::parse("tr").unwrap();
+        let mvps = html
+            .select(&row_selector)
+            .map(|row| parse_player(row, &OverviewColumns(vec![])).unwrap().is_mvp)
+            .collect_vec();
+        assert_eq!(mvps, vec![true, false, true]);
+    }
+
+    #[test]
+    fn test_parse_veto() {
+        let header = fixture_header();
+        let html = scraper::Html::parse_fragment(&format!(
+            "<table><tbody>{}{}</tbody></table>",
+            scoreboard_row(9, "TenZ", "SEN", "Jett"),
+            scoreboard_row(1265, "OXY", "C9", "Raze"),
+        ));
+        let row_selector = Selector::parse("tr").unwrap();
+        let mut game = fixture_game(true);
+        for ((team, row), team_id) in game
+            .teams
+            .iter_mut()
+            .zip(html.select(&row_selector))
+            .zip([2, 188])
+        {
+            let mut player = parse_player(row, &OverviewColumns(vec![])).unwrap();
+            player.team_id = Some(team_id);
+            team.players.push(player);
+        }
+        let note = "SEN ban Bind; C9 ban Split; SEN pick Haven; C9 pick Lotus; \
+                    SEN ban Icebox; C9 ban Breeze; Ascent remains";
+
+        let veto = parse_veto(&header, &[game], note);
+        assert_eq!(veto.len(), 7);
+        assert_eq!(
+            veto[0],
+            VetoAction {
+                team_id: Some(2),
+                action: VetoActionKind::Ban,
+                map: "Bind".to_string(),
+            }
+        );
+        assert_eq!(veto[3].team_id, Some(188));
+        assert_eq!(veto[3].action, VetoActionKind::Pick);
+        assert_eq!(veto[3].map, "Lotus");
+        assert_eq!(
+            veto[6],
+            VetoAction {
+                team_id: None,
+                action: VetoActionKind::Remains,
+                map: "Ascent".to_string(),
+            }
+        );
+
+        // Full team names resolve without a scoreboard
+        let veto = parse_veto(&header, &[], "Cloud9 pick Bind");
+        assert_eq!(veto[0].team_id, Some(188));
+
+        assert!(parse_veto(&header, &[], "").is_empty());
+        assert!(parse_veto(&header, &[], "Sentinels start with a 1-0 map advantage").is_empty());
+    }
+
+    #[test]
+    fn test_parse_map_advantage() {
+        let mut header = fixture_header();
+        header.event_series_name = "Playoffs: Grand Final".to_string();
+        header.teams[0].score = Some(3);
+        header.teams[1].score = Some(1);
+        let note = "Cloud9 starts with a 1-0 map advantage";
+
+        assert_eq!(parse_map_advantage(&header, note), Some(188));
+        // Without an advantage note there is nothing to attribute
+        assert_eq!(parse_map_advantage(&header, ""), None);
+        assert_eq!(
+            parse_map_advantage(&header, "Sentinels ban Bind; Cloud9 ban Split"),
+            None
+        );
+
+        header.event_series_name = "Playoffs: Upper Final".to_string();
+        assert_eq!(parse_map_advantage(&header, note), None);
+    }
+
+    #[test]
+    fn test_parse_player_comma_decimal_rating() {
+        let html = scraper::Html::parse_fragment(
+            r#"<table><tbody><tr>
+                <td class="mod-player"><a href="/player/9/tenz"><div class="text-of">TenZ</div></a></td>
+                <td class="mod-stat"><span class="side mod-both">1,23</span></td>
+                <td class="mod-stat"><span class="side mod-both">245</span></td>
+            </tr></tbody></table>"#,
+        );
+        let row = html.select(&Selector::parse("tr").unwrap()).next().unwrap();
+        let player = parse_player(row, &OverviewColumns(vec![])).unwrap();
+        assert_eq!(player.rating, Some(1.23));
+        assert_eq!(player.acs, Some(245));
+    }
+
+    #[test]
+    fn test_parse_player_team_tag() {
+        let html = scraper::Html::parse_fragment(
+            r#"<table><tbody><tr><td class="mod-player"><a href="/player/9/tenz">
+                <div class="text-of">SEN TenZ</div><div class="ge-text-light">SEN</div>
+            </a></td></tr></tbody></table>"#,
+        );
+        let row = html.select(&Selector::parse("tr").unwrap()).next().unwrap();
+        let player = parse_player(row, &OverviewColumns(vec![])).unwrap();
+        assert_eq!(player.name, "TenZ");
+        assert_eq!(player.team_tag.as_deref(), Some("SEN"));
+
+        assert_eq!(strip_team_tag("TenZ", Some("SEN")), "TenZ");
+        assert_eq!(strip_team_tag("SEN", Some("SEN")), "SEN");
+        assert_eq!(strip_team_tag("Boaster Jr", Some("FNC")), "Boaster Jr");
+        assert_eq!(strip_team_tag("SEN TenZ", None), "SEN TenZ");
+    }
+
+    #[test]
+    fn test_parse_rate() {
+        assert_eq!(parse_rate("73%"), Some(0.73));
+        assert_eq!(parse_rate("73"), Some(0.73));
+        assert_eq!(parse_rate("0.73"), Some(0.73));
+        assert_eq!(parse_rate("100%"), Some(1.0));
+        assert_eq!(parse_rate("1"), Some(1.0));
+        assert_eq!(parse_rate("0%"), Some(0.0));
+        assert_eq!(parse_rate(""), None);
+    }
+
+    #[test]
+    fn test_parse_duration_secs() {
+        assert_eq!(parse_duration_secs("45:12"), Some(2712));
+        assert_eq!(parse_duration_secs("1:02:33"), Some(3753));
+        assert_eq!(parse_duration_secs(" 38:05 "), Some(2285));
+        assert_eq!(parse_duration_secs("45:75"), None);
+        assert_eq!(parse_duration_secs("-"), None);
+        assert_eq!(parse_duration_secs(""), None);
+    }
+
+    #[test]
+    fn test_parse_event_region() {
+        assert_eq!(
+            parse_event_region("Champions Tour 2024: EMEA Stage 1 Playoffs: Upper Final"),
+            Some("EMEA".to_string())
+        );
+        assert_eq!(
+            parse_event_region("Challengers 2024: North America ACE Stage 2"),
+            Some("North America".to_string())
+        );
+        assert_eq!(
+            parse_event_region("VCT 2024: Pacific League"),
+            Some("Pacific".to_string())
+        );
+        assert_eq!(
+            parse_event_region("Valorant Champions 2024 Playoffs: Grand Final"),
+            None
+        );
+        assert_eq!(parse_event_region("Menace Cup"), None);
+    }
+
+    #[test]
+    fn test_parse_utc_ts() {
+        let expected = chrono::NaiveDate::from_ymd_opt(2024, 8, 25)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap();
+        assert_eq!(parse_utc_ts("2024-08-25 14:30:00").unwrap(), expected);
+        assert_eq!(parse_utc_ts("2024-08-25 14:30").unwrap(), expected);
+        assert_eq!(parse_utc_ts(" 2024-08-25T14:30 ").unwrap(), expected);
+        assert!(matches!(parse_utc_ts(""), Err(VlrError::DateParse(_))));
+        assert!(matches!(parse_utc_ts("TBD"), Err(VlrError::DateParse(_))));
+    }
+
+    #[test]
+    fn test_parse_event_stage() {
+        assert_eq!(
+            parse_event_stage("/event/2097/valorant-champions-2024/playoffs?series_id=4030"),
+            (Some(4030), Some("playoffs".to_string()))
+        );
+        assert_eq!(
+            parse_event_stage("/event/2097/valorant-champions-2024/?series_id=4030"),
+            (Some(4030), None)
+        );
+        assert_eq!(
+            parse_event_stage("/event/2097/valorant-champions-2024"),
+            (None, None)
+        );
+        assert_eq!(parse_event_stage(""), (None, None));
+    }
+
+    #[tokio::test]
+    async fn test_get_match_scoreboard() {
+        let client = HttpClient::default();
+        let games = get_match_scoreboard(&client, 595657).await.unwrap();
+        assert!(!games.is_empty());
+        assert!(games.iter().all(|g| g.teams.len() == 2));
+    }
+
+    #[tokio::test]
+    async fn test_get_match_only_games() {
+        let client = HttpClient::default();
+        let all = get_match(&client, 595657, &MatchFetchOptions::default())
+            .await
+            .unwrap();
+        let options = MatchFetchOptions {
+            only_games: Some(vec![1]),
+        };
+        let second = get_match(&client, 595657, &options).await.unwrap();
+        assert_eq!(second.games.len(), 1);
+        assert_eq!(second.games[0].map, all.games[1].map);
+    }
+
+    #[test]
+    fn test_parse_match_status() {
+        assert_eq!(
+            parse_match_status("final", &[Some(2), Some(1)]),
+            MatchStatus::Final
+        );
+        assert_eq!(
+            parse_match_status("Final (OT)", &[Some(2), Some(1)]),
+            MatchStatus::Final
+        );
+        assert_eq!(
+            parse_match_status("Completed", &[Some(2), Some(0)]),
+            MatchStatus::Final
+        );
+        assert_eq!(
+            parse_match_status("LIVE", &[Some(1), Some(0)]),
+            MatchStatus::Live
+        );
+        assert_eq!(
+            parse_match_status(" Live ", &[Some(1), Some(0)]),
+            MatchStatus::Live
+        );
+        assert_eq!(
+            parse_match_status("upcoming", &[None, None]),
+            MatchStatus::Upcoming
+        );
+        assert_eq!(
+            parse_match_status("2h 15m", &[None, None]),
+            MatchStatus::Upcoming
+        );
+        assert_eq!(
+            parse_match_status("Playoffs", &[Some(0), Some(0)]),
+            MatchStatus::Unknown
+        );
+    }
+
     // Compile-time assertion that get_match future is Send
     // This ensures the function can be used in axum handlers
     #[allow(dead_code)]
     fn assert_get_match_is_send() {
-        fn check_get_match_send(client: &reqwest::Client, id: u32) {
+        fn check_get_match_send(client: &HttpClient, id: u32, options: &MatchFetchOptions) {
             fn is_send<T: Send>(_: T) {}
-            is_send(get_match(client, id));
+            is_send(get_match(client, id, options));
         }
     }
 }
@@ -0,0 +1,282 @@
+use chrono::{NaiveDate, NaiveTime};
+use itertools::{izip, Itertools};
+use scraper::{CaseSensitivity, ElementRef, Selector};
+
+use crate::error::{ErrorPolicy, Result, VlrError};
+use crate::model::{MatchItem, MatchItemTeam, MatchResult};
+use crate::vlr_scraper::{normalize_img_url, normalize_number, select_text};
+
+pub(crate) const MATCH_DATE_FORMAT: &str = "%Y/%m/%d";
+pub(crate) const MATCH_TIME_FORMAT: &str = "%I:%M %p";
+
+pub(crate) fn parse_match_items(
+    document: &scraper::Html,
+    policy: ErrorPolicy,
+) -> Result<Vec<MatchItem>> {
+    let match_item_selector = "div#wrapper div.col a.m-item";
+    let selector = Selector::parse(match_item_selector)?;
+    policy.collect(
+        document.select(&selector).map(parse_match_item),
+        "match item",
+    )
+}
+
+/// Read the number of pages from a match history's pagination control: the
+/// highest page number among its links. Histories that fit on one page have
+/// no control and count as a single page.
+pub(crate) fn parse_total_pages(document: &scraper::Html) -> Result<u8> {
+    let selector = selector!("div.action-container-pages :is(span,a)");
+    let total_pages = document
+        .select(selector)
+        .filter_map(|e| {
+            normalize_number(&e.text().collect::<String>())
+                .parse::<u8>()
+                .ok()
+        })
+        .max()
+        .unwrap_or(1);
+    Ok(total_pages)
+}
+
+pub(crate) fn parse_match_item(element: ElementRef) -> Result<MatchItem> {
+    let href = element.value().attr("href");
+    let (id, slug) = href
+        .and_then(|href| {
+            href.strip_prefix("/")
+                .unwrap_or_default()
+                .split('/')
+                .collect_tuple()
+        })
+        .map(|(id, slug)| (id.parse().unwrap_or_default(), slug.to_string()))
+        .ok_or(VlrError::ElementNotFound {
+            context: "match item href",
+        })?;
+
+    let league_icon_selector = selector!("div.m-item-thumb img");
+    let league_icon = element
+        .select(league_icon_selector)
+        .next()
+        .and_then(|e| e.value().attr("src"))
+        .map(normalize_img_url)
+        .unwrap_or_default();
+
+    let league_name_selector = selector!("div.m-item-event div");
+    let league_name = select_text(&element, league_name_selector);
+
+    let league_series_selector = selector!("div.m-item-event");
+    let league_series_name = element
+        .select(league_series_selector)
+        .filter_map(|t| t.text().last())
+        .map(|t| t.trim().to_string())
+        .last()
+        .unwrap_or_default()
+        .replace(['\n', '\t'], "");
+
+    let teams_selector = selector!("div.m-item-team");
+    let logos_selector = selector!("div.m-item-logo img");
+    let scores_selector = selector!("div.m-item-result span");
+    let teams = izip!(
+        element.select(teams_selector),
+        element.select(logos_selector),
+        element.select(scores_selector)
+    )
+    .map(|(team, logo, score)| parse_team(team, logo, score))
+    .collect::<Result<Vec<_>>>()?;
+
+    let result_selector = selector!("div.m-item-result");
+    let result = element
+        .select(result_selector)
+        .next()
+        .and_then(|e| parse_result(e, &teams));
+
+    let vods_selector = selector!("div.m-item-vods div.wf-tag span.full");
+    let vods = element
+        .select(vods_selector)
+        .filter_map(|t| t.text().last())
+        .map(|t| t.trim().to_string())
+        .collect_vec();
+
+    let date_selector = selector!("div.m-item-date div");
+    let date = select_text(&element, date_selector);
+    let date = NaiveDate::parse_from_str(&date, MATCH_DATE_FORMAT).ok();
+
+    let time_selector = selector!("div.m-item-date");
+    let time = element
+        .select(time_selector)
+        .filter_map(|t| t.text().last())
+        .map(|t| t.trim().to_string())
+        .last()
+        .unwrap_or_default()
+        .replace(['\n', '\t'], "");
+    let time = NaiveTime::parse_from_str(&time, MATCH_TIME_FORMAT).ok();
+
+    Ok(MatchItem {
+        id,
+        slug,
+        league_icon,
+        league_name,
+        league_series_name,
+        teams,
+        vods,
+        match_start: date.and_then(|d| time.map(|t| d.and_time(t))),
+        result,
+    })
+}
+
+/// Read the result from the `mod-win`/`mod-loss` marker, falling back to
+/// comparing the two scores.
+fn parse_result(result: ElementRef, teams: &[MatchItemTeam]) -> Option<MatchResult> {
+    let has = |class| {
+        result
+            .value()
+            .has_class(class, CaseSensitivity::CaseSensitive)
+    };
+    if has("mod-win") {
+        return Some(MatchResult::Win);
+    }
+    if has("mod-loss") {
+        return Some(MatchResult::Loss);
+    }
+    if has("mod-draw") {
+        return Some(MatchResult::Draw);
+    }
+    let (own, other) = (teams.first()?.score?, teams.get(1)?.score?);
+    Some(match own.cmp(&other) {
+        std::cmp::Ordering::Greater => MatchResult::Win,
+        std::cmp::Ordering::Less => MatchResult::Loss,
+        std::cmp::Ordering::Equal => MatchResult::Draw,
+    })
+}
+
+fn parse_team(
+    team_element: ElementRef,
+    logo_element: ElementRef,
+    score_element: ElementRef,
+) -> Result<MatchItemTeam> {
+    let name_selector = selector!("span.m-item-team-name");
+    let name = select_text(&team_element, name_selector);
+
+    let tag_selector = selector!("span.m-item-team-tag");
+    let tag = select_text(&team_element, tag_selector);
+
+    let logo_url = logo_element
+        .value()
+        .attr("src")
+        .map(normalize_img_url)
+        .unwrap_or_default();
+
+    let score = normalize_number(score_element.text().last().unwrap_or_default())
+        .parse()
+        .ok();
+
+    // The team page link, on the name or logo when the layout has one
+    let link_selector = selector!("a[href^='/team/'], [data-href^='/team/']");
+    let (id, slug) = [team_element, logo_element]
+        .iter()
+        .flat_map(|e| std::iter::once(*e).chain(e.select(link_selector)))
+        .find_map(|e| {
+            let href = e.value().attr("href").or(e.value().attr("data-href"))?;
+            let (id, slug) = href.strip_prefix("/team/")?.split('/').collect_tuple()?;
+            Some((id.parse().ok(), Some(slug.to_string())))
+        })
+        .unwrap_or_default();
+
+    Ok(MatchItemTeam {
+        id,
+        slug,
+        name,
+        tag,
+        logo_url,
+        score,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_total_pages() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="action-container"><div class="action-container-pages">
+                <span class="btn mod-page mod-active">1</span>
+                <a class="btn mod-page" href="?page=2">2</a>
+                <span class="btn mod-page mod-disabled">...</span>
+                <a class="btn mod-page" href="?page=14">14</a>
+            </div></div>"#,
+        );
+        assert_eq!(parse_total_pages(&document).unwrap(), 14);
+
+        let document = scraper::Html::parse_document("<div class=\"wf-card\"></div>");
+        assert_eq!(parse_total_pages(&document).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_parse_match_item_result() {
+        let item = |result: &str, scores: (u8, u8)| {
+            let html = scraper::Html::parse_fragment(&format!(
+                r#"<a class="m-item" href="/378829/sentinels-vs-fnatic-masters">
+                    <div class="m-item-team"><span class="m-item-team-name">Sentinels</span></div>
+                    <div class="m-item-logo"><img src="//owcdn.net/img/sen.png"></div>
+                    <div class="m-item-result {result}"><span>{}</span><span>{}</span></div>
+                    <div class="m-item-logo"><img src="//owcdn.net/img/fnc.png"></div>
+                    <div class="m-item-team mod-right" data-href="/team/2593/fnatic">
+                        <span class="m-item-team-name">FNATIC</span>
+                    </div>
+                </a>"#,
+                scores.0, scores.1
+            ));
+            let element = html
+                .select(&Selector::parse("a.m-item").unwrap())
+                .next()
+                .unwrap();
+            parse_match_item(element).unwrap()
+        };
+
+        assert_eq!(item("mod-win", (2, 1)).result, Some(MatchResult::Win));
+        assert_eq!(item("mod-loss", (2, 1)).result, Some(MatchResult::Loss));
+        assert_eq!(item("", (0, 2)).result, Some(MatchResult::Loss));
+        assert_eq!(item("", (1, 1)).result, Some(MatchResult::Draw));
+
+        let win = item("mod-win", (2, 0));
+        assert!(crate::model::MatchFilter::result(MatchResult::Win)
+            .against(2593)
+            .matches(&win));
+        assert!(!crate::model::MatchFilter::result(MatchResult::Win)
+            .against(188)
+            .matches(&win));
+        assert!(!crate::model::MatchFilter::result(MatchResult::Loss).matches(&win));
+    }
+
+    #[test]
+    fn test_parse_team_link() {
+        let html = scraper::Html::parse_fragment(
+            r#"<div class="m-item-team">
+                <a href="/team/2593/fnatic"><span class="m-item-team-name">FNATIC</span></a>
+                <span class="m-item-team-tag">FNC</span>
+            </div>
+            <div class="m-item-logo"><img src="//owcdn.net/img/fnc.png"></div>
+            <div class="m-item-result"><span>2</span></div>
+            <div class="m-item-team mod-right"><span class="m-item-team-name">TBD</span></div>"#,
+        );
+        let select = |selector: &str| {
+            html.select(&Selector::parse(selector).unwrap())
+                .collect_vec()
+        };
+        let (teams, logos, scores) = (
+            select("div.m-item-team"),
+            select("div.m-item-logo"),
+            select("div.m-item-result span"),
+        );
+
+        let team = parse_team(teams[0], logos[0], scores[0]).unwrap();
+        assert_eq!(team.id, Some(2593));
+        assert_eq!(team.slug.as_deref(), Some("fnatic"));
+        assert_eq!(team.name, "FNATIC");
+        assert_eq!(team.score, Some(2));
+
+        let tbd = parse_team(teams[1], logos[0], scores[0]).unwrap();
+        assert_eq!(tbd.id, None);
+        assert_eq!(tbd.slug, None);
+    }
+}
@@ -1,39 +1,244 @@
+/// A `&'static Selector` for `css`, parsed on first use and shared by every
+/// later call. For the selectors of per-item parsers, which would otherwise
+/// re-parse the same CSS for each row. `css` must be valid; an invalid
+/// selector panics on first use.
+macro_rules! selector {
+    ($css:expr) => {{
+        static SELECTOR: std::sync::LazyLock<scraper::Selector> = std::sync::LazyLock::new(|| {
+            scraper::Selector::parse($css).expect("selector should be valid CSS")
+        });
+        &*SELECTOR
+    }};
+}
+
+#[cfg(feature = "events")]
 pub(crate) mod events;
+// The shared match item parser is also used by player and team match histories
+#[cfg(any(feature = "matches", feature = "players", feature = "teams"))]
 pub(crate) mod matches;
+#[cfg(feature = "news")]
+pub(crate) mod news;
+#[cfg(feature = "players")]
 pub(crate) mod players;
+pub(crate) mod search;
+#[cfg(feature = "stats")]
+pub(crate) mod stats;
+#[cfg(feature = "teams")]
 pub(crate) mod teams;
 
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[cfg(any(
+    feature = "events",
+    feature = "matches",
+    feature = "news",
+    feature = "players",
+    feature = "teams",
+    feature = "stats"
+))]
+use itertools::Itertools;
 pub(crate) use scraper::Html;
 use scraper::{ElementRef, Selector};
 use tracing::debug;
 
-use crate::error::{Result, VlrError};
+use crate::cache::Cache;
+use crate::error::{ErrorPolicy, Result, VlrError};
+use crate::metrics::Metrics;
+#[cfg(any(feature = "players", feature = "teams"))]
+use crate::model::SocialPlatform;
+
+/// The site pages are requested from by default. Links in parsed data always
+/// point here, whatever base URL the pages were fetched from.
+pub(crate) const BASE_URL: &str = "https://www.vlr.gg";
+
+/// Wait before the first retry of a failed request; doubled for every
+/// further retry, up to [`RETRY_MAX_DELAY`].
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// The longest a single retry waits. A `Retry-After` asking for more fails
+/// the request right away instead.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// The HTTP client every scraper fetches through, with an optional page cache,
+/// optional metrics hooks, and the policy for items that fail to parse.
+#[derive(Clone)]
+pub(crate) struct HttpClient {
+    pub(crate) http: reqwest::Client,
+    /// Scheme and host pages are requested from, without a trailing slash.
+    pub(crate) base_url: String,
+    pub(crate) cache: Option<Arc<dyn Cache>>,
+    pub(crate) metrics: Option<Arc<dyn Metrics>>,
+    pub(crate) error_policy: ErrorPolicy,
+    /// How many times a failed request is repeated before giving up.
+    pub(crate) max_retries: u32,
+}
 
-const BASE_URL: &str = "https://www.vlr.gg";
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self {
+            http: reqwest::Client::default(),
+            base_url: BASE_URL.to_string(),
+            cache: None,
+            metrics: None,
+            error_policy: ErrorPolicy::default(),
+            max_retries: 0,
+        }
+    }
+}
+
+impl HttpClient {
+    /// The URL of `path` (starting with `/`) on the configured site.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+}
+
+impl From<reqwest::Client> for HttpClient {
+    fn from(http: reqwest::Client) -> Self {
+        Self {
+            http,
+            ..Default::default()
+        }
+    }
+}
 
 /// Fetch a URL and parse the response body as an HTML document.
-pub(crate) async fn get_document(client: &reqwest::Client, url: &str) -> Result<Html> {
+///
+/// Serves the page from the client's cache when it holds one. A fetched page
+/// is only stored when the cache
+/// [stores changing pages](crate::Cache::stores_changing_pages), e.g. an
+/// `LruCache` with a TTL; see [`get_document_caching`].
+pub(crate) async fn get_document(client: &HttpClient, url: &str) -> Result<Html> {
+    get_document_caching(client, url, |_| false).await
+}
+
+/// Like [`get_document`], but also stores a freshly fetched page in the
+/// cache when `is_final` says it can no longer change.
+pub(crate) async fn get_document_caching(
+    client: &HttpClient,
+    url: &str,
+    is_final: impl FnOnce(&Html) -> bool,
+) -> Result<Html> {
+    if let Some(body) = client.cache.as_ref().and_then(|c| c.get(url)) {
+        debug!(url, "serving page from cache");
+        return Ok(Html::parse_document(&body));
+    }
+
     debug!(url, "fetching page");
 
-    let response = client.get(url).send().await.map_err(|e| VlrError::Http {
-        url: url.to_owned(),
-        source: e,
-    })?;
+    let mut attempt = 0;
+    let body = loop {
+        let result = fetch_body_observed(client, url).await;
+        let delay = match &result {
+            Err(error) if attempt < client.max_retries => retry_delay(error, attempt),
+            _ => None,
+        };
+        match delay {
+            Some(delay) => {
+                attempt += 1;
+                debug!(url, attempt, ?delay, "retrying request");
+                tokio::time::sleep(delay).await;
+            }
+            None => break result?,
+        }
+    };
+
+    let document = Html::parse_document(&body);
+    if let Some(cache) = &client.cache {
+        if cache.stores_changing_pages() || is_final(&document) {
+            cache.put(url, body);
+        }
+    }
+    Ok(document)
+}
+
+/// How long to wait before retrying a request that failed with `error` on
+/// its `attempt`-th retry (0 for the first), or `None` when the failure
+/// isn't worth retrying.
+///
+/// Requests without a response, `429 Too Many Requests`, and server errors
+/// are retried with exponential backoff; a rate limit's `Retry-After` takes
+/// precedence when sent, unless it exceeds [`RETRY_MAX_DELAY`], in which case
+/// the rate limit is returned to the caller instead of waited out.
+fn retry_delay(error: &VlrError, attempt: u32) -> Option<Duration> {
+    let backoff = RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RETRY_MAX_DELAY);
+    match error {
+        VlrError::Http { .. } => Some(backoff),
+        VlrError::RateLimited { retry_after, .. } => match retry_after {
+            Some(retry_after) if *retry_after > RETRY_MAX_DELAY => None,
+            Some(retry_after) => Some(*retry_after),
+            None => Some(backoff),
+        },
+        VlrError::UnexpectedStatus { status, .. } if status.is_server_error() => Some(backoff),
+        _ => None,
+    }
+}
+
+/// Read a `Retry-After` header given in seconds. The HTTP-date form is not
+/// supported and yields `None`, like a missing header.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let seconds = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// [`fetch_body`], reporting the request to the client's metrics hooks.
+async fn fetch_body_observed(client: &HttpClient, url: &str) -> Result<String> {
+    let metrics = client.metrics.as_deref();
+    if let Some(metrics) = metrics {
+        metrics.on_request_start(url);
+    }
+    let started = Instant::now();
+    let mut status = None;
+    let body = fetch_body(client, url, &mut status).await;
+    if let Some(metrics) = metrics {
+        metrics.on_request_end(url, status.map(|s| s.as_u16()), started.elapsed());
+    }
+    body
+}
+
+/// Send the request for `url` and read the body of a successful response,
+/// recording the response status in `status` as soon as it is known.
+async fn fetch_body(
+    client: &HttpClient,
+    url: &str,
+    status: &mut Option<reqwest::StatusCode>,
+) -> Result<String> {
+    let response = client
+        .http
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| VlrError::Http {
+            url: url.to_owned(),
+            source: e,
+        })?;
 
-    let status = response.status();
-    if !status.is_success() {
+    *status = Some(response.status());
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(VlrError::RateLimited {
+            url: url.to_owned(),
+            retry_after: parse_retry_after(response.headers()),
+        });
+    }
+    if !response.status().is_success() {
         return Err(VlrError::UnexpectedStatus {
             url: url.to_owned(),
-            status,
+            status: response.status(),
         });
     }
 
-    let body = response.text().await.map_err(|e| VlrError::ResponseBody {
+    response.text().await.map_err(|e| VlrError::ResponseBody {
         url: url.to_owned(),
         source: e,
-    })?;
-
-    Ok(Html::parse_document(&body))
+    })
 }
 
 /// Extract trimmed text content from the first element matching `selector`
@@ -49,25 +254,214 @@ pub(crate) fn select_text(element: &ElementRef, selector: &Selector) -> String {
         .to_string()
 }
 
+/// Return `name`, or when it is empty the `alt` text of the first image
+/// matching `img_selector` inside `element`. Logos and avatars usually carry
+/// the name as `alt`, so this keeps names filled in when the name markup moves.
+#[cfg(any(feature = "players", feature = "teams"))]
+pub(crate) fn name_or_alt(name: String, element: &ElementRef, img_selector: &Selector) -> String {
+    if !name.is_empty() {
+        return name;
+    }
+    let alt = element
+        .select(img_selector)
+        .filter_map(|img| img.value().attr("alt"))
+        .map(str::trim)
+        .find(|alt| !alt.is_empty());
+    match alt {
+        Some(alt) => {
+            debug!(alt, "name missing, falling back to image alt text");
+            alt.to_string()
+        }
+        None => name,
+    }
+}
+
+/// Zero code points of the Unicode decimal digit blocks we map onto ASCII:
+/// full-width, Arabic-Indic, extended Arabic-Indic, Devanagari, and Bengali.
+#[cfg(any(
+    feature = "events",
+    feature = "matches",
+    feature = "news",
+    feature = "players",
+    feature = "teams",
+    feature = "stats"
+))]
+const DIGIT_ZEROS: [u32; 5] = [0xFF10, 0x0660, 0x06F0, 0x0966, 0x09E6];
+
+/// Normalize scraped numeric text so it can be handed to `str::parse`.
+///
+/// Maps full-width and other non-ASCII decimal digits (and full-width
+/// `.`/`+`/`-`/`%`) to ASCII, and strips whitespace (including non-breaking
+/// spaces) and `,` thousands separators.
+///
+/// A lone `,` that isn't followed by exactly three digits, in text without a
+/// `.`, is a decimal comma from a localized page (`"1,23"` becomes `"1.23"`).
+/// `"1,234"` stays a thousands separator; requesting pages in English (the
+/// [`VlrClient::new`](crate::VlrClient::new) default) avoids the ambiguity.
+#[cfg(any(
+    feature = "events",
+    feature = "matches",
+    feature = "news",
+    feature = "players",
+    feature = "teams",
+    feature = "stats"
+))]
+pub(crate) fn normalize_number(s: &str) -> String {
+    let chars: Vec<char> = s
+        .chars()
+        .filter_map(|c| {
+            let code = c as u32;
+            if let Some(zero) = DIGIT_ZEROS.iter().find(|&&z| (z..z + 10).contains(&code)) {
+                return char::from_digit(code - zero, 10);
+            }
+            match c {
+                '\u{ff0c}' => Some(','),
+                c if c.is_whitespace() => None,
+                '\u{ff0e}' => Some('.'),
+                '\u{ff0b}' => Some('+'),
+                '\u{ff0d}' | '\u{2212}' => Some('-'),
+                '\u{ff05}' => Some('%'),
+                c => Some(c),
+            }
+        })
+        .collect();
+
+    let decimal_comma = match chars.iter().positions(|&c| c == ',').collect_vec()[..] {
+        [pos] if !chars.contains(&'.') => {
+            chars[pos + 1..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count()
+                != 3
+        }
+        _ => false,
+    };
+    chars
+        .into_iter()
+        .filter_map(|c| match c {
+            ',' if decimal_comma => Some('.'),
+            ',' => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+/// The kind of vlr.gg page a URL points at.
+#[cfg(any(feature = "matches", feature = "players", feature = "teams"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::IntoStaticStr)]
+#[strum(serialize_all = "lowercase")]
+pub(crate) enum VlrUrlKind {
+    Match,
+    Event,
+    Player,
+    Team,
+}
+
+/// Extract the page kind and numeric id from a vlr.gg URL.
+///
+/// Accepts absolute URLs (with or without scheme and `www.`) as well as
+/// site-relative paths, ignoring query strings and fragments. Sub-pages such
+/// as `/team/matches/{id}` resolve to the entity they belong to. Match pages
+/// are the bare `/{id}/{slug}` form.
+#[cfg(any(feature = "matches", feature = "players", feature = "teams"))]
+pub(crate) fn parse_vlr_url(url: &str) -> Option<(VlrUrlKind, u32)> {
+    let trimmed = url.trim();
+    let rest = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+        .unwrap_or(trimmed);
+    let rest = rest.strip_prefix("www.").unwrap_or(rest);
+    let path = match rest.strip_prefix("vlr.gg") {
+        Some(path) => path,
+        None if rest.starts_with('/') => rest,
+        None => return None,
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let kind = match segments.first().copied()? {
+        "event" => VlrUrlKind::Event,
+        "player" => VlrUrlKind::Player,
+        "team" => VlrUrlKind::Team,
+        id => return id.parse().ok().map(|id| (VlrUrlKind::Match, id)),
+    };
+    // The id is either the next segment or follows a sub-page name
+    segments[1..]
+        .iter()
+        .take(2)
+        .find_map(|s| s.parse().ok())
+        .map(|id| (kind, id))
+}
+
+/// Extract the id from a vlr.gg URL, failing with [`VlrError::InvalidUrl`]
+/// unless it points at a page of the `expected` kind.
+#[cfg(any(feature = "matches", feature = "players", feature = "teams"))]
+pub(crate) fn vlr_url_id(url: &str, expected: VlrUrlKind) -> Result<u32> {
+    match parse_vlr_url(url) {
+        Some((kind, id)) if kind == expected => Ok(id),
+        _ => Err(VlrError::InvalidUrl {
+            url: url.to_owned(),
+            expected: expected.into(),
+        }),
+    }
+}
+
+/// Parse a follower count like `"1.2M"`, `"34K followers"`, or
+/// `"12,345 followers"`. Bare numbers without a `K`/`M`/`B` suffix or a
+/// "followers" label are rejected, so numeric handles aren't mistaken for
+/// counts.
+#[cfg(any(feature = "players", feature = "teams"))]
+pub(crate) fn parse_follower_count(text: &str) -> Option<u64> {
+    let lower = text.trim().to_lowercase();
+    let (number, labelled) = match lower
+        .strip_suffix("followers")
+        .or_else(|| lower.strip_suffix("follower"))
+    {
+        Some(number) => (number, true),
+        None => (lower.as_str(), false),
+    };
+    let number = normalize_number(number);
+    let (digits, multiplier) = match number.chars().last()? {
+        'k' => (&number[..number.len() - 1], 1e3),
+        'm' => (&number[..number.len() - 1], 1e6),
+        'b' => (&number[..number.len() - 1], 1e9),
+        _ if labelled => (number.as_str(), 1.0),
+        _ => return None,
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+    let value: f64 = digits.parse().ok()?;
+    Some((value * multiplier).round() as u64)
+}
+
 /// Infer the social media platform from a URL.
-pub(crate) fn infer_platform(url: &str) -> String {
+#[cfg(any(feature = "players", feature = "teams"))]
+pub(crate) fn infer_platform(url: &str) -> SocialPlatform {
     let url_lower = url.to_lowercase();
     if url_lower.contains("twitter.com") || url_lower.contains("x.com") {
-        "twitter".to_string()
+        SocialPlatform::Twitter
     } else if url_lower.contains("twitch.tv") {
-        "twitch".to_string()
+        SocialPlatform::Twitch
     } else if url_lower.contains("instagram.com") {
-        "instagram".to_string()
+        SocialPlatform::Instagram
     } else if url_lower.contains("youtube.com") || url_lower.contains("youtu.be") {
-        "youtube".to_string()
+        SocialPlatform::YouTube
     } else if url_lower.contains("tiktok.com") {
-        "tiktok".to_string()
+        SocialPlatform::TikTok
     } else {
-        "other".to_string()
+        SocialPlatform::Other
     }
 }
 
 /// Normalize a potentially relative image URL to an absolute vlr.gg URL.
+#[cfg(any(
+    feature = "events",
+    feature = "matches",
+    feature = "players",
+    feature = "teams",
+    feature = "stats"
+))]
 pub(crate) fn normalize_img_url(src: &str) -> String {
     if src.starts_with("//") {
         format!("https:{src}")
@@ -77,3 +471,334 @@ pub(crate) fn normalize_img_url(src: &str) -> String {
         src.to_string()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::cache::MemoryCache;
+
+    /// Serve `responses` to consecutive connections on a local port and
+    /// return the server's base URL.
+    pub(crate) fn serve(responses: Vec<&'static str>) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        base_url
+    }
+
+    const RATE_LIMITED: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    pub(crate) const OK: &str =
+        "HTTP/1.1 200 OK\r\nContent-Length: 11\r\nConnection: close\r\n\r\n<p>page</p>";
+
+    #[tokio::test]
+    async fn test_get_document_retries_rate_limited_request() {
+        let client = HttpClient {
+            max_retries: 2,
+            ..Default::default()
+        };
+        let url = format!("{}/1", serve(vec![RATE_LIMITED, OK]));
+        let document = get_document(&client, &url).await.unwrap();
+        let p = Selector::parse("p").unwrap();
+        assert_eq!(document.select(&p).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_document_reports_exhausted_rate_limit() {
+        let client = HttpClient {
+            max_retries: 1,
+            ..Default::default()
+        };
+        let url = format!("{}/1", serve(vec![RATE_LIMITED, RATE_LIMITED]));
+        let error = get_document(&client, &url).await.unwrap_err();
+        assert!(matches!(
+            error,
+            VlrError::RateLimited {
+                retry_after: Some(d),
+                ..
+            } if d.is_zero()
+        ));
+    }
+
+    #[cfg(feature = "lru-cache")]
+    #[tokio::test]
+    async fn test_get_document_lru_cache_hit_skips_request() {
+        let client = HttpClient {
+            cache: Some(Arc::new(
+                crate::cache::LruCache::new(8).with_ttl(Duration::from_secs(60)),
+            )),
+            ..Default::default()
+        };
+        // The server answers a single request; a second one would fail
+        let url = format!("{}/player/9", serve(vec![OK]));
+        get_document(&client, &url).await.unwrap();
+        let document = get_document(&client, &url).await.unwrap();
+        let p = Selector::parse("p").unwrap();
+        assert_eq!(document.select(&p).count(), 1);
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let status = |status| VlrError::UnexpectedStatus {
+            url: String::new(),
+            status,
+        };
+        assert_eq!(
+            retry_delay(&status(reqwest::StatusCode::BAD_GATEWAY), 0),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            retry_delay(&status(reqwest::StatusCode::SERVICE_UNAVAILABLE), 2),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            retry_delay(&status(reqwest::StatusCode::INTERNAL_SERVER_ERROR), 20),
+            Some(RETRY_MAX_DELAY)
+        );
+        assert_eq!(
+            retry_delay(&status(reqwest::StatusCode::NOT_FOUND), 0),
+            None
+        );
+
+        let rate_limited = |retry_after| VlrError::RateLimited {
+            url: String::new(),
+            retry_after,
+        };
+        assert_eq!(
+            retry_delay(&rate_limited(Some(Duration::from_secs(7))), 0),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(
+            retry_delay(&rate_limited(None), 1),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            retry_delay(&rate_limited(Some(RETRY_MAX_DELAY)), 0),
+            Some(RETRY_MAX_DELAY)
+        );
+        // A day-long Retry-After fails right away instead of sleeping
+        assert_eq!(
+            retry_delay(&rate_limited(Some(Duration::from_secs(86400))), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_selector_is_parsed_once() {
+        fn games() -> &'static Selector {
+            selector!("div.vm-stats-game")
+        }
+        assert!(std::ptr::eq(games(), games()));
+
+        let html = Html::parse_fragment(r#"<div class="vm-stats-game"></div>"#);
+        assert_eq!(html.select(games()).count(), 1);
+    }
+
+    #[test]
+    fn test_url_uses_base_url() {
+        let client = HttpClient::default();
+        assert_eq!(client.url("/team/2"), "https://www.vlr.gg/team/2");
+
+        let client = HttpClient {
+            base_url: "http://localhost:8080".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(client.url("/team/2"), "http://localhost:8080/team/2");
+    }
+
+    #[tokio::test]
+    async fn test_get_document_serves_cached_page() {
+        let cache = Arc::new(MemoryCache::new());
+        // Unresolvable host: the page can only come from the cache
+        let url = "https://cached.invalid/1";
+        cache.put(url, "<h1 class=\"wf-title\">Cached</h1>".to_string());
+        let client = HttpClient {
+            cache: Some(cache.clone()),
+            ..Default::default()
+        };
+
+        let document = get_document(&client, url).await.unwrap();
+        let title = Selector::parse("h1.wf-title").unwrap();
+        assert_eq!(document.select(&title).count(), 1);
+        assert!(get_document(&client, "https://uncached.invalid/")
+            .await
+            .is_err());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_document_reports_network_requests() {
+        #[derive(Default)]
+        struct Recorder(std::sync::Mutex<Vec<(String, Option<u16>)>>);
+        impl Metrics for Recorder {
+            fn on_request_end(&self, url: &str, status: Option<u16>, _: std::time::Duration) {
+                self.0.lock().unwrap().push((url.to_string(), status));
+            }
+        }
+
+        let cache = Arc::new(MemoryCache::new());
+        let recorder = Arc::new(Recorder::default());
+        cache.put("https://cached.invalid/1", String::new());
+        let client = HttpClient {
+            cache: Some(cache),
+            metrics: Some(recorder.clone()),
+            ..Default::default()
+        };
+
+        get_document(&client, "https://cached.invalid/1")
+            .await
+            .unwrap();
+        assert!(get_document(&client, "https://uncached.invalid/")
+            .await
+            .is_err());
+        // Only the network request is reported, without a status
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec![("https://uncached.invalid/".to_string(), None)]
+        );
+    }
+
+    #[cfg(any(feature = "players", feature = "teams"))]
+    #[test]
+    fn test_infer_platform() {
+        assert_eq!(
+            infer_platform("https://x.com/TenZOfficial"),
+            SocialPlatform::Twitter
+        );
+        assert_eq!(
+            infer_platform("https://twitter.com/TenZOfficial"),
+            SocialPlatform::Twitter
+        );
+        assert_eq!(
+            infer_platform("https://www.twitch.tv/tenz"),
+            SocialPlatform::Twitch
+        );
+        assert_eq!(
+            infer_platform("https://youtu.be/dQw4w9WgXcQ"),
+            SocialPlatform::YouTube
+        );
+        assert_eq!(
+            infer_platform("https://liquipedia.net/valorant/TenZ"),
+            SocialPlatform::Other
+        );
+        assert_eq!(SocialPlatform::YouTube.to_string(), "youtube");
+        assert_eq!(SocialPlatform::TikTok.to_string(), "tiktok");
+    }
+
+    #[cfg(any(feature = "players", feature = "teams"))]
+    #[test]
+    fn test_parse_follower_count() {
+        assert_eq!(parse_follower_count("1.2M"), Some(1_200_000));
+        assert_eq!(parse_follower_count("34K followers"), Some(34_000));
+        assert_eq!(parse_follower_count("12,345 Followers"), Some(12_345));
+        assert_eq!(parse_follower_count("1 follower"), Some(1));
+        assert_eq!(parse_follower_count("1337"), None);
+        assert_eq!(parse_follower_count("@TenZ"), None);
+        assert_eq!(parse_follower_count("K"), None);
+        assert_eq!(parse_follower_count("1e5 followers"), None);
+    }
+
+    #[cfg(any(feature = "matches", feature = "players", feature = "teams"))]
+    #[test]
+    fn test_parse_vlr_url() {
+        assert_eq!(
+            parse_vlr_url("https://www.vlr.gg/429519/paper-rex-vs-fnatic-champions-2024"),
+            Some((VlrUrlKind::Match, 429519))
+        );
+        assert_eq!(
+            parse_vlr_url("vlr.gg/player/9/tenz?timespan=all"),
+            Some((VlrUrlKind::Player, 9))
+        );
+        assert_eq!(
+            parse_vlr_url("/team/matches/2/sentinels/?page=2"),
+            Some((VlrUrlKind::Team, 2))
+        );
+        assert_eq!(
+            parse_vlr_url("http://vlr.gg/event/2097/valorant-champions-2024#streams"),
+            Some((VlrUrlKind::Event, 2097))
+        );
+        assert_eq!(parse_vlr_url("https://www.vlr.gg/team/"), None);
+        assert_eq!(parse_vlr_url("https://liquipedia.net/team/2"), None);
+        assert_eq!(parse_vlr_url("https://www.vlr.gg/events"), None);
+    }
+
+    #[cfg(any(feature = "matches", feature = "players", feature = "teams"))]
+    #[test]
+    fn test_vlr_url_id_rejects_other_kinds() {
+        assert_eq!(
+            vlr_url_id("https://www.vlr.gg/team/2/sentinels", VlrUrlKind::Team).unwrap(),
+            2
+        );
+        let err =
+            vlr_url_id("https://www.vlr.gg/team/2/sentinels", VlrUrlKind::Player).unwrap_err();
+        assert!(matches!(
+            err,
+            VlrError::InvalidUrl {
+                expected: "player",
+                ..
+            }
+        ));
+    }
+
+    #[cfg(any(
+        feature = "events",
+        feature = "matches",
+        feature = "news",
+        feature = "players",
+        feature = "teams",
+        feature = "stats"
+    ))]
+    mod normalize_number_tests {
+        use super::*;
+
+        #[test]
+        fn test_normalize_number_whitespace() {
+            assert_eq!(normalize_number("\u{a0}13\u{a0}"), "13");
+            assert_eq!(normalize_number(" 1 234 "), "1234");
+            assert_eq!(normalize_number("0.98\u{202f}"), "0.98");
+        }
+
+        #[test]
+        fn test_normalize_number_thousands_separators() {
+            assert_eq!(normalize_number("1,234"), "1234");
+            assert_eq!(
+                normalize_number("12,345,678").parse::<u32>(),
+                Ok(12_345_678)
+            );
+        }
+
+        #[test]
+        fn test_normalize_number_decimal_comma() {
+            assert_eq!(normalize_number("1,23"), "1.23");
+            assert_eq!(normalize_number("0,9"), "0.9");
+            assert_eq!(normalize_number("1,23").parse::<f32>(), Ok(1.23));
+            assert_eq!(normalize_number("1,234.5"), "1234.5");
+            assert_eq!(normalize_number("72,5%"), "72.5%");
+        }
+
+        #[test]
+        fn test_normalize_number_non_ascii_digits() {
+            assert_eq!(normalize_number("１３"), "13");
+            assert_eq!(normalize_number("１．２５"), "1.25");
+            assert_eq!(normalize_number("－５"), "-5");
+            assert_eq!(normalize_number("٤٢"), "42");
+            assert_eq!(normalize_number("७७％"), "77%");
+        }
+
+        #[test]
+        fn test_normalize_number_keeps_signs_and_suffixes() {
+            assert_eq!(normalize_number("+12"), "+12");
+            assert_eq!(normalize_number("−3"), "-3");
+            assert_eq!(normalize_number("73%"), "73%");
+        }
+    }
+}
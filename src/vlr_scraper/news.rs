@@ -0,0 +1,130 @@
+use itertools::Itertools;
+use scraper::{ElementRef, Selector};
+use tracing::{debug, instrument};
+
+use crate::error::{ErrorPolicy, Result, VlrError};
+use crate::model::NewsItem;
+use crate::vlr_scraper::{self, normalize_number, select_text, HttpClient};
+
+#[instrument(skip(client))]
+pub(crate) async fn get_news(client: &HttpClient, page: u8) -> Result<Vec<NewsItem>> {
+    let url = client.url(&format!("/news/?page={page}"));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let news = parse_news(&document, client.error_policy)?;
+    debug!(count = news.len(), page, "parsed news");
+    Ok(news)
+}
+
+fn parse_news(document: &scraper::Html, policy: ErrorPolicy) -> Result<Vec<NewsItem>> {
+    let item_selector = Selector::parse("div#wrapper a.wf-module-item")?;
+    let items = document
+        .select(&item_selector)
+        .map(|item| parse_news_item(&item))
+        .collect_vec();
+    policy.collect(items, "news item")
+}
+
+/// Parse one listing entry. Its text column holds the title, the
+/// description, and a `• {date} • by {author}` line, in that order.
+fn parse_news_item(item: &ElementRef) -> Result<NewsItem> {
+    let meta_selector = Selector::parse("div.ge-text-light")?;
+    let comments_selector = Selector::parse("div.news-item-comments")?;
+
+    let (id, slug) = item
+        .value()
+        .attr("href")
+        .and_then(|href| href.strip_prefix('/')?.split('/').collect_tuple())
+        .ok_or(VlrError::ElementNotFound {
+            context: "news item href",
+        })?;
+
+    let text_column =
+        item.children()
+            .filter_map(ElementRef::wrap)
+            .next()
+            .ok_or(VlrError::ElementNotFound {
+                context: "news item text column",
+            })?;
+    let mut lines = text_column
+        .children()
+        .filter_map(ElementRef::wrap)
+        .map(|div| div.text().collect::<String>().trim().to_string());
+    let title = lines.next().unwrap_or_default();
+    let description = lines.next().unwrap_or_default();
+
+    let meta = item
+        .select(&meta_selector)
+        .next()
+        .map(|m| m.text().collect::<String>())
+        .unwrap_or_default();
+    let mut meta = meta.split('•').map(str::trim).filter(|s| !s.is_empty());
+    let date = meta.next().unwrap_or_default().to_string();
+    let author = meta
+        .next()
+        .map(|s| s.strip_prefix("by").unwrap_or(s).trim().to_string())
+        .unwrap_or_default();
+
+    let comment_count = normalize_number(&select_text(item, &comments_selector))
+        .parse()
+        .unwrap_or_default();
+
+    Ok(NewsItem {
+        id: id.parse()?,
+        slug: slug.to_string(),
+        title,
+        description,
+        author,
+        date,
+        comment_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_news() {
+        let document = scraper::Html::parse_document(
+            r#"<div id="wrapper"><div class="wf-card">
+                <a href="/465362/sentinels-sign-zekken" class="wf-module-item mod-flex">
+                    <div>
+                        <div style="font-weight: 700;">Sentinels sign zekken</div>
+                        <div>The duelist joins on a two-year deal.</div>
+                        <div class="ge-text-light"><i class="flag mod-us"></i> • April 27, 2024 • by thothgow</div>
+                    </div>
+                    <div class="news-item-comments">1,204</div>
+                </a>
+                <a href="/465363/weekly-recap" class="wf-module-item mod-flex">
+                    <div>
+                        <div style="font-weight: 700;">Weekly recap</div>
+                        <div>Everything that happened this week.</div>
+                        <div class="ge-text-light">• April 26, 2024 • by vlr</div>
+                    </div>
+                </a>
+            </div></div>"#,
+        );
+        let news = parse_news(&document, ErrorPolicy::Strict).unwrap();
+        assert_eq!(news.len(), 2);
+
+        let first = &news[0];
+        assert_eq!(first.id, 465362);
+        assert_eq!(first.slug, "sentinels-sign-zekken");
+        assert_eq!(first.title, "Sentinels sign zekken");
+        assert_eq!(first.description, "The duelist joins on a two-year deal.");
+        assert_eq!(first.date, "April 27, 2024");
+        assert_eq!(first.author, "thothgow");
+        assert_eq!(first.comment_count, 1204);
+
+        assert_eq!(news[1].author, "vlr");
+        assert_eq!(news[1].comment_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_news() {
+        let client = HttpClient::default();
+        let news = get_news(&client, 1).await.unwrap();
+        assert!(!news.is_empty());
+        assert!(news.iter().all(|n| n.id > 0 && !n.title.is_empty()));
+    }
+}
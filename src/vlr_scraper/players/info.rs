@@ -1,89 +1,276 @@
+use std::str::FromStr;
+
+use futures::{StreamExt, TryFutureExt, TryStreamExt};
 use itertools::Itertools;
-use scraper::{ElementRef, Selector};
+use scraper::ElementRef;
 use tracing::{debug, instrument};
 
-use crate::error::{Result, VlrError};
+use crate::error::{ErrorPolicy, Result, VlrError};
 use crate::model::{
-    AgentStatsTimespan, EventPlacement, PlacementEntry, Player, PlayerAgentStats, PlayerInfo,
-    PlayerNewsItem, PlayerTeam, Social,
+    AgentRole, AgentStatsTimespan, EventPlacement, PlacementEntry, Player, PlayerAgentStats,
+    PlayerInfo, PlayerNewsItem, PlayerTeam, Social, SCHEMA_VERSION,
+};
+use crate::vlr_scraper::players::matchlist::get_player_matchlist;
+use crate::vlr_scraper::{
+    self, infer_platform, name_or_alt, normalize_img_url, normalize_number, parse_follower_count,
+    select_text, HttpClient,
 };
-use crate::vlr_scraper::{self, infer_platform, normalize_img_url, select_text};
+
+/// Maximum number of match history pages fetched at once for `include_matches`.
+const MATCH_PAGE_CONCURRENCY: usize = 4;
 
 /// Fetch a complete player profile: basic info, teams, agent stats, news, and event placements.
+///
+/// When `include_matches` is set, that many pages of the player's match history
+/// are fetched alongside the overview page and attached as `recent_matches`.
 #[instrument(skip(client))]
 pub(crate) async fn get_player(
-    client: &reqwest::Client,
+    client: &HttpClient,
     player_id: u32,
     timespan: AgentStatsTimespan,
+    include_matches: Option<u8>,
 ) -> Result<Player> {
-    let overview_url = format!("https://www.vlr.gg/player/{player_id}/?timespan={timespan}");
-
-    // Fetch the overview page and agent stats concurrently
-    let overview_doc = vlr_scraper::get_document(client, &overview_url).await?;
-
-    let (info, current_teams, past_teams) = parse_player_overview(&overview_doc, player_id)?;
-    let news = parse_player_news(&overview_doc)?;
-    let (event_placements, total_winnings) = parse_event_placements(&overview_doc)?;
-    let agent_stats = parse_agent_stats(&overview_doc)?;
+    let overview_url = overview_url(client, player_id, timespan);
+
+    // Fetch the overview page and match history pages concurrently
+    let recent_matches = futures::stream::iter(1..=include_matches.unwrap_or(0))
+        .map(|page| get_player_matchlist(client, player_id, page).map_ok(|list| list.items))
+        .buffered(MATCH_PAGE_CONCURRENCY)
+        .try_concat();
+    let (overview, recent_matches) = futures::try_join!(
+        get_overview(client, &overview_url, player_id),
+        recent_matches
+    )?;
+    let PlayerOverview {
+        info,
+        current_teams,
+        past_teams,
+        news,
+        event_placements,
+        total_winnings,
+        agent_stats,
+    } = overview;
 
     debug!(player_id, name = %info.name, "parsed player profile");
 
     Ok(Player {
+        schema_version: SCHEMA_VERSION,
         info,
         current_teams,
         past_teams,
         agent_stats,
         news,
         event_placements,
+        total_winnings_money: total_winnings.as_deref().and_then(|w| w.parse().ok()),
         total_winnings,
+        recent_matches,
     })
 }
 
+/// Everything parsed from a player's overview page.
+struct PlayerOverview {
+    info: PlayerInfo,
+    current_teams: Vec<PlayerTeam>,
+    past_teams: Vec<PlayerTeam>,
+    news: Vec<PlayerNewsItem>,
+    event_placements: Vec<EventPlacement>,
+    total_winnings: Option<String>,
+    agent_stats: Vec<PlayerAgentStats>,
+}
+
+/// Fetch and parse a player's overview page.
+///
+/// The parsed document is dropped before this returns, so the future stays
+/// `Send` when joined with other requests.
+async fn get_overview(
+    client: &HttpClient,
+    overview_url: &str,
+    player_id: u32,
+) -> Result<PlayerOverview> {
+    let document = vlr_scraper::get_document(client, overview_url).await?;
+    let (info, current_teams, past_teams) =
+        parse_player_overview(&document, player_id, client.error_policy)?;
+    let news = parse_player_news(&document)?;
+    let (event_placements, total_winnings) = parse_event_placements(&document)?;
+    let agent_stats = parse_agent_stats(&document)?;
+    Ok(PlayerOverview {
+        info,
+        current_teams,
+        past_teams,
+        news,
+        event_placements,
+        total_winnings,
+        agent_stats,
+    })
+}
+
+/// The overview page of a player, with agent stats over `timespan`.
+fn overview_url(client: &HttpClient, player_id: u32, timespan: AgentStatsTimespan) -> String {
+    client.url(&format!("/player/{player_id}/?timespan={timespan}"))
+}
+
+/// Fetch a player's overview page and parse only its agent stats table.
+#[instrument(skip(client))]
+pub(crate) async fn get_player_agent_stats(
+    client: &HttpClient,
+    player_id: u32,
+    timespan: AgentStatsTimespan,
+) -> Result<Vec<PlayerAgentStats>> {
+    let document =
+        vlr_scraper::get_document(client, &overview_url(client, player_id, timespan)).await?;
+    let agent_stats = parse_agent_stats(&document)?;
+    debug!(
+        player_id,
+        count = agent_stats.len(),
+        "parsed player agent stats"
+    );
+    Ok(agent_stats)
+}
+
+/// Header labels of the agent stats table, used to find columns by name so
+/// that added or reordered columns don't shift the parsed values.
+struct AgentStatsColumns(Vec<(String, String)>);
+
+impl AgentStatsColumns {
+    fn parse(document: &scraper::Html) -> Self {
+        let th_selector = selector!("table.wf-table thead th");
+        let labels = document
+            .select(th_selector)
+            .map(|th| {
+                let title = th.value().attr("title").unwrap_or_default();
+                (cell_text(&th).to_lowercase(), title.trim().to_lowercase())
+            })
+            .collect();
+        Self(labels)
+    }
+
+    /// The index of the column labelled with any of `names`. Without a header
+    /// row, falls back to the column's position in the classic 17-column
+    /// layout.
+    fn find(&self, names: &[&str], position: usize) -> Option<usize> {
+        if self.0.is_empty() {
+            return Some(position);
+        }
+        self.0.iter().position(|(text, title)| {
+            names.contains(&text.as_str()) || names.contains(&title.as_str())
+        })
+    }
+
+    /// The index of the rating column, whichever rating model version its
+    /// label names. Falls back to `position` like [`find`](Self::find).
+    fn find_rating(&self, position: usize) -> Option<usize> {
+        if self.0.is_empty() {
+            return Some(position);
+        }
+        self.0
+            .iter()
+            .position(|(text, title)| is_rating_label(text) || is_rating_label(title))
+    }
+
+    /// The rating model version named by the rating column's label or title,
+    /// e.g. `"2.0"` for `"R2.0"` or `"Rating 2.0"`.
+    fn rating_version(&self) -> Option<String> {
+        self.0
+            .iter()
+            .flat_map(|(text, title)| [text, title])
+            .find_map(|label| rating_label_version(label))
+            .map(str::to_string)
+    }
+}
+
+/// Whether a lowercased header label names the rating column: `"rating"`,
+/// `"r"`, or either followed by a model version like `"r2.1"`.
+fn is_rating_label(label: &str) -> bool {
+    label == "rating" || label == "r" || rating_label_version(label).is_some()
+}
+
+/// The model version in a lowercased rating label, e.g. `"2.1"` for
+/// `"rating 2.1"`.
+fn rating_label_version(label: &str) -> Option<&str> {
+    let version = label
+        .strip_prefix("rating")
+        .or_else(|| label.strip_prefix('r'))?
+        .trim();
+    let is_version = version.starts_with(|c: char| c.is_ascii_digit())
+        && version.chars().all(|c| c.is_ascii_digit() || c == '.');
+    is_version.then_some(version)
+}
+
 /// Parse agent stats from the table on a player overview page.
+///
+/// A header row that lines up with the cells but lacks one of the classic
+/// columns is an error rather than a row of zeroes.
 fn parse_agent_stats(document: &scraper::Html) -> Result<Vec<PlayerAgentStats>> {
-    let row_selector = Selector::parse("table.wf-table tbody tr")?;
-    let td_selector = Selector::parse("td")?;
-    let img_selector = Selector::parse("img")?;
+    let row_selector = selector!("table.wf-table tbody tr");
+    let td_selector = selector!("td");
+    let img_selector = selector!("img");
+    let header = AgentStatsColumns::parse(document);
+    let positional = AgentStatsColumns(Vec::new());
+    let rating_version = header.rating_version();
 
     document
-        .select(&row_selector)
+        .select(row_selector)
         .map(|row| {
-            let cells: Vec<ElementRef> = row.select(&td_selector).collect();
-            if cells.len() < 17 {
+            let cells: Vec<ElementRef> = row.select(td_selector).collect();
+            // Only trust the header when it lines up with the row's cells
+            let columns = if header.0.len() == cells.len() {
+                &header
+            } else {
+                &positional
+            };
+            if columns.0.is_empty() && cells.len() < 17 {
                 return Err(VlrError::ElementNotFound {
                     context: "agent stats row: expected 17 columns",
                 });
             }
+            let cell = |index: Option<usize>| index.and_then(|i| cells.get(i)).map(cell_text);
+            let column = |names: &[&str], position: usize| cell(columns.find(names, position));
+            let required = |text: Option<String>| {
+                text.ok_or(VlrError::ElementNotFound {
+                    context: "agent stats column",
+                })
+            };
+            let text = |names: &[&str], position: usize| required(column(names, position));
 
             // Agent name from img alt attribute
             let agent = cells[0]
-                .select(&img_selector)
+                .select(img_selector)
                 .next()
                 .and_then(|img| img.value().attr("alt"))
                 .unwrap_or_default()
                 .to_string();
 
             // Usage: "(95) 20%" -> count=95, pct=0.20
-            let use_text = cell_text(&cells[1]);
+            let use_text = text(&["use", "usage"], 1)?;
             let (usage_count, usage_pct) = parse_usage(&use_text);
 
-            let rounds = parse_u32(&cell_text(&cells[2]));
-            let rating = parse_f32(&cell_text(&cells[3]));
-            let acs = parse_f32(&cell_text(&cells[4]));
-            let kd = parse_f32(&cell_text(&cells[5]));
-            let adr = parse_f32(&cell_text(&cells[6]));
-            let kast = parse_pct(&cell_text(&cells[7]));
-            let kpr = parse_f32(&cell_text(&cells[8]));
-            let apr = parse_f32(&cell_text(&cells[9]));
-            let fkpr = parse_f32(&cell_text(&cells[10]));
-            let fdpr = parse_f32(&cell_text(&cells[11]));
-            let kills = parse_u32(&cell_text(&cells[12]));
-            let deaths = parse_u32(&cell_text(&cells[13]));
-            let assists = parse_u32(&cell_text(&cells[14]));
-            let first_kills = parse_u32(&cell_text(&cells[15]));
-            let first_deaths = parse_u32(&cell_text(&cells[16]));
+            let rounds = parse_u32(&text(&["rnd", "rounds"], 2)?);
+            let rating = parse_f32(&required(cell(columns.find_rating(3)))?);
+            let acs = parse_f32(&text(&["acs"], 4)?);
+            let kd = parse_f32(&text(&["k:d", "kd"], 5)?);
+            let adr = parse_f32(&text(&["adr"], 6)?);
+            let kast = parse_pct(&text(&["kast"], 7)?);
+            let kpr = parse_f32(&text(&["kpr"], 8)?);
+            let apr = parse_f32(&text(&["apr"], 9)?);
+            let fkpr = parse_f32(&text(&["fkpr"], 10)?);
+            let fdpr = parse_f32(&text(&["fdpr"], 11)?);
+            let kills = parse_u32(&text(&["k", "kills"], 12)?);
+            let deaths = parse_u32(&text(&["d", "deaths"], 13)?);
+            let assists = parse_u32(&text(&["a", "assists"], 14)?);
+            let first_kills = parse_u32(&text(&["fk", "first kills"], 15)?);
+            let first_deaths = parse_u32(&text(&["fd", "first deaths"], 16)?);
+
+            // Columns newer than the classic layout only exist with a header
+            let optional_pct = |names: &[&str]| {
+                column(names, usize::MAX)
+                    .filter(|t| !t.is_empty())
+                    .map(|t| parse_pct(&t))
+            };
+            let clutch_pct = optional_pct(&["cl%", "clutch%", "clutch %", "clutch"]);
+            let first_kill_pct = optional_pct(&["fk%", "first kill%", "first kill %"]);
 
             Ok(PlayerAgentStats {
+                agent_kind: agent.parse().ok(),
                 agent,
                 usage_count,
                 usage_pct,
@@ -102,6 +289,9 @@ fn parse_agent_stats(document: &scraper::Html) -> Result<Vec<PlayerAgentStats>>
                 assists,
                 first_kills,
                 first_deaths,
+                clutch_pct,
+                first_kill_pct,
+                rating_version: rating_version.clone(),
             })
         })
         .collect()
@@ -119,6 +309,7 @@ fn cell_text(el: &ElementRef) -> String {
 /// Parse usage text like "(95) 20%" into (count, fraction).
 fn parse_usage(text: &str) -> (u32, f32) {
     // Format: "(95) 20%"
+    let text = normalize_number(text);
     let count = text
         .split(')')
         .next()
@@ -139,48 +330,50 @@ fn parse_usage(text: &str) -> (u32, f32) {
 
 /// Parse a percentage string like "77%" into a fraction (0.77).
 fn parse_pct(text: &str) -> f32 {
-    text.strip_suffix('%')
-        .and_then(|s| s.trim().parse::<f32>().ok())
+    normalize_number(text)
+        .strip_suffix('%')
+        .and_then(|s| s.parse::<f32>().ok())
         .map(|p| p / 100.0)
         .unwrap_or(0.0)
 }
 
 fn parse_f32(text: &str) -> f32 {
-    text.trim().parse().unwrap_or(0.0)
+    normalize_number(text).parse().unwrap_or(0.0)
 }
 
 fn parse_u32(text: &str) -> u32 {
-    text.trim().parse().unwrap_or(0)
+    normalize_number(text).parse().unwrap_or(0)
 }
 
 /// Parse the player overview page and return basic info and team lists.
 fn parse_player_overview(
     document: &scraper::Html,
     player_id: u32,
+    policy: ErrorPolicy,
 ) -> Result<(PlayerInfo, Vec<PlayerTeam>, Vec<PlayerTeam>)> {
     let info = parse_player_info(document, player_id)?;
-    let current_teams = parse_teams_section(document, "Current Teams")?;
-    let past_teams = parse_teams_section(document, "Past Teams")?;
+    let current_teams = parse_teams_section(document, "Current Teams", policy)?;
+    let past_teams = parse_teams_section(document, "Past Teams", policy)?;
     Ok((info, current_teams, past_teams))
 }
 
 fn parse_player_info(document: &scraper::Html, player_id: u32) -> Result<PlayerInfo> {
-    let header_selector = Selector::parse("div.player-header")?;
+    let header_selector = selector!("div.player-header");
     let header = document
-        .select(&header_selector)
+        .select(header_selector)
         .next()
         .ok_or(VlrError::ElementNotFound {
             context: "player header",
         })?;
 
     // Name
-    let name_selector = Selector::parse("h1.wf-title")?;
-    let name = select_text(&header, &name_selector);
+    let name_selector = selector!("h1.wf-title");
+    let name = select_text(&header, name_selector);
 
     // Real name
-    let real_name_selector = Selector::parse("h2.player-real-name")?;
+    let real_name_selector = selector!("h2.player-real-name");
     let real_name = {
-        let text = select_text(&header, &real_name_selector);
+        let text = select_text(&header, real_name_selector);
         if text.is_empty() {
             None
         } else {
@@ -189,16 +382,17 @@ fn parse_player_info(document: &scraper::Html, player_id: u32) -> Result<PlayerI
     };
 
     // Avatar
-    let avatar_selector = Selector::parse("div.wf-avatar img")?;
+    let avatar_selector = selector!("div.wf-avatar img");
     let avatar_url = header
-        .select(&avatar_selector)
+        .select(avatar_selector)
         .next()
         .and_then(|e| e.value().attr("src"))
         .map(normalize_img_url);
+    let name = name_or_alt(name, &header, avatar_selector);
 
     // Country code from <i class="flag mod-{code}">
-    let flag_selector = Selector::parse("i.flag")?;
-    let country_code = header.select(&flag_selector).next().and_then(|e| {
+    let flag_selector = selector!("i.flag");
+    let country_code = header.select(flag_selector).next().and_then(|e| {
         e.value()
             .classes()
             .find(|c| c.starts_with("mod-"))
@@ -206,9 +400,9 @@ fn parse_player_info(document: &scraper::Html, player_id: u32) -> Result<PlayerI
     });
 
     // Country name from the text near the flag
-    let country_div_selector = Selector::parse("div.ge-text-light")?;
+    let country_div_selector = selector!("div.ge-text-light");
     let country = header
-        .select(&country_div_selector)
+        .select(country_div_selector)
         .filter_map(|e| {
             let text: String = e
                 .text()
@@ -224,10 +418,17 @@ fn parse_player_info(document: &scraper::Html, player_id: u32) -> Result<PlayerI
         })
         .last();
 
+    // Role badge, e.g. <div class="wf-tag player-role">Duelist</div>
+    let role_selector = selector!(".player-role, .wf-tag");
+    let primary_role = header
+        .select(role_selector)
+        .flat_map(|e| e.text())
+        .find_map(|t| AgentRole::from_str(t.trim()).ok());
+
     // Social links: plain <a> tags in .player-header with non-empty href and text
-    let social_selector = Selector::parse("a")?;
+    let social_selector = selector!("a");
     let socials = header
-        .select(&social_selector)
+        .select(social_selector)
         .filter_map(|a| {
             let href = a
                 .value()
@@ -235,24 +436,49 @@ fn parse_player_info(document: &scraper::Html, player_id: u32) -> Result<PlayerI
                 .unwrap_or_default()
                 .trim()
                 .to_string();
-            let display_text: String = a
+            // A follower count, when shown, is a separate text node in the link
+            let texts: Vec<&str> = a
                 .text()
                 .map(|t| t.trim())
                 .filter(|t| !t.is_empty())
+                .collect();
+            let followers = texts.iter().find_map(|t| parse_follower_count(t));
+            let display_text: String = texts
+                .iter()
+                .filter(|t| parse_follower_count(t).is_none())
+                .copied()
                 .collect::<Vec<_>>()
                 .join("");
             if href.is_empty() || display_text.is_empty() {
                 return None;
             }
-            let platform = infer_platform(&href);
+            let platform_kind = infer_platform(&href);
             Some(Social {
-                platform,
+                platform: platform_kind.to_string(),
+                platform_kind,
                 url: href,
                 display_text,
+                followers,
             })
         })
         .collect();
 
+    // Headline stats shown as "Matches: N" / "Win Rate: N%" label-value pairs
+    let header_text: Vec<&str> = header
+        .text()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .collect();
+    let matches_played = header_stat(&header_text, &["matches", "matches played"])
+        .and_then(|v| normalize_number(&v).parse().ok());
+    let win_rate = header_stat(&header_text, &["win rate", "winrate", "win%", "win %"])
+        .and_then(|v| {
+            normalize_number(&v)
+                .strip_suffix('%')
+                .and_then(|s| s.parse::<f32>().ok())
+        })
+        .map(|p| p / 100.0);
+
     Ok(PlayerInfo {
         id: player_id,
         name,
@@ -261,15 +487,45 @@ fn parse_player_info(document: &scraper::Html, player_id: u32) -> Result<PlayerI
         country,
         country_code,
         socials,
+        matches_played,
+        win_rate,
+        primary_role,
+    })
+}
+
+/// Find the value for a header stat label among the header's text nodes.
+/// Handles both `"Label: value"` in one node and a label node followed by a
+/// separate value node.
+fn header_stat(texts: &[&str], labels: &[&str]) -> Option<String> {
+    let is_label = |t: &str| {
+        let t = t.trim().trim_end_matches(':').trim().to_lowercase();
+        labels.contains(&t.as_str())
+    };
+    texts.iter().enumerate().find_map(|(i, text)| {
+        if let Some((label, value)) = text.split_once(':') {
+            if is_label(label) && !value.trim().is_empty() {
+                return Some(value.trim().to_string());
+            }
+        }
+        if is_label(text) {
+            return texts
+                .get(i + 1)
+                .map(|v| v.trim_start_matches(':').trim().to_string());
+        }
+        None
     })
 }
 
-fn parse_teams_section(document: &scraper::Html, section_title: &str) -> Result<Vec<PlayerTeam>> {
-    let label_selector = Selector::parse("h2.wf-label")?;
-    let team_link_selector = Selector::parse("a.wf-module-item")?;
+fn parse_teams_section(
+    document: &scraper::Html,
+    section_title: &str,
+    policy: ErrorPolicy,
+) -> Result<Vec<PlayerTeam>> {
+    let label_selector = selector!("h2.wf-label");
+    let team_link_selector = selector!("a.wf-module-item");
 
     // Find the h2.wf-label with the matching text, then get teams from its next sibling card
-    let label = document.select(&label_selector).find(|el| {
+    let label = document.select(label_selector).find(|el| {
         el.text()
             .map(|t| t.trim())
             .collect::<String>()
@@ -289,12 +545,10 @@ fn parse_teams_section(document: &scraper::Html, section_title: &str) -> Result<
         None => return Ok(Vec::new()),
     };
 
-    let teams = card
-        .select(&team_link_selector)
-        .filter_map(|a| parse_player_team(a).ok())
-        .collect();
-
-    Ok(teams)
+    policy.collect(
+        card.select(team_link_selector).map(parse_player_team),
+        "player team",
+    )
 }
 
 fn parse_player_team(element: ElementRef) -> Result<PlayerTeam> {
@@ -308,9 +562,9 @@ fn parse_player_team(element: ElementRef) -> Result<PlayerTeam> {
         .unwrap_or_default();
 
     // Team logo
-    let img_selector = Selector::parse("img")?;
+    let img_selector = selector!("img");
     let logo_url = element
-        .select(&img_selector)
+        .select(img_selector)
         .next()
         .and_then(|e| e.value().attr("src"))
         .map(normalize_img_url)
@@ -318,7 +572,7 @@ fn parse_player_team(element: ElementRef) -> Result<PlayerTeam> {
 
     // Team name: first div with font-weight: 500 style, or first meaningful text
     let name_divs: Vec<_> = element
-        .select(&Selector::parse("div")?)
+        .select(selector!("div"))
         .filter(|d| {
             d.value()
                 .attr("style")
@@ -339,9 +593,9 @@ fn parse_player_team(element: ElementRef) -> Result<PlayerTeam> {
     };
 
     // Info text (e.g. "joined in October 2021") from div.ge-text-light
-    let info_selector = Selector::parse("div.ge-text-light")?;
+    let info_selector = selector!("div.ge-text-light");
     let info = element
-        .select(&info_selector)
+        .select(info_selector)
         .filter_map(|e| {
             let text: String = e
                 .text()
@@ -369,11 +623,11 @@ fn parse_player_team(element: ElementRef) -> Result<PlayerTeam> {
 
 /// Parse the Latest News section from a player overview page.
 fn parse_player_news(document: &scraper::Html) -> Result<Vec<PlayerNewsItem>> {
-    let label_selector = Selector::parse("h2.wf-label")?;
-    let item_selector = Selector::parse("a.wf-module-item")?;
-    let date_selector = Selector::parse("div.ge-text-light")?;
+    let label_selector = selector!("h2.wf-label");
+    let item_selector = selector!("a.wf-module-item");
+    let date_selector = selector!("div.ge-text-light");
 
-    let label = document.select(&label_selector).find(|el| {
+    let label = document.select(label_selector).find(|el| {
         el.text()
             .map(|t| t.trim())
             .collect::<String>()
@@ -393,7 +647,7 @@ fn parse_player_news(document: &scraper::Html) -> Result<Vec<PlayerNewsItem>> {
     };
 
     let news = card
-        .select(&item_selector)
+        .select(item_selector)
         .filter_map(|a| {
             let href = a
                 .value()
@@ -405,7 +659,7 @@ fn parse_player_news(document: &scraper::Html) -> Result<Vec<PlayerNewsItem>> {
                 return None;
             }
 
-            let date = select_text(&a, &date_selector);
+            let date = select_text(&a, date_selector);
 
             // Title is the text in the div with font-weight: 500
             let title: String = a
@@ -438,10 +692,10 @@ fn parse_player_news(document: &scraper::Html) -> Result<Vec<PlayerNewsItem>> {
 fn parse_event_placements(
     document: &scraper::Html,
 ) -> Result<(Vec<EventPlacement>, Option<String>)> {
-    let label_selector = Selector::parse("h2.wf-label")?;
-    let event_item_selector = Selector::parse("a.player-event-item")?;
+    let label_selector = selector!("h2.wf-label");
+    let event_item_selector = selector!("a.player-event-item");
 
-    let label = document.select(&label_selector).find(|el| {
+    let label = document.select(label_selector).find(|el| {
         el.text()
             .map(|t| t.trim())
             .collect::<String>()
@@ -461,25 +715,25 @@ fn parse_event_placements(
     };
 
     // Total winnings: first span in the header div with font-size: 22px
-    let winnings_selector = Selector::parse("span")?;
+    let winnings_selector = selector!("span");
     let total_winnings = card
         .children()
         .filter_map(ElementRef::wrap)
         .next() // first child div (header)
         .and_then(|header| {
             header
-                .select(&winnings_selector)
+                .select(winnings_selector)
                 .next()
                 .map(|s| cell_text(&s))
         })
         .filter(|s| !s.is_empty());
 
-    let event_name_selector = Selector::parse("div.text-of")?;
-    let stage_selector = Selector::parse("span.ge-text-light")?;
-    let prize_selector = Selector::parse("span[style]")?;
+    let event_name_selector = selector!("div.text-of");
+    let stage_selector = selector!("span.ge-text-light");
+    let prize_selector = selector!("span[style]");
 
     let placements = card
-        .select(&event_item_selector)
+        .select(event_item_selector)
         .filter_map(|a| {
             let href = a
                 .value()
@@ -497,7 +751,7 @@ fn parse_event_placements(
                 })
                 .unwrap_or_default();
 
-            let event_name = select_text(&a, &event_name_selector);
+            let event_name = select_text(&a, event_name_selector);
 
             // Year is in the last child div (not inside the flex: 1 div)
             let year: String = a
@@ -522,7 +776,7 @@ fn parse_event_placements(
                         .filter_map(|entry_div| {
                             // Stage + placement from span.ge-text-light
                             let stage_placement = entry_div
-                                .select(&stage_selector)
+                                .select(stage_selector)
                                 .next()
                                 .map(|s| cell_text(&s))
                                 .unwrap_or_default();
@@ -541,7 +795,7 @@ fn parse_event_placements(
 
                             // Prize from span with font-weight: 700
                             let prize = entry_div
-                                .select(&prize_selector)
+                                .select(prize_selector)
                                 .find(|s| {
                                     s.value()
                                         .attr("style")
@@ -577,6 +831,7 @@ fn parse_event_placements(
                             Some(PlacementEntry {
                                 stage,
                                 placement,
+                                prize_money: prize.as_deref().and_then(|p| p.parse().ok()),
                                 prize,
                                 team_name,
                             })
@@ -607,12 +862,189 @@ fn parse_event_placements(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_player_info_primary_role() {
+        let role = |badge: &str| {
+            let document = scraper::Html::parse_document(&format!(
+                r#"<div class="player-header">
+                    <h1 class="wf-title">TenZ</h1>
+                    {badge}
+                </div>"#
+            ));
+            parse_player_info(&document, 9).unwrap().primary_role
+        };
+        assert_eq!(
+            role(r#"<div class="wf-tag player-role">Duelist</div>"#),
+            Some(AgentRole::Duelist)
+        );
+        assert_eq!(
+            role(r#"<span class="player-role">sentinel</span>"#),
+            Some(AgentRole::Sentinel)
+        );
+        assert_eq!(role(r#"<div class="wf-tag">Inactive</div>"#), None);
+        assert_eq!(role(""), None);
+    }
+
+    #[test]
+    fn test_parse_player_info_name_from_avatar_alt() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="player-header">
+                <div class="wf-avatar"><img src="//owcdn.net/img/tenz.png" alt="TenZ"></div>
+                <div class="player-header-name">TenZ</div>
+            </div>"#,
+        );
+        let info = parse_player_info(&document, 9).unwrap();
+        assert_eq!(info.name, "TenZ");
+        assert_eq!(
+            info.avatar_url.as_deref(),
+            Some("https://owcdn.net/img/tenz.png")
+        );
+    }
+
+    #[test]
+    fn test_agent_stats_rating_version() {
+        let columns = |labels: &[(&str, &str)]| {
+            AgentStatsColumns(
+                labels
+                    .iter()
+                    .map(|(text, title)| (text.to_string(), title.to_string()))
+                    .collect(),
+            )
+        };
+        assert_eq!(
+            columns(&[("use", ""), ("r2.0", "")]).rating_version(),
+            Some("2.0".to_string())
+        );
+        assert_eq!(
+            columns(&[("rating", "rating 2.1")]).rating_version(),
+            Some("2.1".to_string())
+        );
+        assert_eq!(
+            columns(&[("rating", ""), ("rnd", "")]).rating_version(),
+            None
+        );
+        assert_eq!(columns(&[]).rating_version(), None);
+        assert_eq!(
+            columns(&[("use", ""), ("r2.1", "")]).find_rating(3),
+            Some(1)
+        );
+        assert_eq!(
+            columns(&[("rnd", ""), ("rating", "")]).find_rating(3),
+            Some(1)
+        );
+        assert_eq!(columns(&[("rnd", "")]).find_rating(3), None);
+    }
+
+    #[test]
+    fn test_parse_agent_stats_extra_columns() {
+        let labels = [
+            "", "Use", "RND", "Rating", "ACS", "K:D", "ADR", "KAST", "CL%", "KPR", "APR", "FKPR",
+            "FDPR", "K", "D", "A", "FK", "FD",
+        ];
+        let values = [
+            r#"<img alt="jett">"#,
+            "(95) 20%",
+            "2051",
+            "1.12",
+            "245.3",
+            "1.20",
+            "154.1",
+            "72%",
+            "18%",
+            "0.85",
+            "0.21",
+            "0.15",
+            "0.10",
+            "1743",
+            "1452",
+            "431",
+            "308",
+            "205",
+        ];
+        let row = |cells: &[&str]| {
+            cells
+                .iter()
+                .map(|c| format!("<td>{c}</td>"))
+                .collect::<String>()
+        };
+        let document = scraper::Html::parse_document(&format!(
+            r#"<table class="wf-table"><thead><tr>{}</tr></thead><tbody><tr>{}</tr></tbody></table>"#,
+            labels
+                .iter()
+                .map(|l| format!("<th>{l}</th>"))
+                .collect::<String>(),
+            row(&values)
+        ));
+
+        let stats = parse_agent_stats(&document).unwrap();
+        assert_eq!(stats.len(), 1);
+        let jett = &stats[0];
+        assert_eq!(jett.agent, "jett");
+        assert_eq!(jett.usage_count, 95);
+        assert_eq!(jett.kast, 0.72);
+        assert_eq!(jett.clutch_pct, Some(0.18));
+        assert_eq!(jett.first_kill_pct, None);
+        assert_eq!(jett.kpr, 0.85);
+        assert_eq!(jett.first_deaths, 205);
+        assert_eq!(jett.rating_version, None);
+
+        // Without a header the classic positional layout still parses
+        let mut classic = values.to_vec();
+        classic.remove(8);
+        let document = scraper::Html::parse_document(&format!(
+            r#"<table class="wf-table"><tbody><tr>{}</tr></tbody></table>"#,
+            row(&classic)
+        ));
+        let stats = parse_agent_stats(&document).unwrap();
+        assert_eq!(stats[0].kpr, 0.85);
+        assert_eq!(stats[0].clutch_pct, None);
+
+        // A newer rating model's column is still found by its label
+        let mut labels = labels.to_vec();
+        labels[3] = "R2.1";
+        let document = scraper::Html::parse_document(&format!(
+            r#"<table class="wf-table"><thead><tr>{}</tr></thead><tbody><tr>{}</tr></tbody></table>"#,
+            labels
+                .iter()
+                .map(|l| format!("<th>{l}</th>"))
+                .collect::<String>(),
+            row(&values)
+        ));
+        let stats = parse_agent_stats(&document).unwrap();
+        assert_eq!(stats[0].rating, 1.12);
+        assert_eq!(stats[0].rating_version.as_deref(), Some("2.1"));
+
+        // A header without one of the classic columns is an error, not zeroes
+        labels[6] = "DMG";
+        let document = scraper::Html::parse_document(&format!(
+            r#"<table class="wf-table"><thead><tr>{}</tr></thead><tbody><tr>{}</tr></tbody></table>"#,
+            labels
+                .iter()
+                .map(|l| format!("<th>{l}</th>"))
+                .collect::<String>(),
+            row(&values)
+        ));
+        assert!(matches!(
+            parse_agent_stats(&document),
+            Err(VlrError::ElementNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_header_stat() {
+        let texts = ["TenZ", "Matches:", "128", "Win Rate: 56%"];
+        assert_eq!(header_stat(&texts, &["matches"]), Some("128".to_string()));
+        assert_eq!(header_stat(&texts, &["win rate"]), Some("56%".to_string()));
+        assert_eq!(header_stat(&texts, &["rating"]), None);
+    }
+
     #[tokio::test]
     async fn test_parse_player_overview() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let url = "https://www.vlr.gg/player/17323";
         let document = vlr_scraper::get_document(&client, url).await.unwrap();
-        let (info, current_teams, past_teams) = parse_player_overview(&document, 17323).unwrap();
+        let (info, current_teams, past_teams) =
+            parse_player_overview(&document, 17323, ErrorPolicy::Strict).unwrap();
 
         assert_eq!(info.name, "mimi");
         assert_eq!(info.id, 17323);
@@ -633,7 +1065,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_parse_agent_stats() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let url = "https://www.vlr.gg/player/17323?timespan=all";
         let document = vlr_scraper::get_document(&client, url).await.unwrap();
         let stats = parse_agent_stats(&document).unwrap();
@@ -649,9 +1081,19 @@ mod tests {
         assert!(first.deaths > 0);
     }
 
+    #[tokio::test]
+    async fn test_get_player_agent_stats() {
+        let client = HttpClient::default();
+        let stats = get_player_agent_stats(&client, 17323, AgentStatsTimespan::All)
+            .await
+            .unwrap();
+        assert!(!stats.is_empty());
+        assert!(stats.iter().all(|s| !s.agent.is_empty()));
+    }
+
     #[tokio::test]
     async fn test_parse_player_news() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let url = "https://www.vlr.gg/player/17323";
         let document = vlr_scraper::get_document(&client, url).await.unwrap();
         let news = parse_player_news(&document).unwrap();
@@ -665,7 +1107,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_parse_event_placements() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let url = "https://www.vlr.gg/player/17323";
         let document = vlr_scraper::get_document(&client, url).await.unwrap();
         let (placements, total_winnings) = parse_event_placements(&document).unwrap();
@@ -692,8 +1134,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_player() {
-        let client = reqwest::Client::new();
-        let player = get_player(&client, 17323, Default::default())
+        let client = HttpClient::default();
+        let player = get_player(&client, 17323, Default::default(), None)
             .await
             .unwrap();
 
@@ -716,4 +1158,30 @@ mod tests {
         // Event placements
         assert!(!player.event_placements.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_get_player_with_matches() {
+        let client = HttpClient::default();
+        let player = get_player(&client, 17323, Default::default(), Some(2))
+            .await
+            .unwrap();
+
+        assert_eq!(player.info.name, "mimi");
+        assert!(!player.recent_matches.is_empty());
+    }
+
+    // Compile-time assertion that get_player future is Send
+    // This ensures the function can be used in axum handlers
+    #[allow(dead_code)]
+    fn assert_get_player_is_send() {
+        fn check_get_player_send(client: &HttpClient, id: u32) {
+            fn is_send<T: Send>(_: T) {}
+            is_send(get_player(
+                client,
+                id,
+                AgentStatsTimespan::default(),
+                Some(2),
+            ));
+        }
+    }
 }
@@ -1,33 +1,66 @@
 use tracing::{debug, instrument};
 
 use crate::error::Result;
-use crate::model::MatchItem;
-use crate::vlr_scraper::{self, matches};
+use crate::model::{MatchFilter, MatchItem, PaginatedMatchList};
+use crate::vlr_scraper::{self, matches, HttpClient};
 
 #[instrument(skip(client))]
 pub(crate) async fn get_player_matchlist(
-    client: &reqwest::Client,
+    client: &HttpClient,
     player_id: u32,
     page: u8,
-) -> Result<Vec<MatchItem>> {
-    let url = format!("https://www.vlr.gg/player/matches/{player_id}/?page={page}");
+) -> Result<PaginatedMatchList> {
+    let url = client.url(&format!("/player/matches/{player_id}/?page={page}"));
     let document = vlr_scraper::get_document(client, &url).await?;
-    let matches = matches::parse_match_items(&document)?;
+    let items = matches::parse_match_items(&document, client.error_policy)?;
+    let total_pages = matches::parse_total_pages(&document)?;
+    debug!(
+        count = items.len(),
+        player_id, page, total_pages, "parsed player match list"
+    );
+    Ok(PaginatedMatchList {
+        items,
+        page,
+        total_pages,
+    })
+}
+
+/// Walk the first `pages` pages of a player's match history and keep the
+/// matches passing `filter`.
+///
+/// Pages are fetched one after another, stopping after the last page of the
+/// history, so a long history doesn't burst requests at vlr.gg.
+#[instrument(skip(client))]
+pub(crate) async fn get_player_matches_filtered(
+    client: &HttpClient,
+    player_id: u32,
+    pages: u8,
+    filter: MatchFilter,
+) -> Result<Vec<MatchItem>> {
+    let mut matches = vec![];
+    for page in 1..=pages {
+        let list = get_player_matchlist(client, player_id, page).await?;
+        let has_next_page = list.has_next_page();
+        matches.extend(list.into_iter().filter(|m| filter.matches(m)));
+        if !has_next_page {
+            break;
+        }
+    }
     debug!(
         count = matches.len(),
-        player_id, page, "parsed player match list"
+        player_id, "filtered player match list"
     );
     Ok(matches)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "events", feature = "matches"))]
 mod tests {
     use super::*;
-    use crate::model::{EventType, Region};
+    use crate::model::{EventType, MatchResult, Region};
 
     #[tokio::test]
     async fn test_get_player_matchlist() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
 
         let events = crate::vlr_scraper::events::list::get_events(
             &client,
@@ -39,17 +72,31 @@ mod tests {
         .unwrap();
         let event_id = events.events[0].id;
 
-        let matches = crate::vlr_scraper::events::matchlist::get_event_matchlist(&client, event_id)
-            .await
-            .unwrap();
+        let matches =
+            crate::vlr_scraper::events::matchlist::get_event_matchlist(&client, event_id, None)
+                .await
+                .unwrap();
         let match_id = matches[0].id;
 
-        let vlr_match = crate::vlr_scraper::matches::detail::get_match(&client, match_id)
-            .await
-            .unwrap();
+        let vlr_match =
+            crate::vlr_scraper::matches::detail::get_match(&client, match_id, &Default::default())
+                .await
+                .unwrap();
         let player_id = vlr_match.games[0].teams[0].players[0].id;
 
         let player_matchlist = get_player_matchlist(&client, player_id, 1).await.unwrap();
         assert!(!player_matchlist.is_empty());
+        assert_eq!(player_matchlist.page, 1);
+        assert!(player_matchlist.total_pages >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_player_matches_filtered() {
+        let client = HttpClient::default();
+        let filter = MatchFilter::result(MatchResult::Win);
+        let wins = get_player_matches_filtered(&client, 9, 2, filter)
+            .await
+            .unwrap();
+        assert!(wins.iter().all(|m| m.result == Some(MatchResult::Win)));
     }
 }
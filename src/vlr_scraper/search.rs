@@ -0,0 +1,286 @@
+use itertools::Itertools;
+use scraper::ElementRef;
+use tracing::{debug, instrument};
+
+use crate::error::{Result, VlrError};
+#[cfg(feature = "players")]
+use crate::model::PlayerRef;
+#[cfg(feature = "teams")]
+use crate::model::TeamRef;
+use crate::model::{SearchKind, SearchResult};
+#[cfg(feature = "teams")]
+use crate::vlr_scraper::normalize_img_url;
+use crate::vlr_scraper::{self, select_text, HttpClient};
+
+/// A single player or team entry of the search results page.
+#[cfg(any(feature = "players", feature = "teams"))]
+#[derive(Debug, Clone)]
+struct SearchHit {
+    id: u32,
+    slug: String,
+    name: String,
+    #[cfg(feature = "teams")]
+    image_url: Option<String>,
+    /// The `mod-{code}` flag shown next to player results.
+    #[cfg(feature = "players")]
+    flag: Option<String>,
+}
+
+/// Look up a team by name and return the best match (see [`best_match`]).
+#[cfg(feature = "teams")]
+#[instrument(skip(client))]
+pub(crate) async fn resolve_team(client: &HttpClient, name: &str) -> Result<Option<TeamRef>> {
+    let document = search_page(client, name, SearchKind::Teams).await?;
+    let hits = parse_search_results(&document, SearchKind::Teams);
+    Ok(best_match(hits, name).map(|hit| TeamRef {
+        id: hit.id,
+        slug: hit.slug,
+        name: hit.name,
+        logo_url: hit.image_url,
+    }))
+}
+
+/// Look up a player by name and return the best match (see [`best_match`]).
+#[cfg(feature = "players")]
+#[instrument(skip(client))]
+pub(crate) async fn resolve_player(client: &HttpClient, name: &str) -> Result<Option<PlayerRef>> {
+    let document = search_page(client, name, SearchKind::Players).await?;
+    let hits = parse_search_results(&document, SearchKind::Players);
+    Ok(best_match(hits, name).map(|hit| PlayerRef {
+        id: hit.id,
+        name: hit.name,
+        slug: hit.slug,
+        nation: hit.flag.unwrap_or_default(),
+        team_id: None,
+    }))
+}
+
+/// Search vlr.gg for players, teams, and/or events, in page order.
+#[instrument(skip(client))]
+pub(crate) async fn search(
+    client: &HttpClient,
+    query: &str,
+    kind: SearchKind,
+) -> Result<Vec<SearchResult>> {
+    let document = search_page(client, query, kind).await?;
+    let results = parse_search_result_kinds(&document);
+    debug!(count = results.len(), "parsed search results");
+    Ok(results)
+}
+
+async fn search_page(client: &HttpClient, query: &str, kind: SearchKind) -> Result<scraper::Html> {
+    let search_url = client.url("/search/");
+    let url =
+        reqwest::Url::parse_with_params(&search_url, [("q", query), ("type", &kind.to_string())])
+            .map_err(|_| VlrError::InvalidUrl {
+            url: search_url.clone(),
+            expected: "search",
+        })?;
+    vlr_scraper::get_document(client, url.as_str()).await
+}
+
+/// Parse the player, team, and event results, telling them apart by link.
+fn parse_search_result_kinds(document: &scraper::Html) -> Vec<SearchResult> {
+    document
+        .select(selector!("a.search-item"))
+        .filter_map(|item| {
+            let (kind, id, _) = parse_search_link(&item)?;
+            let name = select_text(&item, selector!(".search-item-title"));
+            Some(match kind {
+                SearchKind::Players => SearchResult::Player { id, name },
+                SearchKind::Teams => SearchResult::Team { id, name },
+                SearchKind::Events => SearchResult::Event { id, name },
+                // Never produced by `parse_search_link`
+                SearchKind::All => return None,
+            })
+        })
+        .collect()
+}
+
+/// Parse the result items of one `kind` (players or teams), in page order.
+#[cfg(any(feature = "players", feature = "teams"))]
+fn parse_search_results(document: &scraper::Html, kind: SearchKind) -> Vec<SearchHit> {
+    document
+        .select(selector!("a.search-item"))
+        .filter_map(|item| {
+            let (item_kind, id, slug) = parse_search_link(&item)?;
+            (item_kind == kind).then(|| parse_search_hit(&item, id, slug))
+        })
+        .collect()
+}
+
+/// Read the kind of page, id, and slug from a result item's link, or `None`
+/// when it isn't `/player/`, `/team/`, or `/event/` followed by
+/// `{id}/{slug}`.
+fn parse_search_link<'a>(item: &ElementRef<'a>) -> Option<(SearchKind, u32, &'a str)> {
+    let href = item.value().attr("href")?;
+    let (kind, path) = [
+        ("/player/", SearchKind::Players),
+        ("/team/", SearchKind::Teams),
+        ("/event/", SearchKind::Events),
+    ]
+    .into_iter()
+    .find_map(|(prefix, kind)| Some((kind, href.strip_prefix(prefix)?)))?;
+    let (id, slug) = path.split('/').collect_tuple()?;
+    Some((kind, id.parse().ok()?, slug))
+}
+
+/// Parse a player or team result item whose link was already read.
+#[cfg(any(feature = "players", feature = "teams"))]
+fn parse_search_hit(item: &ElementRef, id: u32, slug: &str) -> SearchHit {
+    #[cfg(feature = "teams")]
+    let image_url = item
+        .select(selector!("img"))
+        .next()
+        .and_then(|img| img.value().attr("src"))
+        .filter(|src| !src.contains("/img/vlr/tmp/"))
+        .map(normalize_img_url);
+    #[cfg(feature = "players")]
+    let flag = item.select(selector!("i.flag")).next().and_then(|f| {
+        f.value()
+            .classes()
+            .find_map(|c| c.strip_prefix("mod-"))
+            .map(str::to_string)
+    });
+    SearchHit {
+        id,
+        slug: slug.to_string(),
+        name: select_text(item, selector!(".search-item-title")),
+        #[cfg(feature = "teams")]
+        image_url,
+        #[cfg(feature = "players")]
+        flag,
+    }
+}
+
+/// Pick the result for `name`: the first whose name equals `name`
+/// (ignoring case and surrounding whitespace), else the first whose slug
+/// matches the name written as a slug (`"Team Liquid"` → `team-liquid`),
+/// else the top result.
+#[cfg(any(feature = "players", feature = "teams"))]
+fn best_match(hits: Vec<SearchHit>, name: &str) -> Option<SearchHit> {
+    let name = name.trim().to_lowercase();
+    let slug = name.split_whitespace().join("-");
+    let exact = hits
+        .iter()
+        .position(|h| h.name.trim().to_lowercase() == name)
+        .or_else(|| hits.iter().position(|h| h.slug == slug))
+        .unwrap_or(0);
+    hits.into_iter().nth(exact)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "players", feature = "teams"))]
+    fn fixture() -> scraper::Html {
+        scraper::Html::parse_document(
+            r#"<div class="wf-card">
+                <a class="wf-module-item search-item" href="/team/6961/loud">
+                    <img src="//owcdn.net/img/loud.png">
+                    <div class="search-item-title">LOUD</div>
+                </a>
+                <a class="wf-module-item search-item" href="/team/2/sentinels">
+                    <img src="//owcdn.net/img/sen.png">
+                    <div class="search-item-title">Sentinels</div>
+                </a>
+                <a class="wf-module-item search-item" href="/player/9/tenz">
+                    <img src="/img/vlr/tmp/vlr.png">
+                    <div class="search-item-title"><i class="flag mod-ca"></i> TenZ</div>
+                </a>
+            </div>"#,
+        )
+    }
+
+    #[cfg(feature = "teams")]
+    #[test]
+    fn test_parse_team_search_results() {
+        let teams = parse_search_results(&fixture(), SearchKind::Teams);
+        assert_eq!(teams.len(), 2);
+        assert_eq!(teams[1].id, 2);
+        assert_eq!(teams[1].slug, "sentinels");
+        assert_eq!(
+            teams[1].image_url.as_deref(),
+            Some("https://owcdn.net/img/sen.png")
+        );
+    }
+
+    #[cfg(feature = "players")]
+    #[test]
+    fn test_parse_player_search_results() {
+        let players = parse_search_results(&fixture(), SearchKind::Players);
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].name, "TenZ");
+        assert_eq!(players[0].slug, "tenz");
+        assert_eq!(players[0].flag.as_deref(), Some("ca"));
+    }
+
+    #[test]
+    fn test_parse_search_result_kinds() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="wf-card">
+                <a class="wf-module-item search-item" href="/event/2097/valorant-champions-2024">
+                    <div class="search-item-title">Valorant Champions 2024</div>
+                </a>
+                <a class="wf-module-item search-item" href="/team/2/sentinels">
+                    <div class="search-item-title">Sentinels</div>
+                </a>
+                <a class="wf-module-item search-item" href="/search/?q=sen&type=players">
+                    <div class="search-item-title">More players</div>
+                </a>
+                <a class="wf-module-item search-item" href="/player/9/tenz">
+                    <div class="search-item-title"><i class="flag mod-ca"></i> TenZ</div>
+                </a>
+            </div>"#,
+        );
+        let results = parse_search_result_kinds(&document);
+        assert_eq!(
+            results,
+            vec![
+                SearchResult::Event {
+                    id: 2097,
+                    name: "Valorant Champions 2024".to_string()
+                },
+                SearchResult::Team {
+                    id: 2,
+                    name: "Sentinels".to_string()
+                },
+                SearchResult::Player {
+                    id: 9,
+                    name: "TenZ".to_string()
+                },
+            ]
+        );
+    }
+
+    #[cfg(any(feature = "players", feature = "teams"))]
+    #[test]
+    fn test_best_match() {
+        let teams = parse_search_results(&fixture(), SearchKind::Teams);
+        let name = |query: &str| best_match(teams.clone(), query).map(|h| h.name);
+
+        assert_eq!(name(" sentinels ").as_deref(), Some("Sentinels"));
+        assert_eq!(name("Sent").as_deref(), Some("LOUD"));
+        assert_eq!(best_match(Vec::new(), "Sentinels").map(|h| h.id), None);
+    }
+
+    #[tokio::test]
+    async fn test_search() {
+        let client = HttpClient::default();
+        let results = search(&client, "Sentinels", SearchKind::Teams)
+            .await
+            .unwrap();
+        assert!(results
+            .iter()
+            .any(|r| matches!(r, SearchResult::Team { id: 2, .. })));
+    }
+
+    #[cfg(feature = "teams")]
+    #[tokio::test]
+    async fn test_resolve_team() {
+        let client = HttpClient::default();
+        let team = resolve_team(&client, "Sentinels").await.unwrap().unwrap();
+        assert_eq!(team.id, 2);
+    }
+}
@@ -0,0 +1,149 @@
+use scraper::{ElementRef, Selector};
+use tracing::{debug, instrument};
+
+use super::stats_region_code;
+use crate::error::{Result, VlrError};
+use crate::model::{AgentMapStat, AgentMetaStat, AgentStatsTimespan, Region};
+use crate::vlr_scraper::{self, normalize_img_url, normalize_number, HttpClient};
+
+#[instrument(skip(client), fields(region = %region, timespan = %timespan))]
+pub(crate) async fn get_agent_stats(
+    client: &HttpClient,
+    region: Region,
+    timespan: AgentStatsTimespan,
+) -> Result<Vec<AgentMetaStat>> {
+    let region_code = stats_region_code(&region);
+    let url = client.url(&format!(
+        "/stats/agents/?region={region_code}&timespan={timespan}"
+    ));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let stats = parse_agent_stats(&document)?;
+    debug!(count = stats.len(), "parsed agent stats");
+    Ok(stats)
+}
+
+/// Parse the agent pick-rate table: one column per agent, one row per map,
+/// with the "All Maps" row holding the overall pick rate. Non-agent columns
+/// (map, games played, side win rates) are skipped.
+fn parse_agent_stats(document: &scraper::Html) -> Result<Vec<AgentMetaStat>> {
+    let table_selector = Selector::parse("table.wf-table")?;
+    let header_selector = Selector::parse("tr th")?;
+    let img_selector = Selector::parse("img")?;
+    let row_selector = Selector::parse("tr:has(td)")?;
+    let td_selector = Selector::parse("td")?;
+
+    let table = document
+        .select(&table_selector)
+        .next()
+        .ok_or(VlrError::ElementNotFound {
+            context: "agent stats table (table.wf-table)",
+        })?;
+
+    // Agent columns are the header cells carrying an agent icon; remember
+    // their position so body cells can be matched up by index
+    let mut agents: Vec<(usize, AgentMetaStat)> = table
+        .select(&header_selector)
+        .enumerate()
+        .filter_map(|(i, th)| {
+            let img = th.select(&img_selector).next()?;
+            let agent = img
+                .value()
+                .attr("title")
+                .or_else(|| img.value().attr("alt"))?
+                .trim()
+                .to_string();
+            let icon_url = normalize_img_url(img.value().attr("src").unwrap_or_default());
+            Some((
+                i,
+                AgentMetaStat {
+                    agent,
+                    icon_url,
+                    pick_rate: None,
+                    maps: Vec::new(),
+                },
+            ))
+        })
+        .collect();
+
+    for row in table.select(&row_selector) {
+        let cells: Vec<ElementRef> = row.select(&td_selector).collect();
+        let map = cells.first().map(cell_text).unwrap_or_default();
+        if map.is_empty() {
+            continue;
+        }
+        let is_overall = map.to_lowercase().starts_with("all");
+        for (i, stat) in agents.iter_mut() {
+            let pick_rate = cells.get(*i).and_then(|c| parse_pick_rate(&cell_text(c)));
+            if is_overall {
+                stat.pick_rate = pick_rate;
+            } else {
+                stat.maps.push(AgentMapStat {
+                    map: map.clone(),
+                    pick_rate,
+                });
+            }
+        }
+    }
+
+    Ok(agents.into_iter().map(|(_, stat)| stat).collect())
+}
+
+fn cell_text(cell: &ElementRef) -> String {
+    cell.text()
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse a pick rate like "45%" into a fraction (0.45). Empty cells (agent
+/// never picked) yield `None`.
+fn parse_pick_rate(text: &str) -> Option<f32> {
+    normalize_number(text)
+        .strip_suffix('%')
+        .and_then(|s| s.parse::<f32>().ok())
+        .map(|p| p / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_agent_stats_table() {
+        let document = scraper::Html::parse_document(
+            r#"<table class="wf-table mod-pr-global">
+                <tr>
+                    <th>Map</th><th>#</th>
+                    <th><img src="/img/vlr/game/agents/jett.png" title="Jett"></th>
+                    <th><img src="/img/vlr/game/agents/omen.png" title="Omen"></th>
+                </tr>
+                <tr><td>All Maps</td><td>120</td><td>45%</td><td>30%</td></tr>
+                <tr><td>Ascent</td><td>20</td><td>60%</td><td></td></tr>
+            </table>"#,
+        );
+        let stats = parse_agent_stats(&document).unwrap();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].agent, "Jett");
+        assert_eq!(
+            stats[0].icon_url,
+            "https://www.vlr.gg/img/vlr/game/agents/jett.png"
+        );
+        assert_eq!(stats[0].pick_rate, Some(0.45));
+        assert_eq!(stats[0].maps.len(), 1);
+        assert_eq!(stats[0].maps[0].map, "Ascent");
+        assert_eq!(stats[0].maps[0].pick_rate, Some(0.6));
+        assert_eq!(stats[1].pick_rate, Some(0.3));
+        assert_eq!(stats[1].maps[0].pick_rate, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_agent_stats() {
+        let client = HttpClient::default();
+        let stats = get_agent_stats(&client, Region::All, AgentStatsTimespan::Days30)
+            .await
+            .unwrap();
+        assert!(!stats.is_empty(), "should have agent columns");
+        assert!(stats.iter().all(|s| !s.agent.is_empty()));
+    }
+}
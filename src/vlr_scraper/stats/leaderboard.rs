@@ -0,0 +1,230 @@
+use scraper::{ElementRef, Selector};
+use tracing::{debug, instrument};
+
+use super::stats_region_code;
+use crate::error::{Result, VlrError};
+use crate::model::{AgentStatsTimespan, Region, StatLeaderboardRow};
+use crate::vlr_scraper::{self, normalize_number, select_text, HttpClient};
+
+#[instrument(skip(client), fields(region = %region, timespan = %timespan))]
+pub(crate) async fn get_stats(
+    client: &HttpClient,
+    region: Region,
+    timespan: AgentStatsTimespan,
+    min_rounds: u32,
+) -> Result<Vec<StatLeaderboardRow>> {
+    let region_code = stats_region_code(&region);
+    let url = client.url(&format!(
+        "/stats/?region={region_code}&timespan={timespan}&min_rounds={min_rounds}"
+    ));
+    let document = vlr_scraper::get_document(client, &url).await?;
+    let rows = parse_stats(&document)?;
+    debug!(count = rows.len(), "parsed stats leaderboard");
+    Ok(rows)
+}
+
+/// Positions of the stat columns, looked up by header label so the parser
+/// survives columns being added or reordered.
+struct LeaderboardColumns(Vec<String>);
+
+impl LeaderboardColumns {
+    fn parse(table: &ElementRef) -> Self {
+        let header_selector = selector!("thead th");
+        let labels = table
+            .select(header_selector)
+            .map(|th| th.text().collect::<String>().trim().to_lowercase())
+            .collect();
+        Self(labels)
+    }
+
+    fn position(&self, labels: &[&str]) -> Option<usize> {
+        self.0.iter().position(|l| labels.contains(&l.as_str()))
+    }
+}
+
+/// Parse the player stats table. Rows without a player link are skipped.
+fn parse_stats(document: &scraper::Html) -> Result<Vec<StatLeaderboardRow>> {
+    let table_selector = Selector::parse("table.wf-table")?;
+    let row_selector = Selector::parse("tbody tr")?;
+    let td_selector = Selector::parse("td")?;
+    let player_selector = Selector::parse("td.mod-player a")?;
+    let name_selector = Selector::parse("div.text-of")?;
+    let team_selector = Selector::parse("div.stats-player-country")?;
+    let agent_selector = Selector::parse("td.mod-agents img")?;
+
+    let table = document
+        .select(&table_selector)
+        .next()
+        .ok_or(VlrError::ElementNotFound {
+            context: "stats leaderboard table (table.wf-table)",
+        })?;
+    let columns = LeaderboardColumns::parse(&table);
+    let rounds_col = columns.position(&["rnd", "rounds"]);
+    let rating_col = columns.position(&["r2.0", "r", "rating"]);
+    let acs_col = columns.position(&["acs"]);
+    let kd_col = columns.position(&["k:d", "kd"]);
+    let kast_col = columns.position(&["kast"]);
+    let adr_col = columns.position(&["adr"]);
+    let kpr_col = columns.position(&["kpr"]);
+    let apr_col = columns.position(&["apr"]);
+    let fkpr_col = columns.position(&["fkpr"]);
+    let fdpr_col = columns.position(&["fdpr"]);
+    let hs_col = columns.position(&["hs%"]);
+    let clutch_col = columns.position(&["cl%"]);
+
+    let rows = table
+        .select(&row_selector)
+        .filter_map(|row| {
+            let player = row.select(&player_selector).next()?;
+            let player_id = player
+                .value()
+                .attr("href")?
+                .strip_prefix("/player/")?
+                .split('/')
+                .next()?
+                .parse()
+                .ok()?;
+            let player_name = select_text(&player, &name_selector);
+            let team = Some(select_text(&player, &team_selector)).filter(|t| !t.is_empty());
+            let agents = row
+                .select(&agent_selector)
+                .filter_map(|img| agent_name(&img))
+                .collect();
+
+            let cells: Vec<String> = row
+                .select(&td_selector)
+                .map(|td| normalize_number(&td.text().collect::<String>()))
+                .collect();
+            let cell = |col: Option<usize>| col.and_then(|i| cells.get(i)).map(String::as_str);
+            let number = |col| cell(col).and_then(|t| t.parse::<f32>().ok());
+            let percent = |col| cell(col).and_then(parse_percent);
+
+            Some(StatLeaderboardRow {
+                player_id,
+                player_name,
+                team,
+                agents,
+                rounds: cell(rounds_col)
+                    .and_then(|t| t.parse().ok())
+                    .unwrap_or_default(),
+                rating: number(rating_col),
+                acs: number(acs_col),
+                kd: number(kd_col),
+                kast: percent(kast_col),
+                adr: number(adr_col),
+                kpr: number(kpr_col),
+                apr: number(apr_col),
+                fkpr: number(fkpr_col),
+                fdpr: number(fdpr_col),
+                hs_pct: percent(hs_col),
+                clutch_pct: percent(clutch_col),
+            })
+        })
+        .collect();
+    Ok(rows)
+}
+
+/// Agent name from an agent icon: its title or alt text, falling back to the
+/// file name (`/img/vlr/game/agents/jett.png` -> "jett").
+fn agent_name(img: &ElementRef) -> Option<String> {
+    let value = img.value();
+    value
+        .attr("title")
+        .or_else(|| value.attr("alt"))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .or_else(|| value.attr("src")?.rsplit('/').next()?.split('.').next())
+        .map(str::to_string)
+}
+
+/// Parse a percentage like "75%" into a fraction (0.75). Empty cells yield
+/// `None`.
+fn parse_percent(text: &str) -> Option<f32> {
+    text.strip_suffix('%')
+        .and_then(|s| s.parse::<f32>().ok())
+        .map(|p| p / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stats_table() {
+        let document = scraper::Html::parse_document(
+            r#"<table class="wf-table mod-stats mod-scroll">
+                <thead><tr>
+                    <th>Player</th><th>Agents</th><th>Rnd</th><th>R2.0</th><th>ACS</th>
+                    <th>K:D</th><th>KAST</th><th>ADR</th><th>KPR</th><th>APR</th>
+                    <th>FKPR</th><th>FDPR</th><th>HS%</th><th>CL%</th>
+                </tr></thead>
+                <tbody>
+                    <tr>
+                        <td class="mod-player"><a href="/player/9/tenz">
+                            <div class="text-of">TenZ</div>
+                            <div class="stats-player-country">SEN</div>
+                        </a></td>
+                        <td class="mod-agents">
+                            <img src="/img/vlr/game/agents/jett.png">
+                            <img src="/img/vlr/game/agents/raze.png" title="Raze">
+                        </td>
+                        <td>412</td><td>1.21</td><td>245.3</td><td>1.32</td><td>74%</td>
+                        <td>158.2</td><td>0.85</td><td>0.21</td><td>0.16</td><td>0.11</td>
+                        <td>27%</td><td>18%</td>
+                    </tr>
+                    <tr>
+                        <td class="mod-player"><a href="/player/29/zekken">
+                            <div class="text-of">zekken</div>
+                            <div class="stats-player-country"></div>
+                        </a></td>
+                        <td class="mod-agents"></td>
+                        <td>1,024</td><td>1.05</td><td>210</td><td>1.08</td><td>70%</td>
+                        <td>140</td><td>0.75</td><td>0.3</td><td>0.12</td><td>0.1</td>
+                        <td>22%</td><td></td>
+                    </tr>
+                </tbody>
+            </table>"#,
+        );
+        let rows = parse_stats(&document).unwrap();
+        assert_eq!(rows.len(), 2);
+
+        let tenz = &rows[0];
+        assert_eq!(tenz.player_id, 9);
+        assert_eq!(tenz.player_name, "TenZ");
+        assert_eq!(tenz.team.as_deref(), Some("SEN"));
+        assert_eq!(tenz.agents, vec!["jett", "Raze"]);
+        assert_eq!(tenz.rounds, 412);
+        assert_eq!(tenz.rating, Some(1.21));
+        assert_eq!(tenz.acs, Some(245.3));
+        assert_eq!(tenz.kd, Some(1.32));
+        assert_eq!(tenz.kast, Some(0.74));
+        assert_eq!(tenz.adr, Some(158.2));
+        assert_eq!(tenz.kpr, Some(0.85));
+        assert_eq!(tenz.apr, Some(0.21));
+        assert_eq!(tenz.fkpr, Some(0.16));
+        assert_eq!(tenz.fdpr, Some(0.11));
+        assert_eq!(tenz.hs_pct, Some(0.27));
+        assert_eq!(tenz.clutch_pct, Some(0.18));
+
+        let zekken = &rows[1];
+        assert_eq!(zekken.team, None);
+        assert!(zekken.agents.is_empty());
+        assert_eq!(zekken.rounds, 1024);
+        assert_eq!(zekken.clutch_pct, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_stats() {
+        let client = HttpClient::default();
+        let rows = get_stats(
+            &client,
+            Region::NorthAmerica,
+            AgentStatsTimespan::Days60,
+            200,
+        )
+        .await
+        .unwrap();
+        assert!(!rows.is_empty(), "should have leaderboard rows");
+        assert!(rows.iter().all(|r| r.player_id > 0 && r.rounds >= 200));
+    }
+}
@@ -0,0 +1,23 @@
+pub(crate) mod agents;
+pub(crate) mod leaderboard;
+
+use crate::model::Region;
+
+/// Map a [`Region`] to the region code used by the `/stats` pages, which
+/// differs from the slug used by the events listing.
+pub(crate) fn stats_region_code(region: &Region) -> &'static str {
+    match region {
+        Region::All => "all",
+        Region::NorthAmerica => "na",
+        Region::Europe => "eu",
+        Region::Brazil => "br",
+        Region::AsiaPacific => "ap",
+        Region::Korea => "kr",
+        Region::Japan => "jp",
+        Region::LatinAmerica => "la",
+        Region::Oceania => "oce",
+        Region::MiddleEastNorthAfrica => "mn",
+        Region::GameChangers => "gc",
+        Region::Collegiate => "col",
+    }
+}
@@ -1,27 +1,42 @@
+use chrono::NaiveDate;
 use itertools::Itertools;
-use scraper::Selector;
+use scraper::{CaseSensitivity, ElementRef, Selector};
 use tracing::{debug, instrument};
 
 use crate::error::Result;
-use crate::model::{EventPlacement, PlacementEntry, Social, Team, TeamInfo, TeamRosterMember};
-use crate::vlr_scraper::{self, infer_platform, normalize_img_url, select_text};
+use crate::model::{
+    EventPlacement, MatchItem, Money, PlacementEntry, Social, Team, TeamInfo, TeamRankingPoint,
+    TeamRole, TeamRosterMember, TeamYearEarnings, SCHEMA_VERSION,
+};
+use crate::vlr_scraper::{
+    self, infer_platform, matches, name_or_alt, normalize_img_url, parse_follower_count,
+    select_text, HttpClient,
+};
 
 #[instrument(skip(client))]
-pub(crate) async fn get_team(client: &reqwest::Client, team_id: u32) -> Result<Team> {
-    let url = format!("https://www.vlr.gg/team/{team_id}");
+pub(crate) async fn get_team(client: &HttpClient, team_id: u32) -> Result<Team> {
+    let url = client.url(&format!("/team/{team_id}"));
     let document = vlr_scraper::get_document(client, &url).await?;
 
     let info = parse_team_header(&document, team_id)?;
     let roster = parse_roster(&document)?;
     let (event_placements, total_winnings) = parse_event_placements(&document)?;
+    let earnings_by_year = parse_earnings_by_year(&document)?;
+    let next_match = parse_next_match(&document)?;
+    let ranking_history = parse_ranking_history(&document)?;
 
     debug!(team_id, name = %info.name, "parsed team profile");
 
     Ok(Team {
+        schema_version: SCHEMA_VERSION,
         info,
         roster,
         event_placements,
+        total_winnings_money: total_winnings.as_deref().and_then(|w| w.parse().ok()),
         total_winnings,
+        earnings_by_year,
+        next_match,
+        ranking_history,
     })
 }
 
@@ -48,13 +63,15 @@ fn parse_team_header(document: &scraper::Html, team_id: u32) -> Result<TeamInfo>
         }
     };
 
-    // Logo from .team-header-logo img
+    // Logo from .team-header-logo img, skipping a dark-mode variant
     let logo_selector = Selector::parse(".team-header-logo img")?;
     let logo_url = header
         .select(&logo_selector)
-        .next()
+        .find(|e| !is_dark_variant(e))
         .and_then(|e| e.value().attr("src"))
         .map(normalize_img_url);
+    let logo_url_dark = parse_dark_logo(&header)?.filter(|dark| Some(dark) != logo_url.as_ref());
+    let name = name_or_alt(name, &header, &logo_selector);
 
     // Country text from .team-header-country
     let country_selector = Selector::parse(".team-header-country")?;
@@ -97,20 +114,29 @@ fn parse_team_header(document: &scraper::Html, team_id: u32) -> Result<TeamInfo>
                 .unwrap_or_default()
                 .trim()
                 .to_string();
-            let display_text: String = a
+            // A follower count, when shown, is a separate text node in the link
+            let texts: Vec<&str> = a
                 .text()
                 .map(|t| t.trim())
                 .filter(|t| !t.is_empty())
+                .collect();
+            let followers = texts.iter().find_map(|t| parse_follower_count(t));
+            let display_text: String = texts
+                .iter()
+                .filter(|t| parse_follower_count(t).is_none())
+                .copied()
                 .collect::<Vec<_>>()
                 .join("");
             if href.is_empty() || display_text.is_empty() {
                 return None;
             }
-            let platform = infer_platform(&href);
+            let platform_kind = infer_platform(&href);
             Some(Social {
-                platform,
+                platform: platform_kind.to_string(),
+                platform_kind,
                 url: href,
                 display_text,
+                followers,
             })
         })
         .collect();
@@ -120,12 +146,44 @@ fn parse_team_header(document: &scraper::Html, team_id: u32) -> Result<TeamInfo>
         name,
         tag,
         logo_url,
+        logo_url_dark,
         country,
         country_code,
         socials,
     })
 }
 
+/// Whether a logo image is marked as the dark-mode variant.
+fn is_dark_variant(img: &ElementRef) -> bool {
+    img.value()
+        .classes()
+        .any(|c| c == "mod-dark" || c == "dark")
+}
+
+/// The dark-mode logo, from a `<picture>` source for `prefers-color-scheme:
+/// dark`, a `data-dark-src` attribute, or a second image marked `mod-dark`.
+fn parse_dark_logo(header: &ElementRef) -> Result<Option<String>> {
+    let source_selector = Selector::parse(".team-header-logo source[media][srcset]")?;
+    let from_source = header
+        .select(&source_selector)
+        .find(|s| s.value().attr("media").is_some_and(|m| m.contains("dark")))
+        .and_then(|s| s.value().attr("srcset"))
+        .and_then(|set| set.split([',', ' ']).find(|u| !u.is_empty()));
+
+    let img_selector = Selector::parse(".team-header-logo img")?;
+    let from_img = || {
+        header.select(&img_selector).find_map(|img| {
+            img.value().attr("data-dark-src").or_else(|| {
+                is_dark_variant(&img)
+                    .then(|| img.value().attr("src"))
+                    .flatten()
+            })
+        })
+    };
+
+    Ok(from_source.or_else(from_img).map(normalize_img_url))
+}
+
 fn parse_roster(document: &scraper::Html) -> Result<Vec<TeamRosterMember>> {
     let item_selector = Selector::parse(".team-roster-item")?;
     let link_selector = Selector::parse("a[href]")?;
@@ -221,6 +279,9 @@ fn parse_roster(document: &scraper::Html) -> Result<Vec<TeamRosterMember>> {
                 }
             };
 
+            let is_igl = is_igl_role(&role);
+            let role_kind = parse_team_role(&role);
+
             Some(TeamRosterMember {
                 id,
                 slug,
@@ -230,7 +291,9 @@ fn parse_roster(document: &scraper::Html) -> Result<Vec<TeamRosterMember>> {
                 country_code,
                 avatar_url,
                 role,
+                role_kind,
                 is_captain,
+                is_igl,
             })
         })
         .collect();
@@ -238,6 +301,34 @@ fn parse_roster(document: &scraper::Html) -> Result<Vec<TeamRosterMember>> {
     Ok(roster)
 }
 
+/// Whether a roster role text (e.g. `"IGL"`, `"in-game leader"`) marks the
+/// in-game leader.
+fn is_igl_role(role: &str) -> bool {
+    let role = role.to_lowercase();
+    role.split(|c: char| !c.is_alphanumeric())
+        .any(|word| word == "igl")
+        || role.contains("in-game leader")
+        || role.contains("in game leader")
+}
+
+/// Classify a roster role text. The in-game leader counts as a player.
+fn parse_team_role(role: &str) -> TeamRole {
+    let text = role.trim().to_lowercase();
+    if text.is_empty() || text == "player" || is_igl_role(&text) {
+        TeamRole::Player
+    } else if text.contains("coach") {
+        TeamRole::Coach
+    } else if text.contains("manager") {
+        TeamRole::Manager
+    } else if text.contains("analyst") {
+        TeamRole::Analyst
+    } else if text == "sub" || text.contains("substitute") {
+        TeamRole::Substitute
+    } else {
+        TeamRole::Other(role.trim().to_string())
+    }
+}
+
 fn parse_event_placements(
     document: &scraper::Html,
 ) -> Result<(Vec<EventPlacement>, Option<String>)> {
@@ -343,6 +434,7 @@ fn parse_event_placements(
             let entry = PlacementEntry {
                 stage,
                 placement,
+                prize_money: prize.as_deref().and_then(|p| p.parse().ok()),
                 prize,
                 team_name: None,
             };
@@ -361,13 +453,290 @@ fn parse_event_placements(
     Ok((placements, total_winnings))
 }
 
+/// Parse the first entry of the "Upcoming Matches" card, if the team has one.
+fn parse_next_match(document: &scraper::Html) -> Result<Option<MatchItem>> {
+    let label_selector = Selector::parse("h2.wf-label")?;
+    let item_selector = Selector::parse("a.m-item, a.wf-module-item")?;
+
+    let card = document
+        .select(&label_selector)
+        .find(|el| {
+            el.text()
+                .map(|t| t.trim())
+                .collect::<String>()
+                .contains("Upcoming")
+        })
+        .and_then(|label| label.next_siblings().filter_map(ElementRef::wrap).next());
+
+    card.and_then(|c| c.select(&item_selector).next())
+        .map(matches::parse_match_item)
+        .transpose()
+}
+
+/// Parse the per-year earnings breakdown shown in the same card as "Total Winnings".
+fn parse_earnings_by_year(document: &scraper::Html) -> Result<Vec<TeamYearEarnings>> {
+    let winnings_label_selector = Selector::parse("div.wf-module-label")?;
+    let row_selector = Selector::parse("tr, div.wf-module-item")?;
+    let cell_selector = Selector::parse("td, div, span")?;
+
+    let card = document
+        .select(&winnings_label_selector)
+        .find(|el| {
+            el.text()
+                .map(|t| t.trim())
+                .collect::<String>()
+                .contains("Total Winnings")
+        })
+        .and_then(|label| {
+            label.ancestors().filter_map(ElementRef::wrap).find(|e| {
+                e.value()
+                    .has_class("wf-card", CaseSensitivity::CaseSensitive)
+            })
+        });
+
+    let card = match card {
+        Some(c) => c,
+        None => return Ok(Vec::new()),
+    };
+
+    let earnings = card
+        .select(&row_selector)
+        .filter_map(|row| {
+            // Leaf cells only, so nested wrappers don't repeat their children's text
+            let cells: Vec<String> = row
+                .select(&cell_selector)
+                .filter(|c| c.children().filter_map(ElementRef::wrap).next().is_none())
+                .map(|c| {
+                    c.text()
+                        .map(|t| t.trim())
+                        .filter(|t| !t.is_empty())
+                        .collect::<String>()
+                })
+                .filter(|t| !t.is_empty())
+                .collect();
+
+            let year = cells
+                .iter()
+                .find(|t| t.len() == 4 && t.chars().all(|c| c.is_ascii_digit()))?
+                .clone();
+            let amount = cells
+                .iter()
+                .find(|t| **t != year && t.parse::<Money>().is_ok())?
+                .clone();
+
+            Some(TeamYearEarnings {
+                amount_money: amount.parse().ok(),
+                year,
+                amount,
+            })
+        })
+        .unique_by(|e| e.year.clone())
+        .collect();
+
+    Ok(earnings)
+}
+
+/// Parse the data points embedded in the team's ranking chart: elements
+/// inside the chart carrying `data-date` (`YYYY-MM-DD`) and `data-rank`
+/// attributes. Points with a malformed date or rank are skipped.
+fn parse_ranking_history(document: &scraper::Html) -> Result<Vec<TeamRankingPoint>> {
+    let point_selector = Selector::parse(
+        ":is(.team-rating-chart, .team-rank-chart, .team-ranking-history) [data-date][data-rank]",
+    )?;
+    let points = document
+        .select(&point_selector)
+        .filter_map(|point| {
+            let date = point.value().attr("data-date")?.trim();
+            let rank = point.value().attr("data-rank")?.trim();
+            Some(TeamRankingPoint {
+                date: NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?,
+                rank: rank.trim_start_matches('#').parse().ok()?,
+            })
+        })
+        .sorted_by_key(|p| p.date)
+        .dedup_by(|a, b| a.date == b.date)
+        .collect();
+    Ok(points)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_ranking_history() {
+        let document = scraper::Html::parse_document(
+            r##"<div class="wf-card team-rating-chart">
+                <div class="point" data-date="2024-03-01" data-rank="4"></div>
+                <div class="point" data-date="2024-01-01" data-rank="#12"></div>
+                <div class="point" data-date="2024-02-01" data-rank="unranked"></div>
+            </div>"##,
+        );
+        let history = parse_ranking_history(&document).unwrap();
+        assert_eq!(
+            history,
+            vec![
+                TeamRankingPoint {
+                    date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                    rank: 12,
+                },
+                TeamRankingPoint {
+                    date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                    rank: 4,
+                },
+            ]
+        );
+
+        let document = scraper::Html::parse_document(r#"<div class="team-header"></div>"#);
+        assert!(parse_ranking_history(&document).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_next_match() {
+        let document = scraper::Html::parse_document(
+            r#"<h2 class="wf-label">Upcoming Matches</h2>
+            <div class="wf-card">
+                <a class="m-item" href="/427991/sentinels-vs-cloud9-champions-tour-2025">
+                    <div class="m-item-event"><div>Champions Tour 2025: Americas</div>Week 3</div>
+                    <div class="m-item-team"><span class="m-item-team-name">Sentinels</span></div>
+                    <div class="m-item-logo"><img src="/img/sen.png"></div>
+                    <div class="m-item-result"><span></span></div>
+                </a>
+                <a class="m-item" href="/427999/sentinels-vs-nrg-champions-tour-2025"></a>
+            </div>
+            <h2 class="wf-label">Recent Results</h2>
+            <div class="wf-card"></div>"#,
+        );
+        let next = parse_next_match(&document).unwrap().unwrap();
+        assert_eq!(next.id, 427991);
+        assert_eq!(next.league_name, "Champions Tour 2025: Americas");
+
+        let document = scraper::Html::parse_document(
+            r#"<h2 class="wf-label">Recent Results</h2><div class="wf-card"></div>"#,
+        );
+        assert!(parse_next_match(&document).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_earnings_by_year() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="wf-card">
+                <div class="wf-module-label">Total Winnings</div>
+                <span>$2,151,000</span>
+                <table class="wf-table">
+                    <tr><td><div>2024</div></td><td><span>$1,046,000</span></td></tr>
+                    <tr><td><div>2023</div></td><td><span>$1,105,000</span></td></tr>
+                    <tr><td><div>2023</div></td><td><span>$1,105,000</span></td></tr>
+                    <tr><td>Placements</td><td>12</td></tr>
+                </table>
+            </div>
+            <div class="wf-card"><table><tr><td>2022</td><td>$50,000</td></tr></table></div>"#,
+        );
+        let earnings = parse_earnings_by_year(&document).unwrap();
+        let years = earnings
+            .iter()
+            .map(|e| (e.year.as_str(), e.amount.as_str()))
+            .collect_vec();
+        assert_eq!(years, [("2024", "$1,046,000"), ("2023", "$1,105,000")]);
+        assert_eq!(
+            earnings[0].amount_money.map(|m| m.amount),
+            Some(1_046_000.0)
+        );
+
+        let document = scraper::Html::parse_document(
+            r#"<div class="wf-card"><table><tr><td>2022</td><td>$50,000</td></tr></table></div>"#,
+        );
+        assert!(parse_earnings_by_year(&document).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_team_header_dark_logo() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="team-header">
+                <div class="team-header-logo">
+                    <img src="//owcdn.net/img/sen.png">
+                    <img class="mod-dark" src="//owcdn.net/img/sen-dark.png">
+                </div>
+                <h1 class="wf-title">Sentinels</h1>
+            </div>"#,
+        );
+        let info = parse_team_header(&document, 2).unwrap();
+        assert_eq!(
+            info.logo_url.as_deref(),
+            Some("https://owcdn.net/img/sen.png")
+        );
+        assert_eq!(
+            info.logo_url_dark.as_deref(),
+            Some("https://owcdn.net/img/sen-dark.png")
+        );
+
+        let document = scraper::Html::parse_document(
+            r#"<div class="team-header">
+                <div class="team-header-logo"><picture>
+                    <source media="(prefers-color-scheme: dark)" srcset="//owcdn.net/img/fnc-dark.png 1x">
+                    <img src="//owcdn.net/img/fnc.png">
+                </picture></div>
+                <h1 class="wf-title">FNATIC</h1>
+            </div>"#,
+        );
+        let info = parse_team_header(&document, 2593).unwrap();
+        assert_eq!(
+            info.logo_url_dark.as_deref(),
+            Some("https://owcdn.net/img/fnc-dark.png")
+        );
+
+        let document = scraper::Html::parse_document(
+            r#"<div class="team-header">
+                <div class="team-header-logo"><img src="//owcdn.net/img/nrg.png"></div>
+                <h1 class="wf-title">NRG</h1>
+            </div>"#,
+        );
+        let info = parse_team_header(&document, 1034).unwrap();
+        assert!(info.logo_url.is_some());
+        assert_eq!(info.logo_url_dark, None);
+    }
+
+    #[test]
+    fn test_parse_team_header_name_from_logo_alt() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="team-header">
+                <div class="team-header-logo"><img src="//owcdn.net/img/sen.png" alt="Sentinels"></div>
+                <div class="team-header-name"><h1>Sentinels</h1></div>
+            </div>"#,
+        );
+        let info = parse_team_header(&document, 2).unwrap();
+        assert_eq!(info.name, "Sentinels");
+    }
+
+    #[test]
+    fn test_is_igl_role() {
+        assert!(is_igl_role("IGL"));
+        assert!(is_igl_role("player / igl"));
+        assert!(is_igl_role("In-Game Leader"));
+        assert!(!is_igl_role("player"));
+        assert!(!is_igl_role("head coach"));
+        assert!(!is_igl_role("sigle"));
+    }
+
+    #[test]
+    fn test_parse_team_role() {
+        assert_eq!(parse_team_role("player"), TeamRole::Player);
+        assert_eq!(parse_team_role(""), TeamRole::Player);
+        assert_eq!(parse_team_role("IGL"), TeamRole::Player);
+        assert_eq!(parse_team_role("head coach"), TeamRole::Coach);
+        assert_eq!(parse_team_role("Assistant Coach"), TeamRole::Coach);
+        assert_eq!(parse_team_role("manager"), TeamRole::Manager);
+        assert_eq!(parse_team_role("performance analyst"), TeamRole::Analyst);
+        assert_eq!(parse_team_role("Sub"), TeamRole::Substitute);
+        assert_eq!(
+            parse_team_role("Content Creator"),
+            TeamRole::Other("Content Creator".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn test_get_team() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let team = get_team(&client, 6530).await.unwrap();
 
         // Team info
@@ -400,5 +769,11 @@ mod tests {
             !team.total_winnings.as_ref().unwrap().is_empty(),
             "expected total_winnings to be non-empty"
         );
+
+        // Any per-year breakdown rows carry a year and a parsed amount
+        for earnings in &team.earnings_by_year {
+            assert_eq!(earnings.year.len(), 4);
+            assert!(earnings.amount_money.is_some());
+        }
     }
 }
@@ -1,23 +1,28 @@
 use tracing::{debug, instrument};
 
 use crate::error::Result;
-use crate::model::MatchItem;
-use crate::vlr_scraper::{self, matches};
+use crate::model::TeamMatchListPage;
+use crate::vlr_scraper::{self, matches, HttpClient};
 
 #[instrument(skip(client))]
 pub(crate) async fn get_team_matchlist(
-    client: &reqwest::Client,
+    client: &HttpClient,
     team_id: u32,
     page: u8,
-) -> Result<Vec<MatchItem>> {
-    let url = format!("https://www.vlr.gg/team/matches/{team_id}/?page={page}");
+) -> Result<TeamMatchListPage> {
+    let url = client.url(&format!("/team/matches/{team_id}/?page={page}"));
     let document = vlr_scraper::get_document(client, &url).await?;
-    let matches = matches::parse_match_items(&document)?;
+    let items = matches::parse_match_items(&document, client.error_policy)?;
+    let total_pages = matches::parse_total_pages(&document)?;
     debug!(
-        count = matches.len(),
-        team_id, page, "parsed team match list"
+        count = items.len(),
+        team_id, page, total_pages, "parsed team match list"
     );
-    Ok(matches)
+    Ok(TeamMatchListPage {
+        items,
+        page,
+        total_pages,
+    })
 }
 
 #[cfg(test)]
@@ -26,7 +31,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_team_matchlist() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let matches = get_team_matchlist(&client, 6530, 1).await.unwrap();
 
         assert!(!matches.is_empty());
@@ -41,9 +46,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_team_matchlist_page2() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::default();
         let matches = get_team_matchlist(&client, 6530, 2).await.unwrap();
 
         assert!(!matches.is_empty());
+        assert_eq!(matches.page, 2);
+        assert!(matches.total_pages >= 2);
     }
 }
@@ -1,3 +1,4 @@
 pub(crate) mod info;
 pub(crate) mod matchlist;
+pub(crate) mod rankings;
 pub(crate) mod transactions;
@@ -0,0 +1,219 @@
+use scraper::{ElementRef, Selector};
+use tracing::{debug, instrument};
+
+use crate::error::Result;
+use crate::model::{RankedTeam, Region};
+use crate::vlr_scraper::{self, normalize_img_url, normalize_number, select_text, HttpClient};
+
+#[instrument(skip(client))]
+pub(crate) async fn get_rankings(client: &HttpClient, region: Region) -> Result<Vec<RankedTeam>> {
+    let paths = rankings_paths(&region);
+    let mut teams = Vec::new();
+    for path in paths {
+        let document = vlr_scraper::get_document(client, &client.url(path)).await?;
+        teams.extend(parse_rankings(&document)?);
+    }
+    if paths.len() > 1 {
+        merge_rankings(&mut teams);
+    }
+    debug!(count = teams.len(), %region, "parsed rankings");
+    Ok(teams)
+}
+
+/// Map a [`Region`] to the paths of its rankings pages. vlr.gg splits Latin
+/// America into south and north tables, so that region reads both.
+fn rankings_paths(region: &Region) -> &'static [&'static str] {
+    match region {
+        Region::All => &["/rankings"],
+        Region::NorthAmerica => &["/rankings/north-america"],
+        Region::Europe => &["/rankings/europe"],
+        Region::Brazil => &["/rankings/brazil"],
+        Region::AsiaPacific => &["/rankings/asia-pacific"],
+        Region::Korea => &["/rankings/korea"],
+        Region::Japan => &["/rankings/japan"],
+        Region::LatinAmerica => &["/rankings/la-s", "/rankings/la-n"],
+        Region::Oceania => &["/rankings/oceania"],
+        Region::MiddleEastNorthAfrica => &["/rankings/mena"],
+        Region::GameChangers => &["/rankings/gc"],
+        Region::Collegiate => &["/rankings/collegiate"],
+    }
+}
+
+/// Combine rows read from several tables into one ranking: ordered by
+/// rating, highest first, and renumbered from 1.
+fn merge_rankings(teams: &mut [RankedTeam]) {
+    teams.sort_by(|a, b| b.rating.total_cmp(&a.rating));
+    for (rank, team) in (1..).zip(teams.iter_mut()) {
+        team.rank = rank;
+    }
+}
+
+/// Parse every `div.rank-item` row. Rows without a rank or a team link are
+/// skipped.
+fn parse_rankings(document: &scraper::Html) -> Result<Vec<RankedTeam>> {
+    let item_selector = Selector::parse("div.rank-item")?;
+    let rank_selector = Selector::parse("div.rank-item-rank")?;
+    let team_selector = Selector::parse("a.rank-item-team")?;
+    let name_selector = Selector::parse("div.ge-text")?;
+    let flag_selector = Selector::parse("i.flag")?;
+    let img_selector = Selector::parse("img")?;
+    let rating_selector = Selector::parse("div.rank-item-rating")?;
+    let record_selector = Selector::parse("div.rank-item-record")?;
+
+    let teams = document
+        .select(&item_selector)
+        .filter_map(|item| {
+            let rank = normalize_number(&select_text(&item, &rank_selector))
+                .trim_start_matches('#')
+                .parse()
+                .ok()?;
+
+            let team = item.select(&team_selector).next()?;
+            let (team_id, _slug) = team
+                .value()
+                .attr("href")?
+                .strip_prefix("/team/")?
+                .split_once('/')?;
+            let team_name = team_name(&team, &name_selector);
+
+            let country_code = item.select(&flag_selector).next().and_then(|f| {
+                f.value()
+                    .classes()
+                    .find_map(|c| c.strip_prefix("mod-"))
+                    .map(str::to_string)
+            });
+            let logo_url = team
+                .select(&img_selector)
+                .next()
+                .and_then(|img| img.value().attr("src"))
+                .map(normalize_img_url)
+                .unwrap_or_default();
+
+            let rating = normalize_number(&select_text(&item, &rating_selector))
+                .parse()
+                .unwrap_or_default();
+            let record = Some(select_text(&item, &record_selector))
+                .map(|r| r.split_whitespace().collect::<String>())
+                .filter(|r| !r.is_empty());
+
+            Some(RankedTeam {
+                rank,
+                team_id: team_id.parse().ok()?,
+                team_name,
+                country_code,
+                rating,
+                record,
+                logo_url,
+            })
+        })
+        .collect();
+    Ok(teams)
+}
+
+/// The team name from the link's first text node, without the `#TAG` and
+/// country lines that follow it.
+fn team_name(team: &ElementRef, name_selector: &Selector) -> String {
+    team.value()
+        .attr("data-sort-value")
+        .or_else(|| {
+            team.select(name_selector)
+                .next()?
+                .text()
+                .map(str::trim)
+                .find(|t| !t.is_empty())
+        })
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rankings() {
+        let document = scraper::Html::parse_document(
+            r#"<div class="wf-card">
+                <div class="rank-item wf-card fc-flex">
+                    <div class="rank-item-rank"><a class="rank-item-rank-num">1</a></div>
+                    <a class="rank-item-team fc-flex" href="/team/2/sentinels">
+                        <img src="//owcdn.net/img/sen.png">
+                        <div class="ge-text">Sentinels <span class="ge-text-light">#SEN</span>
+                            <div class="rank-item-team-country"><i class="flag mod-us"></i> United States</div>
+                        </div>
+                    </a>
+                    <div class="rank-item-rating"><a>1,923</a></div>
+                    <div class="rank-item-record">20 – 5</div>
+                </div>
+                <div class="rank-item wf-card fc-flex">
+                    <div class="rank-item-rank">2</div>
+                    <a class="rank-item-team fc-flex" href="/team/188/cloud9" data-sort-value="Cloud9">
+                        <div class="ge-text">Cloud9</div>
+                    </a>
+                    <div class="rank-item-rating"></div>
+                </div>
+                <div class="rank-item wf-card fc-flex"><div class="rank-item-rank">3</div></div>
+            </div>"#,
+        );
+        let teams = parse_rankings(&document).unwrap();
+        assert_eq!(teams.len(), 2);
+
+        let sen = &teams[0];
+        assert_eq!((sen.rank, sen.team_id), (1, 2));
+        assert_eq!(sen.team_name, "Sentinels");
+        assert_eq!(sen.country_code.as_deref(), Some("us"));
+        assert_eq!(sen.rating, 1923.0);
+        assert_eq!(sen.record.as_deref(), Some("20–5"));
+        assert_eq!(sen.logo_url, "https://owcdn.net/img/sen.png");
+
+        let c9 = &teams[1];
+        assert_eq!((c9.rank, c9.team_name.as_str()), (2, "Cloud9"));
+        assert_eq!(c9.rating, 0.0);
+        assert_eq!(c9.record, None);
+        assert_eq!(c9.logo_url, "");
+    }
+
+    #[test]
+    fn test_rankings_paths() {
+        assert_eq!(rankings_paths(&Region::All), ["/rankings"]);
+        assert_eq!(
+            rankings_paths(&Region::NorthAmerica),
+            ["/rankings/north-america"]
+        );
+        assert_eq!(
+            rankings_paths(&Region::LatinAmerica),
+            ["/rankings/la-s", "/rankings/la-n"]
+        );
+    }
+
+    #[test]
+    fn test_merge_rankings() {
+        let team = |rank, team_id, rating| RankedTeam {
+            rank,
+            team_id,
+            team_name: String::new(),
+            country_code: None,
+            rating,
+            record: None,
+            logo_url: String::new(),
+        };
+        // South table first, then north
+        let mut teams = vec![
+            team(1, 10, 1800.0),
+            team(2, 11, 1500.0),
+            team(1, 20, 1650.0),
+        ];
+        merge_rankings(&mut teams);
+        let ranked: Vec<(u16, u32)> = teams.iter().map(|t| (t.rank, t.team_id)).collect();
+        assert_eq!(ranked, [(1, 10), (2, 20), (3, 11)]);
+    }
+
+    #[tokio::test]
+    async fn test_get_rankings() {
+        let client = HttpClient::default();
+        let teams = get_rankings(&client, Region::Europe).await.unwrap();
+        assert!(!teams.is_empty());
+        assert_eq!(teams[0].rank, 1);
+    }
+}
@@ -5,27 +5,58 @@ use tracing::{debug, instrument};
 
 use crate::error::Result;
 use crate::model::TeamTransaction;
-use crate::vlr_scraper;
+use crate::vlr_scraper::{self, HttpClient};
 
 #[instrument(skip(client))]
 pub(crate) async fn get_team_transactions(
-    client: &reqwest::Client,
+    client: &HttpClient,
     team_id: u32,
+    page: u8,
 ) -> Result<Vec<TeamTransaction>> {
-    let url = format!("https://www.vlr.gg/team/transactions/{team_id}/");
+    let url = client.url(&format!("/team/transactions/{team_id}/?page={page}"));
     let document = vlr_scraper::get_document(client, &url).await?;
     let transactions = parse_transactions(&document)?;
     debug!(
         count = transactions.len(),
-        team_id, "parsed team transactions"
+        team_id, page, "parsed team transactions"
+    );
+    Ok(transactions)
+}
+
+/// Fetch every transaction page for a team and concatenate them.
+///
+/// Pages are requested sequentially until one comes back empty or identical
+/// to the previous page, which is what vlr.gg serves for out-of-range page
+/// numbers. Identical rows within the history are kept as listed.
+#[instrument(skip(client))]
+pub(crate) async fn get_all_team_transactions(
+    client: &HttpClient,
+    team_id: u32,
+) -> Result<Vec<TeamTransaction>> {
+    let mut transactions: Vec<TeamTransaction> = Vec::new();
+    let mut previous_page: Vec<TeamTransaction> = Vec::new();
+    for page in 1..=u8::MAX {
+        let page_transactions = get_team_transactions(client, team_id, page).await?;
+        if page_transactions.is_empty() || page_transactions == previous_page {
+            break;
+        }
+        transactions.extend(page_transactions.iter().cloned());
+        previous_page = page_transactions;
+    }
+    debug!(
+        count = transactions.len(),
+        team_id, "parsed all team transactions"
     );
     Ok(transactions)
 }
 
 fn parse_transactions(document: &scraper::Html) -> Result<Vec<TeamTransaction>> {
     let row_selector = Selector::parse("tr.txn-item")?;
+    let td_selector = Selector::parse("td")?;
     document
         .select(&row_selector)
+        // Header rows repeated within the table carry only <th> cells
+        .filter(|row| row.select(&td_selector).next().is_some())
         .map(|row| parse_transaction_row(&row))
         .collect()
 }
@@ -146,11 +177,38 @@ fn parse_transaction_row(element: &ElementRef) -> Result<TeamTransaction> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vlr_scraper::tests::serve;
+
+    /// An HTTP response serving a transactions page with `rows`.
+    fn transactions_page(rows: &str) -> &'static str {
+        let body = format!("<table>{rows}</table>");
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .leak()
+    }
+
+    #[tokio::test]
+    async fn test_get_all_team_transactions_stops_on_repeated_page() {
+        let row = r#"<tr class="txn-item"><td>2024/01/02</td><td class="txn-item-action">join</td><td></td><td><a href="/player/9/tenz">TenZ</a></td><td>player</td></tr>"#;
+        let other = r#"<tr class="txn-item"><td>2023/05/06</td><td class="txn-item-action">leave</td><td></td><td><a href="/player/4/zombs">zombs</a></td><td>player</td></tr>"#;
+        let page_1 = transactions_page(&format!("{row}{row}"));
+        let page_2 = transactions_page(other);
+        let client = HttpClient {
+            base_url: serve(vec![page_1, page_2, page_2]),
+            ..Default::default()
+        };
+
+        let all = get_all_team_transactions(&client, 2).await.unwrap();
+        let aliases: Vec<&str> = all.iter().map(|t| t.player_alias.as_str()).collect();
+        assert_eq!(aliases, ["TenZ", "TenZ", "zombs"]);
+    }
 
     #[tokio::test]
     async fn test_get_team_transactions() {
-        let client = reqwest::Client::new();
-        let transactions = get_team_transactions(&client, 6530).await.unwrap();
+        let client = HttpClient::default();
+        let transactions = get_team_transactions(&client, 6530, 1).await.unwrap();
 
         assert!(!transactions.is_empty());
 
@@ -168,4 +226,14 @@ mod tests {
         assert!(!first.position.is_empty());
         assert!(first.player_id > 0);
     }
+
+    #[tokio::test]
+    async fn test_get_all_team_transactions() {
+        let client = HttpClient::default();
+        let first_page = get_team_transactions(&client, 6530, 1).await.unwrap();
+        let all = get_all_team_transactions(&client, 6530).await.unwrap();
+
+        assert!(all.len() >= first_page.len());
+        assert_eq!(all[..first_page.len()], first_page[..]);
+    }
 }